@@ -0,0 +1,254 @@
+// Copyright 2020 Louis Garczynski
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/*!
+Build polymorphic constants programmatically, for build scripts computing them from external data.
+
+The constants are rendered as a `polymorphic_constant!` invocation, so the generated code
+gets the same compile-time checks and code generation as constants written by hand:
+```
+use polymorphic_constant_build::{Constant, Constants};
+
+let source = Constants::new()
+    .constant(Constant::new("SAMPLE_RATE", 48_000).variants(&["u32", "usize"]).public())
+    .constant(Constant::new("GAIN", 0.5).variants(&["f32", "f64"]).doc("The default gain"))
+    .render()
+    .unwrap();
+
+assert_eq!(source, "\
+::polymorphic_constant::polymorphic_constant! {
+    pub const SAMPLE_RATE: u32 | usize = 48000;
+    /// The default gain
+    const GAIN: f32 | f64 = 0.5;
+}
+");
+```
+
+The rendered source is then written to `OUT_DIR` by the build script,
+and included by the crate, which must depend on `polymorphic-constant`:
+```ignore
+include!(concat!(env!("OUT_DIR"), "/constants.rs"));
+```
+*/
+
+use polymorphic_constant_syntax::{variant_kind, VariantKind};
+use std::fmt;
+use std::fmt::Write;
+
+/// The value of a constant, rendered as an untyped literal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Int(i128),
+    Uint(u128),
+    Float(f64),
+}
+
+macro_rules! impl_from_for_value {
+    ($($variant:ident($numeric_type:ty)),*) => {
+        $(impl From<$numeric_type> for Value {
+            fn from(value: $numeric_type) -> Self {
+                Value::$variant(value.into())
+            }
+        })*
+    };
+}
+
+impl_from_for_value!(
+    Int(i8), Int(i16), Int(i32), Int(i64), Int(i128),
+    Uint(u8), Uint(u16), Uint(u32), Uint(u64), Uint(u128),
+    Float(f32), Float(f64)
+);
+
+/// The reason a constant cannot be rendered
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    /// The name of the constant is not a valid identifier
+    InvalidName(String),
+    /// The constant has no variant
+    NoVariant(String),
+    /// The variant is not supported by `polymorphic_constant!`
+    UnknownVariant { constant: String, variant: String },
+    /// Integer and float literals cannot be stored in variants of the other kind
+    MismatchedVariant { constant: String, variant: String },
+    /// Infinite and NaN values have no literal
+    NonFiniteValue(String),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::InvalidName(name) => write!(f, "`{}` is not a valid constant name", name),
+            BuildError::NoVariant(constant) => write!(f, "`{}` has no variant", constant),
+            BuildError::UnknownVariant { constant, variant } => {
+                write!(f, "`{}` has an unknown variant `{}`", constant, variant)
+            }
+            BuildError::MismatchedVariant { constant, variant } => {
+                write!(f, "the value of `{}` cannot be stored in its `{}` variant", constant, variant)
+            }
+            BuildError::NonFiniteValue(constant) => write!(f, "the value of `{}` is not finite", constant),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// A single constant, with its value and variants
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constant {
+    name: String,
+    value: Value,
+    variants: Vec<String>,
+    visibility: String,
+    docs: Vec<String>,
+    attributes: Vec<String>,
+}
+
+impl Constant {
+    /// Create a private constant without variants
+    pub fn new(name: impl Into<String>, value: impl Into<Value>) -> Self {
+        Constant {
+            name: name.into(),
+            value: value.into(),
+            variants: Vec::new(),
+            visibility: String::new(),
+            docs: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Add a variant, such as `f32` or `nz_u8`
+    pub fn variant(mut self, variant: impl Into<String>) -> Self {
+        self.variants.push(variant.into());
+        self
+    }
+
+    /// Add several variants
+    pub fn variants(mut self, variants: &[&str]) -> Self {
+        self.variants.extend(variants.iter().map(|variant| variant.to_string()));
+        self
+    }
+
+    /// Make the constant `pub`
+    pub fn public(self) -> Self {
+        self.visibility("pub")
+    }
+
+    /// Set the visibility of the constant, such as `pub(crate)`
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = visibility.into();
+        self
+    }
+
+    /// Add a line of documentation
+    pub fn doc(mut self, line: impl Into<String>) -> Self {
+        self.docs.push(line.into());
+        self
+    }
+
+    /// Add an attribute, without the surrounding `#[]`, such as `also(halved = HALF)`
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.attributes.push(attribute.into());
+        self
+    }
+
+    fn check(&self) -> Result<(), BuildError> {
+        let mut chars = self.name.strip_prefix("r#").unwrap_or(&self.name).chars();
+        let valid_start = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+        if !valid_start || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(BuildError::InvalidName(self.name.clone()));
+        }
+        if self.variants.is_empty() {
+            return Err(BuildError::NoVariant(self.name.clone()));
+        }
+        if let Value::Float(value) = self.value {
+            if !value.is_finite() {
+                return Err(BuildError::NonFiniteValue(self.name.clone()));
+            }
+        }
+        for variant in &self.variants {
+            // Atomic variants require a `static`, while only constants are rendered
+            let is_float = match variant_kind(variant) {
+                Some(_) if variant.starts_with("atomic_") => {
+                    return Err(BuildError::UnknownVariant {
+                        constant: self.name.clone(),
+                        variant: variant.clone(),
+                    })
+                }
+                Some(VariantKind::Float) => true,
+                Some(VariantKind::Integer) => false,
+                Some(VariantKind::Fixed) | Some(VariantKind::Decimal) | Some(VariantKind::Ratio) | Some(VariantKind::Str) => continue,
+                None => {
+                    return Err(BuildError::UnknownVariant {
+                        constant: self.name.clone(),
+                        variant: variant.clone(),
+                    })
+                }
+            };
+            if is_float != matches!(self.value, Value::Float(_)) {
+                return Err(BuildError::MismatchedVariant {
+                    constant: self.name.clone(),
+                    variant: variant.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn render(&self, out: &mut String) -> fmt::Result {
+        for doc in &self.docs {
+            writeln!(out, "    /// {}", doc)?;
+        }
+        for attribute in &self.attributes {
+            writeln!(out, "    #[{}]", attribute)?;
+        }
+        out.push_str("    ");
+        if !self.visibility.is_empty() {
+            write!(out, "{} ", self.visibility)?;
+        }
+        write!(out, "const {}: {} = ", self.name, self.variants.join(" | "))?;
+        match self.value {
+            Value::Int(value) => write!(out, "{}", value)?,
+            Value::Uint(value) => write!(out, "{}", value)?,
+            // Debug keeps the decimal point, and the shortest representation that reads back exactly
+            Value::Float(value) => write!(out, "{:?}", value)?,
+        }
+        out.push_str(";\n");
+        Ok(())
+    }
+}
+
+/// A list of constants, rendered as a single `polymorphic_constant!` invocation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constants {
+    constants: Vec<Constant>,
+}
+
+impl Constants {
+    /// Create an empty list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a constant
+    pub fn constant(mut self, constant: Constant) -> Self {
+        self.constants.push(constant);
+        self
+    }
+
+    /// Check every constant, and render the invocation
+    ///
+    /// Literals too large for their variants are only caught when compiling the rendered source.
+    pub fn render(&self) -> Result<String, BuildError> {
+        let mut out = String::from("::polymorphic_constant::polymorphic_constant! {\n");
+        for constant in &self.constants {
+            constant.check()?;
+            constant.render(&mut out).expect("writing to a String cannot fail");
+        }
+        out.push_str("}\n");
+        Ok(out)
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(test)]
+
+use polymorphic_constant_build::{BuildError, Constant, Constants};
+
+#[test]
+fn test_render() {
+    let source = Constants::new()
+        .constant(
+            Constant::new("PI", std::f64::consts::PI)
+                .variants(&["f32", "f64"])
+                .visibility("pub(crate)")
+                .doc("Pi")
+                .attribute("also(halved = HALF_PI)"),
+        )
+        .constant(Constant::new("OFFSET", -3).variant("i8").variant("nz_i64"))
+        .constant(Constant::new("HUGE", 1e300).variant("f64"))
+        .constant(Constant::new("ONE", 1.0f32).variant("f64"))
+        .render()
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "::polymorphic_constant::polymorphic_constant! {
+    /// Pi
+    #[also(halved = HALF_PI)]
+    pub(crate) const PI: f32 | f64 = 3.141592653589793;
+    const OFFSET: i8 | nz_i64 = -3;
+    const HUGE: f64 = 1e300;
+    const ONE: f64 = 1.0;
+}
+"
+    );
+}
+
+#[test]
+fn test_errors() {
+    let render = |constant: Constant| Constants::new().constant(constant).render();
+
+    assert_eq!(render(Constant::new("1X", 1).variant("u8")), Err(BuildError::InvalidName("1X".into())));
+    assert_eq!(render(Constant::new("X", 1)), Err(BuildError::NoVariant("X".into())));
+    assert_eq!(
+        render(Constant::new("X", 1).variant("u7")),
+        Err(BuildError::UnknownVariant { constant: "X".into(), variant: "u7".into() })
+    );
+    assert_eq!(
+        render(Constant::new("X", 1).variants(&["u8", "f32"])),
+        Err(BuildError::MismatchedVariant { constant: "X".into(), variant: "f32".into() })
+    );
+    assert_eq!(render(Constant::new("X", f64::NAN).variant("f32")), Err(BuildError::NonFiniteValue("X".into())));
+    assert!(render(Constant::new("r#type", 1).variant("u8")).is_ok());
+}
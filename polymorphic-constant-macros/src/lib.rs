@@ -0,0 +1,343 @@
+// Copyright 2020 Louis Garczynski
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/*!
+Apply `polymorphic_constant!` to every constant of a module, written as regular Rust.
+
+The variants of each constant are listed as a tuple type, as `f32 | f64` is not a valid type.
+Constants with a single type keep it, and the other items of the module are left untouched:
+```
+use polymorphic_constant_macros::polymorphic_constants;
+
+#[polymorphic_constants]
+mod consts {
+    #![lookup(pub fn find)]
+
+    /// Pi, in every float type
+    pub const PI: (f32, f64) = 3.141592653589793;
+    pub const ANSWER: u8 = 42;
+
+    pub fn pi_squared() -> f64 {
+        PI.f64 * PI.f64
+    }
+}
+
+assert_eq!(consts::pi_squared(), 9.869604401089358);
+assert!(consts::find("ANSWER").is_some());
+```
+
+The inner attributes of the module are passed to the macro, as options of the invocation.
+Initializers that are not valid Rust, such as literals with units or `pack(...)`, require the macro itself.
+*/
+
+extern crate proc_macro;
+
+use polymorphic_constant_syntax::variant_kind;
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, TokenStream, TokenTree};
+
+/// Generate every constant of a module, whose type is a tuple of variants or a single variant
+#[proc_macro_attribute]
+pub fn polymorphic_constants(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    if !attribute.is_empty() {
+        return error("`polymorphic_constants` takes no arguments");
+    }
+
+    // Keep everything up to the body of the module
+    let mut head: Vec<TokenTree> = item.into_iter().collect();
+    let body = match head.pop() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace && is_module(&head) => body,
+        _ => return error("`polymorphic_constants` only applies to modules with a body"),
+    };
+
+    let mut options = TokenStream::new();
+    let mut constants = TokenStream::new();
+    let mut others = TokenStream::new();
+    for item in items(body.stream()) {
+        match item {
+            Item::Option(tokens) => options.extend(tokens),
+            Item::Constant(tokens) => constants.extend(tokens),
+            Item::Other(tokens) => others.extend(tokens),
+        }
+    }
+
+    // Invoke the macro inside the module, after its other items
+    let mut invocation: TokenStream = "::polymorphic_constant::polymorphic_constant!".parse().unwrap();
+    options.extend(constants);
+    invocation.extend(Some(TokenTree::Group(Group::new(Delimiter::Brace, options))));
+    others.extend(invocation);
+
+    let body = Group::new(Delimiter::Brace, others);
+    head.into_iter().chain(Some(TokenTree::Group(body))).collect()
+}
+
+// Replace every `[< ... >]` group by the identifier joining its parts, used by the macro to name companions
+#[doc(hidden)]
+#[proc_macro]
+pub fn __paste(input: TokenStream) -> TokenStream {
+    paste(input)
+}
+
+// Declare a deprecated function named after a float variant, whose note tells how the variant rounds the initializer,
+// used by the macro to warn about the variants losing precision
+#[doc(hidden)]
+#[proc_macro]
+pub fn __lossy_variant(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let (variant, kind, initializer) = match tokens.as_slice() {
+        [TokenTree::Ident(variant), TokenTree::Ident(kind), initializer] => (variant, kind.to_string(), initializer),
+        _ => return error("expected a variant, its float type and the initializer"),
+    };
+    let note = match literal(initializer) {
+        Some(text) => match (parse_float(&text), kind.as_str()) {
+            (Some(value), "f32") => format!("`{}` variant rounds {} to {}", variant, text, round_f32(value)),
+            (Some(value), "f16") => format!("`{}` variant rounds {} to {}", variant, text, round_f16(value)),
+            _ => format!("`{}` variant does not hold {} exactly", variant, text),
+        },
+        None => format!("`{}` variant does not hold the exact value of `{}`", variant, expression(initializer)),
+    };
+    let mut function: TokenStream = format!("#[deprecated(note = {:?})] const fn", note).parse().unwrap();
+    function.extend(vec![
+        TokenTree::Ident(variant.clone()),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
+        TokenTree::Group(Group::new(Delimiter::Brace, TokenStream::new())),
+    ]);
+    function
+}
+
+// The text of a literal initializer, which may be wrapped in the invisible group of a captured fragment
+fn literal(initializer: &TokenTree) -> Option<String> {
+    match initializer {
+        TokenTree::Literal(literal) => Some(literal.to_string()),
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+            match tokens.as_slice() {
+                [token] => literal(token),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// The text of an expression initializer, given by the macro as `(@EXPR NAME expression)`
+fn expression(initializer: &TokenTree) -> String {
+    match initializer {
+        TokenTree::Group(group) => group.stream().into_iter().skip(3).collect::<TokenStream>().to_string(),
+        other => other.to_string(),
+    }
+}
+
+// The value of a float or integer literal, without its separators and suffix
+fn parse_float(text: &str) -> Option<f64> {
+    let text = text.replace('_', "");
+    let text = ["f32", "f64"].iter().fold(text.as_str(), |text, suffix| text.trim_end_matches(suffix));
+    text.parse().ok()
+}
+
+// Round a value to the nearest `f32`, written in full when its shortest form reads as the value itself
+fn round_f32(value: f64) -> String {
+    let rounded = value as f32;
+    let shortest = rounded.to_string();
+    if shortest.parse::<f64>() == Ok(value) {
+        (rounded as f64).to_string()
+    } else {
+        shortest
+    }
+}
+
+// Round a value to the nearest `f16`, ties to even, which stays exact in `f64`
+fn round_f16(value: f64) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let exponent = ((value.abs().to_bits() >> 52) & 0x7FF) as i32 - 1023;
+    // Subnormal `f16` values share the quantum of the smallest exponent
+    let quantum = 2f64.powi(exponent.max(-14) - 10);
+    (value / quantum).round_ties_even() * quantum
+}
+
+fn paste(input: TokenStream) -> TokenStream {
+    input
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let stream = group.stream();
+                if group.delimiter() == Delimiter::Bracket {
+                    if let Some(ident) = joined(&stream) {
+                        return TokenTree::Ident(ident);
+                    }
+                }
+                let mut pasted = Group::new(group.delimiter(), paste(stream));
+                pasted.set_span(group.span());
+                TokenTree::Group(pasted)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+// The identifier joining the parts of `< ... >`, given the inside of the brackets
+fn joined(stream: &TokenStream) -> Option<Ident> {
+    let tokens: Vec<TokenTree> = stream.clone().into_iter().collect();
+    let parts = match tokens.as_slice() {
+        [open, parts @ .., close] if is_punct(open, '<') && is_punct(close, '>') && !parts.is_empty() => parts,
+        _ => return None,
+    };
+    let mut name = String::new();
+    for part in parts {
+        match part {
+            TokenTree::Ident(ident) => name.push_str(ident.to_string().trim_start_matches("r#")),
+            TokenTree::Literal(literal) => name.push_str(&literal.to_string()),
+            // Captured fragments are wrapped in invisible groups
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                name.push_str(&group.stream().to_string().replace(' ', ""));
+            }
+            _ => return None,
+        }
+    }
+    Some(Ident::new(&name, parts[0].span()))
+}
+
+// A module item is `mod name`, after its attributes and visibility
+fn is_module(head: &[TokenTree]) -> bool {
+    match head {
+        [.., TokenTree::Ident(keyword), TokenTree::Ident(_)] => keyword.to_string() == "mod",
+        _ => false,
+    }
+}
+
+enum Item {
+    // An inner attribute of the module
+    Option(Vec<TokenTree>),
+    // A constant rewritten for the macro
+    Constant(Vec<TokenTree>),
+    // Any other item, kept as is
+    Other(Vec<TokenTree>),
+}
+
+// Split the body of the module into items, ending with `;` or with a block
+fn items(body: TokenStream) -> Vec<Item> {
+    let mut items = Vec::new();
+    let mut tokens = body.into_iter().peekable();
+    let mut current: Vec<TokenTree> = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        // Inner attributes are items of their own
+        if current.is_empty() && is_punct(&token, '#') {
+            if let Some(TokenTree::Punct(bang)) = tokens.peek() {
+                if bang.as_char() == '!' {
+                    let bang = tokens.next().unwrap();
+                    let attribute = tokens.next().into_iter();
+                    items.push(Item::Option(vec![token, bang].into_iter().chain(attribute).collect()));
+                    continue;
+                }
+            }
+        }
+
+        let is_const = is_constant(&current);
+        let ends = match &token {
+            TokenTree::Punct(punct) => punct.as_char() == ';',
+            TokenTree::Group(group) => group.delimiter() == Delimiter::Brace && !is_const,
+            _ => false,
+        };
+        current.push(token);
+
+        if ends {
+            let item = std::mem::take(&mut current);
+            items.push(match rewrite(&item) {
+                Some(constant) => Item::Constant(constant),
+                None => Item::Other(item),
+            });
+        }
+    }
+
+    if !current.is_empty() {
+        items.push(Item::Other(current));
+    }
+    items
+}
+
+// Whether the item started so far is a constant, after its attributes and visibility
+// Constant functions are not, and `const _` items cannot be given to the macro
+fn is_constant(item: &[TokenTree]) -> bool {
+    let mut tokens = item
+        .iter()
+        .skip_while(|token| is_punct(token, '#') || matches!(token, TokenTree::Group(_)) || is_ident(token, "pub"));
+    match (tokens.next(), tokens.next()) {
+        (Some(keyword), Some(TokenTree::Ident(name))) if is_ident(keyword, "const") => {
+            !["fn", "unsafe", "async", "extern", "_"].contains(&name.to_string().as_str())
+        }
+        _ => false,
+    }
+}
+
+// Replace the tuple type of a constant by the variants of the macro
+fn rewrite(item: &[TokenTree]) -> Option<Vec<TokenTree>> {
+    if !is_constant(item) {
+        return None;
+    }
+    let colon = item.iter().position(|token| is_punct(token, ':'))?;
+    let (head, tail) = item.split_at(colon + 1);
+    match tail {
+        // A single variant
+        [TokenTree::Ident(variant), equal, ..] if is_variant(variant) && is_punct(equal, '=') => Some(item.to_vec()),
+        // A tuple of variants, unless initialized with a tuple
+        [TokenTree::Group(group), equal, rest @ ..]
+            if group.delimiter() == Delimiter::Parenthesis && is_punct(equal, '=') && !is_tuple(rest) =>
+        {
+            let mut variants = Vec::new();
+            for token in group.stream() {
+                match token {
+                    TokenTree::Ident(variant) if is_variant(&variant) => variants.push(TokenTree::Ident(variant)),
+                    TokenTree::Punct(comma) if comma.as_char() == ',' => {}
+                    _ => return None,
+                }
+            }
+            if variants.is_empty() {
+                return None;
+            }
+            let separators = std::iter::repeat_with(|| TokenTree::Punct(Punct::new('|', Spacing::Alone)));
+            let mut rewritten = head.to_vec();
+            for (i, (variant, separator)) in variants.into_iter().zip(separators).enumerate() {
+                if i > 0 {
+                    rewritten.push(separator);
+                }
+                rewritten.push(variant);
+            }
+            rewritten.push(equal.clone());
+            rewritten.extend(rest.iter().cloned());
+            Some(rewritten)
+        }
+        _ => None,
+    }
+}
+
+// The numeric types supported by the macro
+fn is_variant(variant: &proc_macro::Ident) -> bool {
+    variant_kind(&variant.to_string()).is_some()
+}
+
+fn is_tuple(initializer: &[TokenTree]) -> bool {
+    match initializer {
+        [TokenTree::Group(group), ..] if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream().into_iter().any(|token| is_punct(&token, ','))
+        }
+        _ => false,
+    }
+}
+
+fn is_punct(token: &TokenTree, c: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == c)
+}
+
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string() == name)
+}
+
+fn error(message: &str) -> TokenStream {
+    format!("::core::compile_error!({:?});", message).parse().unwrap()
+}
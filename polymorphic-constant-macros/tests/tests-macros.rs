@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+use polymorphic_constant_macros::polymorphic_constants;
+
+#[polymorphic_constants]
+mod consts {
+    #![lookup(pub fn find)]
+
+    use core::ops::Add;
+
+    /// Width of the screen
+    #[also(halved = HALF_WIDTH)]
+    pub const WIDTH: (u16, u32) = 640;
+    pub(crate) const HEIGHT: (u16, u32,) = 480;
+    pub const SCALE: (f32, f64) = 1.5;
+    pub const DEPTH: u8 = 8;
+
+    // Left to the compiler
+    pub const BORDERS: [u8; 2] = [1, 2];
+    const _: () = assert!(BORDERS.len() == 2);
+
+    pub const fn double(value: u32) -> u32 {
+        value * 2
+    }
+
+    pub fn total<T: Add<Output = T>>() -> T
+    where
+        WIDTH: Into<T>,
+        HEIGHT: Into<T>,
+    {
+        WIDTH.into() + HEIGHT.into()
+    }
+
+    pub struct Resolution {
+        pub width: u32,
+    }
+
+    pub const NATIVE: Resolution = Resolution { width: double(320) };
+    pub const ORIGIN: (u16, u16) = (0, 0);
+}
+
+#[test]
+fn test_module() {
+    assert_eq!(consts::total::<u32>(), 1120);
+    assert_eq!(consts::HALF_WIDTH.widest(), 320);
+    assert_eq!(consts::SCALE.widest(), 1.5);
+    assert_eq!(consts::DEPTH.widest(), 8);
+    assert_eq!(consts::NATIVE.width, 640);
+    assert_eq!(consts::BORDERS, [1, 2]);
+    assert_eq!(consts::ORIGIN, (0, 0));
+    assert_eq!(consts::find("HEIGHT").map(|variants| variants.len()), Some(2));
+}
@@ -0,0 +1,706 @@
+// Copyright 2020 Louis Garczynski
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/*!
+Parse the definitions of `polymorphic_constant!` into a syntax tree, for tools reading them
+outside of the compiler, such as code generators, linters or documentation generators.
+
+The source is either the body of an invocation, or a single invocation of the macro:
+```
+use polymorphic_constant_syntax::{parse, Initializer};
+
+let block = parse("
+    #![lookup(fn lookup)]
+
+    /// The ratio of a circle
+    #[also(halved = HALF_PI)]
+    pub const PI: f32 | f64 = 3.141592653589793;
+    const TAU = PI * 2.0;
+").unwrap();
+
+assert_eq!(block.options[0].name, "lookup");
+
+let pi = &block.constants[0];
+assert_eq!(pi.docs, ["The ratio of a circle"]);
+assert_eq!(pi.attributes[0].arguments.as_deref(), Some("halved = HALF_PI"));
+assert_eq!(pi.variants, ["f32", "f64"]);
+assert_eq!(pi.initializer, Initializer::Literal { value: "3.141592653589793".into(), unit: None });
+
+// Derived constants have no variants
+let tau = &block.constants[1];
+assert!(tau.variants.is_empty());
+assert_eq!(tau.initializer, Initializer::Expression("PI * 2.0".into()));
+```
+
+The text of attributes and expressions is kept as written, and is not checked further.
+*/
+
+use std::fmt;
+
+/// The variants of one or more constants, and the options of the invocation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Block {
+    /// The inner attributes, such as `#![lookup(fn lookup)]`
+    pub options: Vec<Attribute>,
+    /// The constants, in order of declaration
+    pub constants: Vec<Definition>,
+}
+
+/// An attribute of a constant, or an option of the invocation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    /// The path of the attribute, such as `also` or `cfg_attr`
+    pub name: String,
+    /// The text inside the delimiters, or after `=`, if any
+    pub arguments: Option<String>,
+}
+
+/// A single constant declaration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    /// The lines of documentation, without the leading `///` and space
+    pub docs: Vec<String>,
+    /// The outer attributes, in order
+    pub attributes: Vec<Attribute>,
+    /// The visibility as written, such as `pub` or `pub(crate)`
+    pub visibility: Option<String>,
+    /// The name of the constant
+    pub name: String,
+    /// The variants, empty for constants derived from the ones declared before them
+    pub variants: Vec<String>,
+    /// The initializer
+    pub initializer: Initializer,
+    /// The line of the `const` or `static` keyword, starting at 1
+    pub line: usize,
+    /// Whether the item is a `static`, which may hold atomic variants
+    pub is_static: bool,
+    /// The name of the `group` block holding the constant, if any
+    pub group: Option<String>,
+}
+
+/// The initializer of a constant
+#[derive(Debug, Clone, PartialEq)]
+pub enum Initializer {
+    /// An untyped literal, with its sign, and the unit following it if any, as in `3.5 km`
+    Literal { value: String, unit: Option<String> },
+    /// The arguments of `pack(...)`
+    Pack(String),
+    /// The arguments of `from_file(...)`
+    FromFile(String),
+    /// The arguments of `ratio_of(...)`, the numerator and denominator constants
+    RatioOf(String),
+    /// Any other expression, computed then narrowed to each variant
+    Expression(String),
+}
+
+/// The kind of values held by a variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantKind {
+    Integer,
+    Float,
+    /// Fixed-point variants such as `i16f16`, holding either kind of literal
+    Fixed,
+    /// The `decimal` variant, holding either kind of literal
+    Decimal,
+    /// The `ratio` variants such as `ratio_i64`, holding either kind of literal
+    Ratio,
+    /// The `str` variant, holding the text of either kind of literal
+    Str,
+}
+
+const INTEGER_VARIANTS: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "nz_i8", "nz_i16", "nz_i32", "nz_i64", "nz_i128", "nz_isize",
+    "nz_u8", "nz_u16", "nz_u32", "nz_u64", "nz_u128", "nz_usize",
+    "opt_nz_i8", "opt_nz_i16", "opt_nz_i32", "opt_nz_i64", "opt_nz_i128", "opt_nz_isize",
+    "opt_nz_u8", "opt_nz_u16", "opt_nz_u32", "opt_nz_u64", "opt_nz_u128", "opt_nz_usize",
+    "nonmax_i8", "nonmax_i16", "nonmax_i32", "nonmax_i64", "nonmax_i128", "nonmax_isize",
+    "nonmax_u8", "nonmax_u16", "nonmax_u32", "nonmax_u64", "nonmax_u128", "nonmax_usize",
+    "nm_i8", "nm_i16", "nm_i32", "nm_i64", "nm_i128", "nm_isize",
+    "nm_u8", "nm_u16", "nm_u32", "nm_u64", "nm_u128", "nm_usize",
+    "bounded_i8", "bounded_i16", "bounded_i32", "bounded_i64", "bounded_i128", "bounded_isize",
+    "bounded_u8", "bounded_u16", "bounded_u32", "bounded_u64", "bounded_u128", "bounded_usize",
+    "smallest_uint", "smallest_int",
+    "u24", "u40", "u48", "u56", "u256", "i256",
+    "bigint", "biguint",
+    "ipv4", "ipv6", "duration", "limbs", "bool", "char",
+    "ptr_u8", "ptr_u16", "ptr_u32", "ptr_u64", "ptr_usize",
+    "le_bytes", "be_bytes", "ne_bytes",
+    "atomic_bool", "atomic_i8", "atomic_i16", "atomic_i32", "atomic_i64", "atomic_isize",
+    "atomic_u8", "atomic_u16", "atomic_u32", "atomic_u64", "atomic_usize",
+    "c_char", "c_schar", "c_uchar", "c_short", "c_ushort", "c_int", "c_uint",
+    "c_long", "c_ulong", "c_longlong", "c_ulonglong", "c_ssize_t", "c_size_t",
+    "i8x16", "i8x32", "i8x64", "u8x16", "u8x32", "u8x64",
+    "i16x8", "i16x16", "i16x32", "u16x8", "u16x16", "u16x32",
+    "i32x4", "i32x8", "i32x16", "u32x4", "u32x8", "u32x16",
+    "i64x2", "i64x4", "i64x8", "u64x2", "u64x4", "u64x8",
+];
+
+const FLOAT_VARIANTS: &[&str] = &[
+    "f16", "bf16", "f32", "f64", "f128", "real", "c_f32", "c_f64",
+    "of_f32", "of_f64", "nn_f32", "nn_f64",
+    "uom_length", "uom_mass", "uom_time", "uom_velocity", "uom_acceleration", "uom_frequency",
+    "f32x2", "f32x4", "f32x8", "f32x16", "f64x2", "f64x4", "f64x8",
+];
+
+const RATIO_VARIANTS: &[&str] = &["ratio_i32", "ratio_i64", "ratio_i128", "ratio_u32", "ratio_u64", "ratio_u128"];
+
+/// Get the kind of a variant supported by `polymorphic_constant!`, or `None` if it is unknown
+pub fn variant_kind(variant: &str) -> Option<VariantKind> {
+    if INTEGER_VARIANTS.contains(&variant) {
+        Some(VariantKind::Integer)
+    } else if FLOAT_VARIANTS.contains(&variant) {
+        Some(VariantKind::Float)
+    } else if variant == "decimal" {
+        Some(VariantKind::Decimal)
+    } else if variant == "str" {
+        Some(VariantKind::Str)
+    } else if RATIO_VARIANTS.contains(&variant) {
+        Some(VariantKind::Ratio)
+    } else if is_fixed(variant) {
+        Some(VariantKind::Fixed)
+    } else {
+        None
+    }
+}
+
+/// The variants with a fixed name supported by `polymorphic_constant!`, which excludes the fixed-point variants
+pub fn variants() -> impl Iterator<Item = &'static str> {
+    INTEGER_VARIANTS.iter().chain(FLOAT_VARIANTS).chain(RATIO_VARIANTS).chain(&["decimal", "str"]).copied()
+}
+
+// Fixed-point variants are named after their integer and fractional bits, in 8, 16 or 32 bits
+fn is_fixed(variant: &str) -> bool {
+    let bits = match variant.strip_prefix('i').or_else(|| variant.strip_prefix('u')) {
+        Some(bits) => bits,
+        None => return false,
+    };
+    let (int, frac) = match bits.find('f') {
+        Some(index) => (&bits[..index], &bits[index + 1..]),
+        None => return false,
+    };
+    match (int.parse::<u32>(), frac.parse::<u32>()) {
+        // Rendering the bits back rejects signs and leading zeros
+        (Ok(int), Ok(frac)) if format!("{}f{}", int, frac) == bits => matches!(int.checked_add(frac), Some(8) | Some(16) | Some(32)),
+        _ => false,
+    }
+}
+
+/// The reason a source cannot be parsed, with its position
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The line of the error, starting at 1
+    pub line: usize,
+    /// The column of the error in characters, starting at 1
+    pub column: usize,
+    /// What was expected
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse the body of an invocation, or a single invocation of the macro
+pub fn parse(source: &str) -> Result<Block, ParseError> {
+    let trees = Lexer::new(source).trees(None)?;
+    let parser = Parser { source };
+    match invocation(&trees) {
+        Some(body) if trees.len() == invocation_len(&trees) => parser.block(body),
+        _ => parser.block(&trees),
+    }
+}
+
+/// Parse every `polymorphic_constant!` invocation of a file, at any depth, in order
+pub fn parse_invocations(source: &str) -> Result<Vec<Block>, ParseError> {
+    let trees = Lexer::new(source).trees(None)?;
+    let parser = Parser { source };
+    let mut blocks = Vec::new();
+    parser.find(&trees, &mut blocks)?;
+    Ok(blocks)
+}
+
+// A token, or a delimited group of tokens, with its span in bytes
+#[derive(Debug, Clone)]
+struct Tree {
+    kind: Kind,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Kind {
+    Ident(String),
+    Literal,
+    Punct(char),
+    Doc(String),
+    Group(char, Vec<Tree>),
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str) -> Self {
+        Lexer { source, position: 0 }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.source[self.position..].chars().nth(offset)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.peek(0).is_some_and(&predicate) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, position: usize, message: impl Into<String>) -> ParseError {
+        error_at(self.source, position, message)
+    }
+
+    // Read the trees until the closing delimiter, or the end of the source
+    fn trees(&mut self, close: Option<char>) -> Result<Vec<Tree>, ParseError> {
+        let mut trees = Vec::new();
+        loop {
+            self.eat_while(char::is_whitespace);
+            let start = self.position;
+            let c = match self.peek(0) {
+                Some(c) => c,
+                None if close.is_none() => return Ok(trees),
+                None => return Err(self.error(start, format!("unclosed delimiter, expected `{}`", close.unwrap()))),
+            };
+            let kind = match c {
+                '/' if self.peek(1) == Some('/') => match self.comment() {
+                    Some(doc) => Kind::Doc(doc),
+                    None => continue,
+                },
+                '/' if self.peek(1) == Some('*') => {
+                    self.block_comment(start)?;
+                    continue;
+                }
+                '(' | '[' | '{' => {
+                    self.bump();
+                    let closing = match c {
+                        '(' => ')',
+                        '[' => ']',
+                        _ => '}',
+                    };
+                    Kind::Group(c, self.trees(Some(closing))?)
+                }
+                ')' | ']' | '}' if Some(c) == close => {
+                    self.bump();
+                    return Ok(trees);
+                }
+                ')' | ']' | '}' => return Err(self.error(start, format!("unexpected `{}`", c))),
+                '"' => {
+                    self.string(start)?;
+                    Kind::Literal
+                }
+                'r' if self.peek(1) == Some('#') && self.peek(2).is_some_and(is_ident_start) => {
+                    self.bump();
+                    self.bump();
+                    self.eat_while(is_ident_continue);
+                    Kind::Ident(self.source[start..self.position].to_string())
+                }
+                'r' if matches!(self.peek(1), Some('"') | Some('#')) => {
+                    self.bump();
+                    self.raw_string(start)?;
+                    Kind::Literal
+                }
+                'b' if matches!(self.peek(1), Some('"') | Some('\'')) => {
+                    self.bump();
+                    if self.peek(0) == Some('"') {
+                        self.string(start)?;
+                    } else {
+                        self.character(start)?;
+                    }
+                    Kind::Literal
+                }
+                'b' if self.peek(1) == Some('r') && matches!(self.peek(2), Some('"') | Some('#')) => {
+                    self.bump();
+                    self.bump();
+                    self.raw_string(start)?;
+                    Kind::Literal
+                }
+                '\'' => {
+                    // Characters end with a quote, lifetimes do not
+                    let is_char = self.peek(1) == Some('\\') || self.peek(2) == Some('\'');
+                    if is_char {
+                        self.character(start)?;
+                        Kind::Literal
+                    } else {
+                        self.bump();
+                        self.eat_while(is_ident_continue);
+                        Kind::Ident(self.source[start..self.position].to_string())
+                    }
+                }
+                c if c.is_ascii_digit() => {
+                    self.number();
+                    Kind::Literal
+                }
+                c if is_ident_start(c) => {
+                    self.eat_while(is_ident_continue);
+                    Kind::Ident(self.source[start..self.position].to_string())
+                }
+                c => {
+                    self.bump();
+                    Kind::Punct(c)
+                }
+            };
+            trees.push(Tree { kind, start, end: self.position });
+        }
+    }
+
+    // Skip a line comment, giving back the text of outer documentation
+    fn comment(&mut self) -> Option<String> {
+        let start = self.position;
+        self.eat_while(|c| c != '\n');
+        let comment = &self.source[start..self.position];
+        let doc = comment.strip_prefix("///").filter(|doc| !doc.starts_with('/'))?;
+        Some(doc.strip_prefix(' ').unwrap_or(doc).trim_end().to_string())
+    }
+
+    fn block_comment(&mut self, start: usize) -> Result<(), ParseError> {
+        self.bump();
+        self.bump();
+        let mut depth = 1;
+        while depth > 0 {
+            match (self.bump(), self.peek(0)) {
+                (Some('/'), Some('*')) => {
+                    self.bump();
+                    depth += 1;
+                }
+                (Some('*'), Some('/')) => {
+                    self.bump();
+                    depth -= 1;
+                }
+                (Some(_), _) => {}
+                (None, _) => return Err(self.error(start, "unterminated block comment")),
+            }
+        }
+        Ok(())
+    }
+
+    fn string(&mut self, start: usize) -> Result<(), ParseError> {
+        self.bump();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(()),
+                Some('\\') => {
+                    self.bump();
+                }
+                Some(_) => {}
+                None => return Err(self.error(start, "unterminated string")),
+            }
+        }
+    }
+
+    fn raw_string(&mut self, start: usize) -> Result<(), ParseError> {
+        let hashes = self.source[self.position..].chars().take_while(|&c| c == '#').count();
+        self.position += hashes;
+        if self.bump() != Some('"') {
+            return Err(self.error(start, "invalid raw string"));
+        }
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        match self.source[self.position..].find(&terminator) {
+            Some(end) => {
+                self.position += end + terminator.len();
+                Ok(())
+            }
+            None => Err(self.error(start, "unterminated raw string")),
+        }
+    }
+
+    fn character(&mut self, start: usize) -> Result<(), ParseError> {
+        self.bump();
+        loop {
+            match self.bump() {
+                Some('\'') => return Ok(()),
+                Some('\\') => {
+                    self.bump();
+                }
+                Some('\n') | None => return Err(self.error(start, "unterminated character")),
+                Some(_) => {}
+            }
+        }
+    }
+
+    // Numbers keep their suffix, and the sign of their exponent
+    fn number(&mut self) {
+        let radix = matches!((self.peek(0), self.peek(1)), (Some('0'), Some('x')) | (Some('0'), Some('o')) | (Some('0'), Some('b')));
+        loop {
+            match self.peek(0) {
+                Some('e') | Some('E') if !radix && matches!(self.peek(1), Some('+') | Some('-')) => {
+                    self.bump();
+                    self.bump();
+                }
+                Some('.') if self.peek(1).is_some_and(|c| c.is_ascii_digit()) => {
+                    self.bump();
+                }
+                Some(c) if c.is_ascii_alphanumeric() || c == '_' => {
+                    self.bump();
+                }
+                _ => return,
+            }
+        }
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn error_at(source: &str, position: usize, message: impl Into<String>) -> ParseError {
+    let before = &source[..position];
+    let line = before.matches('\n').count() + 1;
+    let column = before[before.rfind('\n').map_or(0, |i| i + 1)..].chars().count() + 1;
+    ParseError { line, column, message: message.into() }
+}
+
+fn is_punct(tree: Option<&Tree>, c: char) -> bool {
+    matches!(tree, Some(Tree { kind: Kind::Punct(p), .. }) if *p == c)
+}
+
+fn is_ident(tree: Option<&Tree>, name: &str) -> bool {
+    matches!(tree, Some(Tree { kind: Kind::Ident(ident), .. }) if ident == name)
+}
+
+// The body of an invocation such as `polymorphic_constant! { ... }`, with an optional path
+fn invocation(trees: &[Tree]) -> Option<&[Tree]> {
+    let bang = trees.iter().position(|tree| !matches!(tree.kind, Kind::Ident(_) | Kind::Punct(':')))?;
+    let is_macro = trees[..bang]
+        .iter()
+        .rev()
+        .find_map(|tree| match &tree.kind {
+            Kind::Ident(name) => Some(name == "polymorphic_constant"),
+            _ => None,
+        })
+        .unwrap_or(false);
+    match (is_macro && is_punct(trees.get(bang), '!'), trees.get(bang + 1)) {
+        (true, Some(Tree { kind: Kind::Group(_, body), .. })) => Some(body),
+        _ => None,
+    }
+}
+
+// The number of trees of an invocation at the start, with its optional `;`
+fn invocation_len(trees: &[Tree]) -> usize {
+    let bang = trees.iter().position(|tree| is_punct(Some(tree), '!')).unwrap_or(0);
+    if is_punct(trees.get(bang + 2), ';') {
+        bang + 3
+    } else {
+        bang + 2
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn text(&self, trees: &[Tree]) -> String {
+        match (trees.first(), trees.last()) {
+            (Some(first), Some(last)) => self.source[first.start..last.end].to_string(),
+            _ => String::new(),
+        }
+    }
+
+    fn error(&self, tree: Option<&Tree>, end: usize, message: impl Into<String>) -> ParseError {
+        error_at(self.source, tree.map_or(end, |tree| tree.start), message)
+    }
+
+    fn find(&self, trees: &[Tree], blocks: &mut Vec<Block>) -> Result<(), ParseError> {
+        for (i, tree) in trees.iter().enumerate() {
+            if let Kind::Group(_, inner) = &tree.kind {
+                self.find(inner, blocks)?;
+            }
+            if is_ident(Some(tree), "polymorphic_constant") && !is_punct(trees.get(i + 1), ':') {
+                if let Some(body) = invocation(&trees[i..]) {
+                    blocks.push(self.block(body)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn block(&self, trees: &[Tree]) -> Result<Block, ParseError> {
+        let end = trees.last().map_or(0, |tree| tree.end);
+        let mut block = Block::default();
+        let mut i = 0;
+        while i < trees.len() {
+            // Options of the invocation
+            if is_punct(trees.get(i), '#') && is_punct(trees.get(i + 1), '!') {
+                block.options.push(self.attribute(trees.get(i + 2), end)?);
+                i += 3;
+                continue;
+            }
+            // Constants of a `group` block, the attributes of the block being left to its struct
+            if let Some((group, body, next)) = self.group(&trees[i..]) {
+                for mut constant in self.block(body)?.constants {
+                    constant.group = Some(group.clone());
+                    block.constants.push(constant);
+                }
+                i += next;
+                continue;
+            }
+            let (constant, next) = self.definition(&trees[i..], end)?;
+            block.constants.push(constant);
+            i += next;
+        }
+        Ok(block)
+    }
+
+    fn attribute(&self, tree: Option<&Tree>, end: usize) -> Result<Attribute, ParseError> {
+        let inner = match tree {
+            Some(Tree { kind: Kind::Group('[', inner), .. }) => inner,
+            _ => return Err(self.error(tree, end, "expected `[` after `#`")),
+        };
+        let path = inner.iter().take_while(|tree| matches!(tree.kind, Kind::Ident(_) | Kind::Punct(':'))).count();
+        if path == 0 {
+            return Err(self.error(inner.first(), tree.unwrap().end, "expected the name of the attribute"));
+        }
+        let arguments = match &inner[path..] {
+            [] => None,
+            [Tree { kind: Kind::Group(_, arguments), .. }] => Some(self.text(arguments)),
+            [equal, value @ ..] if is_punct(Some(equal), '=') => Some(self.text(value)),
+            [other, ..] => return Err(self.error(Some(other), end, "unexpected tokens in the attribute")),
+        };
+        Ok(Attribute { name: self.text(&inner[..path]), arguments })
+    }
+
+    // Find a `group NAME { ... }` block at the start of the trees, giving back its name, body, and the number of trees read
+    fn group<'t>(&self, trees: &'t [Tree]) -> Option<(String, &'t [Tree], usize)> {
+        let mut i = 0;
+        loop {
+            match trees.get(i) {
+                Some(Tree { kind: Kind::Doc(_), .. }) => i += 1,
+                tree if is_punct(tree, '#') => i += 2,
+                _ => break,
+            }
+        }
+        if is_ident(trees.get(i), "pub") {
+            i += if matches!(trees.get(i + 1), Some(Tree { kind: Kind::Group('(', _), .. })) { 2 } else { 1 };
+        }
+        if !is_ident(trees.get(i), "group") {
+            return None;
+        }
+        match (trees.get(i + 1), trees.get(i + 2)) {
+            (Some(Tree { kind: Kind::Ident(name), .. }), Some(Tree { kind: Kind::Group('{', body), .. })) => {
+                Some((name.clone(), body, i + 3))
+            }
+            _ => None,
+        }
+    }
+
+    // Parse a constant at the start of the trees, giving back the number of trees read
+    fn definition(&self, trees: &[Tree], end: usize) -> Result<(Definition, usize), ParseError> {
+        let mut docs = Vec::new();
+        let mut attributes = Vec::new();
+        let mut i = 0;
+        loop {
+            match trees.get(i) {
+                Some(Tree { kind: Kind::Doc(doc), .. }) => {
+                    docs.push(doc.clone());
+                    i += 1;
+                }
+                tree if is_punct(tree, '#') => {
+                    attributes.push(self.attribute(trees.get(i + 1), end)?);
+                    i += 2;
+                }
+                _ => break,
+            }
+        }
+
+        let visibility = if is_ident(trees.get(i), "pub") {
+            let length = if matches!(trees.get(i + 1), Some(Tree { kind: Kind::Group('(', _), .. })) { 2 } else { 1 };
+            i += length;
+            Some(self.text(&trees[i - length..i]))
+        } else {
+            None
+        };
+
+        let is_static = is_ident(trees.get(i), "static");
+        if !is_static && !is_ident(trees.get(i), "const") {
+            return Err(self.error(trees.get(i), end, "expected `const` or `static`"));
+        }
+        let line = error_at(self.source, trees[i].start, "").line;
+        let name = match trees.get(i + 1) {
+            Some(Tree { kind: Kind::Ident(name), .. }) => name.clone(),
+            tree => return Err(self.error(tree, end, "expected the name of the constant")),
+        };
+        i += 2;
+
+        // Variants separated by `|`, if any
+        let mut variants = Vec::new();
+        if is_punct(trees.get(i), ':') {
+            i += 1;
+            loop {
+                match trees.get(i) {
+                    Some(Tree { kind: Kind::Ident(variant), .. }) => variants.push(variant.clone()),
+                    tree => return Err(self.error(tree, end, "expected a variant")),
+                }
+                i += 1;
+                if !is_punct(trees.get(i), '|') {
+                    break;
+                }
+                i += 1;
+            }
+        }
+        if !is_punct(trees.get(i), '=') {
+            return Err(self.error(trees.get(i), end, "expected `=`"));
+        }
+        i += 1;
+
+        let length = trees[i..].iter().position(|tree| is_punct(Some(tree), ';'));
+        let length = match length {
+            Some(0) => return Err(self.error(trees.get(i), end, "expected an initializer")),
+            Some(length) => length,
+            None => return Err(self.error(None, end, "expected `;`")),
+        };
+        let initializer = self.initializer(&trees[i..i + length]);
+        let definition = Definition { docs, attributes, visibility, name, variants, initializer, line, is_static, group: None };
+        Ok((definition, i + length + 1))
+    }
+
+    fn initializer(&self, trees: &[Tree]) -> Initializer {
+        let sign = is_punct(trees.first(), '-') as usize;
+        match (&trees[sign..], trees.first()) {
+            ([Tree { kind: Kind::Literal, .. }], _) => Initializer::Literal { value: self.text(trees), unit: None },
+            ([Tree { kind: Kind::Literal, .. }, unit @ Tree { kind: Kind::Ident(_), .. }], _) => Initializer::Literal {
+                value: self.text(&trees[..trees.len() - 1]),
+                unit: Some(self.text(std::slice::from_ref(unit))),
+            },
+            ([_, Tree { kind: Kind::Group('(', arguments), .. }], Some(function)) if sign == 0 && is_ident(Some(function), "pack") => {
+                Initializer::Pack(self.text(arguments))
+            }
+            ([_, Tree { kind: Kind::Group('(', arguments), .. }], Some(function)) if sign == 0 && is_ident(Some(function), "from_file") => {
+                Initializer::FromFile(self.text(arguments))
+            }
+            ([_, Tree { kind: Kind::Group('(', arguments), .. }], Some(function)) if sign == 0 && is_ident(Some(function), "ratio_of") => {
+                Initializer::RatioOf(self.text(arguments))
+            }
+            _ => Initializer::Expression(self.text(trees)),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use polymorphic_constant_syntax::{parse, parse_invocations, variant_kind, variants, Attribute, Initializer, VariantKind};
+use std::collections::BTreeSet;
+
+#[test]
+fn test_parse() {
+    let block = parse(
+        r#"
+        #![group(pub const ALL: u16 | u32)]
+
+        /// The size of a page
+        /// in bytes
+        #[cfg(unix)]
+        #[also(halved = HALF_PAGE)]
+        pub(crate) const PAGE: u16 | u32 = 4_096;
+        const NEGATIVE: i8 = -1e1;
+        // Not documentation
+        const LENGTH: f32 = 3.5 km;
+        const VERSION: u16 = pack(major: MAJOR, 8; minor: MINOR, 8);
+        const GAIN: u32 = from_file("calib.bin", 4, u32_le);
+        const DOUBLE = PAGE * 2;
+        const ASPECT: f32 = ratio_of(WIDTH, HEIGHT);
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        block.options,
+        [Attribute { name: "group".into(), arguments: Some("pub const ALL: u16 | u32".into()) }]
+    );
+    let page = &block.constants[0];
+    assert_eq!(page.docs, ["The size of a page", "in bytes"]);
+    assert_eq!(page.attributes[0], Attribute { name: "cfg".into(), arguments: Some("unix".into()) });
+    assert_eq!(page.visibility.as_deref(), Some("pub(crate)"));
+    assert_eq!(page.name, "PAGE");
+    assert_eq!(page.variants, ["u16", "u32"]);
+    assert_eq!(page.line, 8);
+
+    let initializers: Vec<_> = block.constants[1..].iter().map(|constant| constant.initializer.clone()).collect();
+    assert_eq!(
+        initializers,
+        [
+            Initializer::Literal { value: "-1e1".into(), unit: None },
+            Initializer::Literal { value: "3.5".into(), unit: Some("km".into()) },
+            Initializer::Pack("major: MAJOR, 8; minor: MINOR, 8".into()),
+            Initializer::FromFile(r#""calib.bin", 4, u32_le"#.into()),
+            Initializer::Expression("PAGE * 2".into()),
+            Initializer::RatioOf("WIDTH, HEIGHT".into()),
+        ]
+    );
+    assert!(block.constants[2].docs.is_empty());
+    assert!(block.constants[5].variants.is_empty());
+}
+
+#[test]
+fn test_invocations() {
+    let block = parse("polymorphic_constant::polymorphic_constant! { const ONE: u8 = 1; }").unwrap();
+    assert_eq!(block.constants[0].name, "ONE");
+
+    let blocks = parse_invocations(
+        r#"
+        use polymorphic_constant::polymorphic_constant;
+
+        polymorphic_constant! {
+            const ONE: u8 = 1;
+        }
+
+        mod nested {
+            polymorphic_constant! (const TWO: f32 = 2.0; const THREE: f64 = 3.0;);
+            const NAME: &str = "polymorphic_constant! { }";
+        }
+        "#,
+    )
+    .unwrap();
+    let names: Vec<Vec<&str>> = blocks
+        .iter()
+        .map(|block| block.constants.iter().map(|constant| constant.name.as_str()).collect())
+        .collect();
+    assert_eq!(names, [vec!["ONE"], vec!["TWO", "THREE"]]);
+}
+
+#[test]
+fn test_group() {
+    let block = parse(
+        r#"
+        const ONE: u8 = 1;
+        /// Sizes of the screen
+        pub group SCREEN {
+            const WIDTH: u16 = 320;
+            const HEIGHT: u16 = 200;
+        }
+        "#,
+    )
+    .unwrap();
+
+    let groups: Vec<_> = block.constants.iter().map(|constant| (constant.name.as_str(), constant.group.as_deref())).collect();
+    assert_eq!(groups, [("ONE", None), ("WIDTH", Some("SCREEN")), ("HEIGHT", Some("SCREEN"))]);
+}
+
+#[test]
+fn test_static() {
+    let block = parse("static REQUESTS: u32 | atomic_u32 = 0;
+const LIMIT: u32 = 8;").unwrap();
+
+    let statics: Vec<_> = block.constants.iter().map(|constant| (constant.name.as_str(), constant.is_static)).collect();
+    assert_eq!(statics, [("REQUESTS", true), ("LIMIT", false)]);
+    assert_eq!(variant_kind("atomic_u32"), Some(VariantKind::Integer));
+}
+
+#[test]
+fn test_errors() {
+    let error = parse("const ONE: u8 = 1;\nconst TWO: u8 2;").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 15));
+    assert_eq!(error.to_string(), "2:15: expected `=`");
+
+    let error = parse("const ONE: u8 = 1").unwrap_err();
+    assert_eq!(error.message, "expected `;`");
+
+    let error = parse("const ONE: u8 = (1;").unwrap_err();
+    assert_eq!(error.message, "unclosed delimiter, expected `)`");
+
+    let error = parse("fn one() {}").unwrap_err();
+    assert_eq!((error.line, error.column, error.message.as_str()), (1, 1, "expected `const` or `static`"));
+}
+
+#[test]
+fn test_variant_kind() {
+    assert_eq!(variant_kind("nz_u8"), Some(VariantKind::Integer));
+    assert_eq!(variant_kind("opt_nz_u32"), Some(VariantKind::Integer));
+    assert_eq!(variant_kind("u24"), Some(VariantKind::Integer));
+    assert_eq!(variant_kind("i256"), Some(VariantKind::Integer));
+    assert_eq!(variant_kind("nm_u32"), Some(VariantKind::Integer));
+    assert_eq!(variant_kind("c_int"), Some(VariantKind::Integer));
+    assert_eq!(variant_kind("f64"), Some(VariantKind::Float));
+    assert_eq!(variant_kind("c_f64"), Some(VariantKind::Float));
+    assert_eq!(variant_kind("nn_f32"), Some(VariantKind::Float));
+    assert_eq!(variant_kind("uom_velocity"), Some(VariantKind::Float));
+    assert_eq!(variant_kind("u8f24"), Some(VariantKind::Fixed));
+    assert_eq!(variant_kind("decimal"), Some(VariantKind::Decimal));
+    assert_eq!(variant_kind("str"), Some(VariantKind::Str));
+    assert_eq!(variant_kind("ratio_i64"), Some(VariantKind::Ratio));
+    assert_eq!(variant_kind("i9f8"), None);
+    assert_eq!(variant_kind("string"), None);
+}
+
+// The keywords the macro resolves to a type, read from its source
+fn macro_keywords() -> BTreeSet<&'static str> {
+    let mut keywords = BTreeSet::new();
+    for line in include_str!("../../src/lib.rs").lines().map(str::trim) {
+        let typed = line.strip_prefix("(@GET_TYPE ").or_else(|| line.strip_prefix("(@FIELD_TYPE $name:ident "));
+        if let Some((keyword, _)) = typed.and_then(|rest| rest.split_once(')')) {
+            keywords.insert(keyword.trim());
+        }
+        // The SIMD variants are recognized by `__simd_kind!`
+        if let Some((keyword, rest)) = line.strip_prefix('(').and_then(|line| line.split_once(' ')) {
+            if rest.trim_start().starts_with("$simd:tt") {
+                keywords.insert(keyword);
+            }
+        }
+        // The short names of the `nonmax` variants are resolved like aliases
+        for pair in line.split('(').skip(1) {
+            if let Some((alias, _)) = pair.split_once(" nonmax_").filter(|(alias, _)| alias.starts_with("nm_")) {
+                keywords.insert(alias);
+            }
+        }
+    }
+    keywords.retain(|keyword| !keyword.starts_with('$'));
+    keywords
+}
+
+#[test]
+fn test_variants_match_macro() {
+    // Routed to by the macro itself, depending on the enabled features
+    let internal = ["half_f16", "nightly_f16"];
+    let keywords = macro_keywords();
+
+    for keyword in &keywords {
+        assert!(variant_kind(keyword).is_some() || internal.contains(keyword), "`{}` is not listed", keyword);
+    }
+    for variant in variants() {
+        assert!(keywords.contains(variant), "`{}` is not a variant of the macro", variant);
+    }
+}
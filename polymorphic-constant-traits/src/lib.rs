@@ -0,0 +1,80 @@
+// Copyright 2020 Louis Garczynski
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![no_std]
+
+/*!
+The traits implemented by `polymorphic_constant!`, in a crate of their own so that libraries
+can name them in generic code without depending on a given version of the macro.
+
+`polymorphic-constant` re-exports them, and implements them for every constant it generates.
+*/
+
+/// Build a value from the variant of the polymorphic constant `C` of the same type
+///
+/// This is the reverse of the `Into` impls of the constant, so that generic code can bound the type it builds
+/// rather than the constant:
+/// ```
+/// use polymorphic_constant_traits::FromPolymorphic;
+///
+/// #[derive(Clone, Copy)]
+/// struct Pi;
+///
+/// impl FromPolymorphic<Pi> for f32 {
+///     fn from_polymorphic(_: Pi) -> Self {
+///         3.1415927
+///     }
+/// }
+///
+/// fn times_pi<T: FromPolymorphic<Pi> + core::ops::Mul<Output = T>>(value: T) -> T {
+///     value * T::from_polymorphic(Pi)
+/// }
+///
+/// assert_eq!(times_pi(2.0f32), 6.2831855);
+/// ```
+pub trait FromPolymorphic<C>: Sized {
+    /// Get the variant of the constant
+    fn from_polymorphic(constant: C) -> Self;
+}
+
+/// Build a polymorphic constant from the variants it shares with the wider constant `C`
+///
+/// Implemented by the `narrow_from` attribute, and used by the `narrow_to` method of the constants.
+pub trait NarrowFrom<C>: Sized {
+    /// The values of `C`, in the shape of `Self`
+    const NARROWED: Self;
+}
+
+/// The sign of a polymorphic constant, taken from its widest numeric variant
+///
+/// Using it fails to compile if the variants do not all have the same sign, as `saturate` or `round` may cause,
+/// so that generic code can branch on the sign without picking a type.
+pub trait Sign {
+    /// The ordering of the constant with zero, `None` for NaN
+    const SIGN: Option<core::cmp::Ordering>;
+    /// Whether the constant is zero
+    const IS_ZERO: bool = matches!(Self::SIGN, Some(core::cmp::Ordering::Equal));
+    /// Whether the constant is strictly positive
+    const IS_POSITIVE: bool = matches!(Self::SIGN, Some(core::cmp::Ordering::Greater));
+    /// Whether the constant is strictly negative
+    const IS_NEGATIVE: bool = matches!(Self::SIGN, Some(core::cmp::Ordering::Less));
+
+    /// Tell whether the constant is zero
+    fn is_zero(&self) -> bool {
+        Self::IS_ZERO
+    }
+
+    /// Tell whether the constant is strictly positive
+    fn is_positive(&self) -> bool {
+        Self::IS_POSITIVE
+    }
+
+    /// Tell whether the constant is strictly negative
+    fn is_negative(&self) -> bool {
+        Self::IS_NEGATIVE
+    }
+}
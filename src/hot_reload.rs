@@ -0,0 +1,95 @@
+/*!
+Reload the overrides of tunable constants from a file while the program is running.
+
+The file uses a subset of TOML, with one `NAME = value` entry per line:
+```toml
+# Tuning values
+SPEED = 2.5
+LIVES = 5
+```
+Comments, blank lines and table headers are ignored.
+
+```no_run
+use polymorphic_constant::{polymorphic_constant, reload_overrides};
+use polymorphic_constant::hot_reload::Watcher;
+
+polymorphic_constant! {
+    #[tunable]
+    const SPEED: f32 | f64 = 4.0;
+}
+
+let mut watcher = Watcher::new("tuning.toml");
+
+loop {
+    if let Ok(Some(source)) = watcher.poll() {
+        reload_overrides!(&source, [SPEED]);
+    }
+    # break;
+}
+```
+*/
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::string::String;
+use std::time::SystemTime;
+
+/// Iterate over the `NAME = value` entries of a file
+pub fn parse(source: &str) -> impl Iterator<Item = (&str, &str)> {
+    source.lines().filter_map(|line| {
+        // Strip comments, and skip table headers and malformed lines
+        let line = strip_comment(line).trim();
+        if line.starts_with('[') {
+            return None;
+        }
+        let mut entry = line.splitn(2, '=');
+        let name = entry.next()?.trim();
+        let value = entry.next()?.trim().trim_matches('"').trim();
+        if name.is_empty() || value.is_empty() {
+            return None;
+        }
+        Some((name, value))
+    })
+}
+
+// Cut the line at the first `#` outside of a quoted value
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Watch a file for changes, by polling its modification time
+#[derive(Debug)]
+pub struct Watcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl Watcher {
+    /// Watch the file at `path`, the first poll always reads it
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Watcher {
+            path: path.into(),
+            modified: None,
+        }
+    }
+
+    /// Read the file if it was modified since the last poll
+    pub fn poll(&mut self) -> io::Result<Option<String>> {
+        let modified = fs::metadata(&self.path)?.modified()?;
+
+        if self.modified == Some(modified) {
+            return Ok(None);
+        }
+        self.modified = Some(modified);
+        fs::read_to_string(&self.path).map(Some)
+    }
+}
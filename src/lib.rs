@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 #![no_std]
+#![forbid(unsafe_code)]
 
 /*!
 A macro to generate numerical constants in multiple types at once.
@@ -39,7 +40,7 @@ This is meant as a temporary fix to the year-long debate over [Rust Pre-RFC #133
 A few features are supported:
 
 ```rust
-    use polymorphic_constant::polymorphic_constant;
+    use polymorphic_constant::{polymorphic_constant, FromPolymorphic};
 
     polymorphic_constant! {
 
@@ -51,18 +52,27 @@ A few features are supported:
 
         // Nonzero numeric types (NonZeroI32, NonZeroU8, etc)
         const ASCII_LINE_RETURN: u8 | nz_u8 = 10;
+
+        // Optional nonzero types (Option<NonZeroU32>, etc)
+        const MAX_CONNECTIONS: u32 | opt_nz_u32 = 64;
+
+        // Raw identifiers, for names colliding with keywords
+        const r#type: u8 | u16 = 2;
+
+        // C types (c_int, c_ulong, c_size_t, etc), checked against their size on the target
+        const BUFFER_SIZE: c_int | c_size_t = 4096;
+
+        // The `real` float type, f32 or f64 depending on the `real-f64` feature
+        const GRAVITY: real = 9.80665;
     }
 
     // You can handle constants like any const struct
     const PI_COPY: PI = PI;
     const PI_F32: f32 = PI.f32;
     
-    // Into is implemented for every variant of the constant
-    fn times_pi<T: std::ops::Mul<T>> (value: T) -> <T as std::ops::Mul>::Output
-    where
-        PI: Into<T>,
-    {
-        value * PI.into()
+    // Into is implemented for every variant of the constant, and FromPolymorphic the other way around
+    fn times_pi<T: std::ops::Mul<T> + FromPolymorphic<PI>> (value: T) -> <T as std::ops::Mul>::Output {
+        value * T::from_polymorphic(PI)
     }
 
     assert_eq!(times_pi(2.0), 6.283185307179586f64);
@@ -110,6 +120,38 @@ Any incompatible type will prevent compilation:
     # }
 ```
 
+* The maximum cannot be stored in non-max types (`nonmax_u8`, `nonmax_u32`, etc, which require a dependency on `nonmax`).
+  They can be shortened to `nm_u8`, `nm_u32`, etc, resolved like aliases to the field of the `nonmax` variant
+```compile_fail
+    # use polymorphic_constant::polymorphic_constant;
+    
+    # polymorphic_constant! {
+        const FAILS: u8 | nonmax_u8 = 255;
+    # }
+```
+
+* Integers are compared with the exact range of each variant, up to the extremes of `i128` and `u128`,
+  even where the `overflowing_literals` lint is allowed, and computed values in the same way.
+  The error gives the nearest value of the variant, and how far it is from the initializer,
+  such as ``` `300` does not fit in `u8`, the nearest value is 255, off by 45 (15%) ```,
+  literals beyond `u128` included
+```compile_fail
+    # use polymorphic_constant::polymorphic_constant;
+    
+    # polymorphic_constant! {
+        const FAILS: u128 | i128 = 170141183460469231731687303715884105728;
+    # }
+```
+
+* C types are checked against their range on the target, as their width may differ between platforms
+```compile_fail
+    # use polymorphic_constant::polymorphic_constant;
+    
+    # polymorphic_constant! {
+        const FAILS: c_uint | c_int = 2147483648;
+    # }
+```
+
 * However, floats may lose precision, and a lot of it
 ```rust
     # use polymorphic_constant::polymorphic_constant;
@@ -119,6 +161,25 @@ Any incompatible type will prevent compilation:
     # }
 ```
 
+Every check is made during constant evaluation, through the checked constructors of each type.
+The generated code never relies on `unsafe`, so there is no unchecked precondition left to audit,
+and it can be used in crates with `#![forbid(unsafe_code)]`, except for the `nn_f32` and `nn_f64` variants,
+whose value is checked not to be NaN by a hidden const assertion, right before the `unsafe` constructor of `NotNan`.
+
+The `proof` attribute also states the precondition of each variant with a checked constructor as a named constant,
+`_PROOF_<NAME>_<variant>`, evaluated at compile time, so that auditing tools can check for them:
+non-zero variants are not zero, non-max variants are not their maximum, `u24` to `u56` variants fit in their bits,
+and `NotNan` variants are not NaN:
+```rust
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[proof]
+    const DIVISOR: u32 | nz_u32 = 60;
+}
+
+let () = _PROOF_DIVISOR_nz_u32;
+```
+
 # Warnings
 
 Currently, the same constant cannot hold both int and float variants
@@ -149,6 +210,81 @@ The constant also has to be initialized with an untyped literal
 It is still unclear if accepting the examples above could be dangerous,
 thus the conservative choice.
 
+Other initializers, such as calls to `const fn`, are computed once, then narrowed to each variant.
+The narrowing fails to compile if it would change the value, or convert it to infinity.
+Initializers starting with a `-` sign must be wrapped in parentheses:
+```
+# use polymorphic_constant::polymorphic_constant;
+const fn kibibytes(count: u64) -> u64 {
+    count * 1024
+}
+
+polymorphic_constant! {
+    const BUFFER: u16 | u32 | usize = kibibytes(16);
+}
+```
+```compile_fail
+    # use polymorphic_constant::polymorphic_constant;
+    # const fn kibibytes(count: u64) -> u64 { count * 1024 }
+    # polymorphic_constant! {
+        const FAIL: u16 | u32 = kibibytes(64);
+    # }
+```
+
+Constants declared without variants are derived from the constants of the same block they use,
+which must be declared before them. They get the variants common to every operand,
+and the result must fit in each of them:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const WIDTH: u16 | u32 | u64 = 320;
+    const HEIGHT: u16 | u32 = 200;
+    const AREA = WIDTH * HEIGHT;
+}
+
+assert_eq!(AREA.u32, 64_000);
+assert_eq!(AREA.u16, 64_000);
+```
+```compile_fail
+    # use polymorphic_constant::polymorphic_constant;
+    # polymorphic_constant! {
+    #     const WIDTH: u16 | u32 = 640;
+    #     const HEIGHT: u16 | u32 = 480;
+        const AREA = WIDTH * HEIGHT;
+    # }
+```
+
+They are computed from the `f64` variant of the operands if they all have one, and from their first
+common variant otherwise. The `strict` attribute computes them in `i128` instead, from the widest
+integer variant of each operand, so integer results are exact and only narrowed once,
+and the `strict` entry of [`polymorphic_config!`] applies it to every derived constant.
+Overflowing `i128` fails to compile, and every operand must have an integer variant other than `u128`.
+Constants with only float variants in common are computed as usual:
+```
+# use polymorphic_constant::polymorphic_constant;
+const fn pixels() -> u64 { 3_000_000_001 }
+
+polymorphic_constant! {
+    const PIXELS: u64 | f64 = pixels();
+    const LOOSE = PIXELS * PIXELS;
+    #[strict]
+    const EXACT = PIXELS * PIXELS;
+}
+
+assert_eq!(LOOSE.u64, 9_000_000_006_000_000_000);
+assert_eq!(EXACT.u64, 9_000_000_006_000_000_001);
+```
+
+The `from_file(path, offset, format)` initializer reads a number from a file embedded with `include_bytes!`,
+the path being relative to the current file. The format is `u8`, `i8`, or a wider type followed by
+its byte order, such as `u32_le` or `f64_be`. Reading past the end of the file fails to compile:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const GAIN: u32 | u64 = from_file("calib.bin", 4, u32_le);
+}
+```
+
 # Example
 
 ```
@@ -170,113 +306,9167 @@ fn main() {
 }
 ```
 
+# Features
+
+Optional integrations are enabled with cargo features of this crate.
+The generated code refers to the integrated crate directly, so the crate using the macro must depend on it as well.
+
+* `ufmt`: implement `ufmt::uDisplay` and `ufmt::uDebug` for constants with an integer variant, through the widest one
+* `hot-reload`: reload the overrides of `tunable` constants from a file at runtime, in debug builds (requires std)
+* `schemars`: implement `schemars::JsonSchema` for every constant, as a `const` schema holding the value of the widest variant (requires alloc)
+* `attribute`: re-export the `polymorphic_constants` attribute, applying the macro to every constant of a module
+* `bevy`: derive `bevy_reflect::Reflect` for every constant that is not deprecated and whose variants are all primitives, non-zero integers or durations, which also registers it with its `FromReflect` type data when the `auto_register` feature of `bevy_reflect` is enabled, as by default, so that reflection-based inspectors show it
+* `linkme`: register constants into a `linkme` distributed slice of [`Descriptor`], with the `register` option
+* `rkyv`: derive the `Archive`, `Serialize` and `Deserialize` traits of `rkyv` for every constant whose variants are all primitive, non-zero, C or duration types, with a `repr(C)` layout, so that they can be embedded in archived data. Constants with another variant, such as `nonmax`, `bounded`, `decimal` or `bigint`, with `custom` variants, or that are deprecated, are left without the derives
+* `bigint`: allow the `bigint` and `biguint` variants, holding values of any size with `num-bigint`
+* `approx`: implement `PartialEq` and the `AbsDiffEq`, `RelativeEq` and `UlpsEq` traits of `approx` between every constant and its float variants, both ways, for `assert_relative_eq!` and similar macros
+* `real-f64`: make `real` variants `f64` instead of `f32`, so that a crate can switch its precision with one feature forwarding to this one. `real` variants have no `Into` or `From` impls, which would conflict with the ones of the float variant they alias
+* `nightly`: allow the `f16` and `f128` variants, the crate using the macro must enable `#![feature(f16, f128)]`. They are checked for overflow like the other floats, but only support the `nearest` rounding, and go through `f32` and `f64` in a [`ConstValue`]
+* `half`: allow the `bf16` variants, and the `f16` variants without the `nightly` feature, holding the types of `half`, built from the value in `f64`. They only support the `nearest` rounding, and have no companions
+* `fixed`: allow the fixed-point variants of up to 32 bits, such as `i16f16` or `u8f24`, holding the types of `fixed`. Literals are scaled exactly and computed values through `f64`, both rounded to the nearest bits, and fail to compile if they do not fit. They only support the `nearest` rounding, and have no companions
+* `decimal`: allow the `decimal` variants, holding the `Decimal` type of `rust_decimal`. Literals must be held exactly, with at most 28 fractional digits, and computed values must be integers. They have no companions, and are not listed when iterating over the constant
+* `rational`: allow the `ratio` variants, such as `ratio_i64`, holding the `Ratio` type of `num-rational`. Literals are reduced exactly, and computed values must be integers. They have no companions, and are not listed when iterating over the constant
+* `complex`: allow the `c_f32` and `c_f64` variants, holding the `Complex` type of `num-complex`, with the value of the float variant as the real part. They have no companions, and are not listed when iterating over the constant
+* `ordered-float`: allow the `of_f32` and `of_f64` variants, holding the `OrderedFloat` type of `ordered-float`, and the `nn_f32` and `nn_f64` variants, holding its `NotNan` type, so that constants are used as keys of ordered containers. They have no companions, and are not listed when iterating over the constant
+* `arbitrary-int`: allow the `u24`, `u40`, `u48` and `u56` variants, holding the types of `arbitrary-int`. Values out of their range fail to compile, and they have no companions
+* `bnum`: allow the `u256` and `i256` variants, holding the `U256` and `I256` types of `bnum`, read from literals of up to 256 bits. They have no companions, and are not listed when iterating over the constant
+* `uom`: allow the quantity variants such as `uom_length` or `uom_velocity`, holding the `f64` quantities of `uom`, in the unit given by the `uom_unit` attribute. They have no companions, and are not listed when iterating over the constant
+* `portable_simd`: allow the SIMD variants such as `f32x4` or `u8x16`, holding the value in every lane of a `core::simd` vector, the crate using the macro must enable `#![feature(portable_simd)]`. They have no companions, and are not listed when iterating over the constant
+
+# Build scripts
+
+The companion crate `polymorphic-constant-build` builds the same definitions programmatically,
+and renders them to source code that build scripts can write to `OUT_DIR`.
+
+The companion crate `polymorphic-constant-syntax` goes the other way, parsing the definitions
+into a syntax tree for external tools, with the position of any error.
+
+The companion crate `polymorphic-constant-traits` holds the [`FromPolymorphic`], [`NarrowFrom`] and [`Sign`] traits,
+re-exported here, so that libraries can bound their generic code with them without depending on the macro.
+
 # Support
 
 I would love any feedback on usage, for future ameliorations and features.
 */
 
-/**
-Define one or more polymorphic numerical constants. A constant X of value 10, available in i32 and u32 will read:
-```
-# use polymorphic_constant::polymorphic_constant;
-polymorphic_constant! {
-    const X: i32 | u32 = 10;
+/// The value of one variant of a polymorphic constant, as yielded by iterating over the constant
+///
+/// ```
+/// # use polymorphic_constant::{polymorphic_constant, ConstValue};
+/// polymorphic_constant! {
+///     const HALF: f32 | f64 = 0.5;
+/// }
+///
+/// for (name, value) in HALF {
+///     match value {
+///         ConstValue::F32(v) => assert_eq!((name, v), ("f32", 0.5)),
+///         ConstValue::F64(v) => assert_eq!((name, v), ("f64", 0.5)),
+///         _ => unreachable!(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstValue {
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    F32(f32),
+    F64(f64),
+    NonZeroI8(core::num::NonZeroI8),
+    NonZeroI16(core::num::NonZeroI16),
+    NonZeroI32(core::num::NonZeroI32),
+    NonZeroI64(core::num::NonZeroI64),
+    NonZeroI128(core::num::NonZeroI128),
+    NonZeroIsize(core::num::NonZeroIsize),
+    NonZeroU8(core::num::NonZeroU8),
+    NonZeroU16(core::num::NonZeroU16),
+    NonZeroU32(core::num::NonZeroU32),
+    NonZeroU64(core::num::NonZeroU64),
+    NonZeroU128(core::num::NonZeroU128),
+    NonZeroUsize(core::num::NonZeroUsize),
 }
-```
-and be used like:
-```
-# use polymorphic_constant::polymorphic_constant;
-# polymorphic_constant! { const X: i32 | u32 = 10; }
-let x_i32 = X.i32;
-```
-*/
 
-#[macro_export(local_inner_macros)]
-macro_rules! polymorphic_constant {
-    // Handle the const (pub?) CONST format
-    ($(#[$attr:meta])* ($($vis:tt)*) const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($nextLine:tt)*) => {
+// Wrap every variant type into the matching value, and tag it with its type
+macro_rules! impl_from_for_const_value {
+    ($($variant:ident($numeric_type:ty)),*) => {
+        $(impl From<$numeric_type> for ConstValue {
+            fn from(value: $numeric_type) -> Self {
+                ConstValue::$variant(value)
+            }
+        })*
 
-        // Generate the struct to hold the constant
+        /// The type of a variant, among those a [`ConstValue`] can hold
+        ///
+        /// Every constant has a `variant_of` method, giving the type of its variant of a given Rust type, if any,
+        /// so that generic code can dispatch on the variants of a constant.
+        /// C types and `real` are tagged with the primitive they stand for:
+        /// ```
+        /// # use polymorphic_constant::{polymorphic_constant, NumType};
+        /// polymorphic_constant! {
+        ///     const LIMIT: u8 | c_int | u64 = 16;
+        /// }
+        ///
+        /// assert_eq!(LIMIT::variant_of::<u8>(), Some(NumType::U8));
+        /// assert_eq!(LIMIT::variant_of::<i32>(), Some(NumType::I32));
+        /// assert_eq!(LIMIT::variant_of::<u16>(), None);
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum NumType {
+            $($variant),*
+        }
 
-        // Remove warnings
-        #[allow(non_camel_case_types)]
-        // Derive the common traits, but only if std is available
-        #[cfg_attr(not(no_std), derive(Debug, Clone, Copy))]
-        // Expend the attributes passed by the user
-        $(#[$attr])*
-        // Add the visibility attributes
-        $($vis)*
-        // Create the struct
-        struct $name {
-            // For each type (f32, ...) create a new property
-            $($numeric_type: __nz_impl!(@GET_TYPE $numeric_type),)*
+        impl NumType {
+            /// Get the tag of a primitive number or non-zero type, or `None` for any other type
+            pub fn of<T: 'static>() -> Option<Self> {
+                let id = core::any::TypeId::of::<T>();
+                $(if id == core::any::TypeId::of::<$numeric_type>() {
+                    return Some(NumType::$variant);
+                })*
+                None
+            }
         }
 
-        // Implement `into` for every type
-        $(impl ::core::convert::Into<__nz_impl!(@GET_TYPE $numeric_type)> for $name {
-            fn into(self) -> __nz_impl!(@GET_TYPE $numeric_type) {
-                self.$numeric_type
+        impl ConstValue {
+            /// Get the type of the wrapped value
+            pub const fn num_type(&self) -> NumType {
+                match self {
+                    $(ConstValue::$variant(_) => NumType::$variant),*
+                }
             }
-        })*
+        }
+    };
+}
 
-        // Expand the visibility, this time for the constant
-        $($vis)*
-        // Instantiate the struct and create the constant
-        const $name: $name = $name {
-            $($numeric_type: __nz_impl!(@MAKE_VAL $lit, $numeric_type ),)*
-        };
-        // Keep munching until the next ;
-        polymorphic_constant!($($nextLine)*);
+impl_from_for_const_value!(
+    I8(i8), I16(i16), I32(i32), I64(i64), I128(i128), Isize(isize),
+    U8(u8), U16(u16), U32(u32), U64(u64), U128(u128), Usize(usize),
+    F32(f32), F64(f64),
+    NonZeroI8(core::num::NonZeroI8), NonZeroI16(core::num::NonZeroI16),
+    NonZeroI32(core::num::NonZeroI32), NonZeroI64(core::num::NonZeroI64),
+    NonZeroI128(core::num::NonZeroI128), NonZeroIsize(core::num::NonZeroIsize),
+    NonZeroU8(core::num::NonZeroU8), NonZeroU16(core::num::NonZeroU16),
+    NonZeroU32(core::num::NonZeroU32), NonZeroU64(core::num::NonZeroU64),
+    NonZeroU128(core::num::NonZeroU128), NonZeroUsize(core::num::NonZeroUsize)
+);
+
+impl ConstValue {
+    // Wrap a C type variant, whose width and signedness depend on the target, in const contexts
+    #[doc(hidden)]
+    pub const fn __from_ffi(value: i128, size: usize, signed: bool) -> Self {
+        match (size, signed) {
+            (1, true) => ConstValue::I8(value as i8),
+            (2, true) => ConstValue::I16(value as i16),
+            (4, true) => ConstValue::I32(value as i32),
+            (8, true) => ConstValue::I64(value as i64),
+            (1, false) => ConstValue::U8(value as u8),
+            (2, false) => ConstValue::U16(value as u16),
+            (4, false) => ConstValue::U32(value as u32),
+            (8, false) => ConstValue::U64(value as u64),
+            (_, true) => ConstValue::I128(value),
+            (_, false) => ConstValue::U128(value as u128),
+        }
+    }
+}
+
+/// The name, module and variants of a polymorphic constant, as registered by the `register` option
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Descriptor {
+    /// The name of the constant
+    pub name: &'static str,
+    /// The module defining the constant, as given by `module_path!`
+    pub module: &'static str,
+    /// The name and value of every variant
+    pub variants: &'static [(&'static str, ConstValue)],
+}
+
+// Display the wrapped value alone, with the options of the formatter
+impl core::fmt::Display for ConstValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConstValue::I8(value) => value.fmt(f),
+            ConstValue::I16(value) => value.fmt(f),
+            ConstValue::I32(value) => value.fmt(f),
+            ConstValue::I64(value) => value.fmt(f),
+            ConstValue::I128(value) => value.fmt(f),
+            ConstValue::Isize(value) => value.fmt(f),
+            ConstValue::U8(value) => value.fmt(f),
+            ConstValue::U16(value) => value.fmt(f),
+            ConstValue::U32(value) => value.fmt(f),
+            ConstValue::U64(value) => value.fmt(f),
+            ConstValue::U128(value) => value.fmt(f),
+            ConstValue::Usize(value) => value.fmt(f),
+            ConstValue::F32(value) => value.fmt(f),
+            ConstValue::F64(value) => value.fmt(f),
+            ConstValue::NonZeroI8(value) => value.fmt(f),
+            ConstValue::NonZeroI16(value) => value.fmt(f),
+            ConstValue::NonZeroI32(value) => value.fmt(f),
+            ConstValue::NonZeroI64(value) => value.fmt(f),
+            ConstValue::NonZeroI128(value) => value.fmt(f),
+            ConstValue::NonZeroIsize(value) => value.fmt(f),
+            ConstValue::NonZeroU8(value) => value.fmt(f),
+            ConstValue::NonZeroU16(value) => value.fmt(f),
+            ConstValue::NonZeroU32(value) => value.fmt(f),
+            ConstValue::NonZeroU64(value) => value.fmt(f),
+            ConstValue::NonZeroU128(value) => value.fmt(f),
+            ConstValue::NonZeroUsize(value) => value.fmt(f),
+        }
+    }
+}
+
+/// How [`ConstValue::cast`] handles values that the target type cannot hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CastMode {
+    /// Give `None` for values out of the range of the target, truncating floats toward zero
+    Checked,
+    /// Clamp values to the range of the target, truncating floats toward zero, and NaN to zero for integers
+    Saturating,
+    /// Wrap integers around the range of the target, truncating floats toward zero.
+    /// Float targets have no wrapping, and saturate instead
+    Wrapping,
+    /// Give `None` unless the target holds the exact same value
+    Exact,
+}
+
+/// A primitive number that a [`ConstValue`] can be cast to, implemented for every integer and float
+pub trait Cast: Sized {
+    #[doc(hidden)]
+    fn __from_i128(value: i128, mode: CastMode) -> Option<Self>;
+    #[doc(hidden)]
+    fn __from_u128(value: u128, mode: CastMode) -> Option<Self>;
+    #[doc(hidden)]
+    fn __from_f64(value: f64, mode: CastMode) -> Option<Self>;
+}
+
+// Truncate a float toward zero, then wrap it around the range of `u128`, infinities and NaN giving zero
+fn wrap_f64(value: f64) -> u128 {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let magnitude = match exponent {
+        -1075 => 0,
+        128.. => 0,
+        0.. => (mantissa as u128) << exponent,
+        -63.. => (mantissa >> -exponent) as u128,
+        _ => 0,
     };
+    if bits >> 63 == 1 {
+        magnitude.wrapping_neg()
+    } else {
+        magnitude
+    }
+}
 
-    // Handle `const CONST` format
-    ($(#[$attr:meta])* const $($t:tt)*) => {
-        // use `()` to explicitly forward the information about private items
-        polymorphic_constant!($(#[$attr])* () const $($t)*);
+// Convert between integers with the standard library, and from floats through the integer truncating them
+macro_rules! impl_cast_for_int {
+    ($($numeric_type:ident),*) => {
+        $(impl Cast for $numeric_type {
+            fn __from_i128(value: i128, mode: CastMode) -> Option<Self> {
+                match mode {
+                    CastMode::Checked | CastMode::Exact => <$numeric_type as core::convert::TryFrom<_>>::try_from(value).ok(),
+                    CastMode::Saturating => Some(<$numeric_type as core::convert::TryFrom<_>>::try_from(value).unwrap_or(if value < 0 { $numeric_type::MIN } else { $numeric_type::MAX })),
+                    CastMode::Wrapping => Some(value as $numeric_type),
+                }
+            }
+
+            fn __from_u128(value: u128, mode: CastMode) -> Option<Self> {
+                match mode {
+                    CastMode::Checked | CastMode::Exact => <$numeric_type as core::convert::TryFrom<_>>::try_from(value).ok(),
+                    CastMode::Saturating => Some(<$numeric_type as core::convert::TryFrom<_>>::try_from(value).unwrap_or($numeric_type::MAX)),
+                    CastMode::Wrapping => Some(value as $numeric_type),
+                }
+            }
+
+            fn __from_f64(value: f64, mode: CastMode) -> Option<Self> {
+                let exact = mode == CastMode::Exact;
+                match mode {
+                    CastMode::Saturating => Some(value as $numeric_type),
+                    CastMode::Wrapping => Some(wrap_f64(value) as $numeric_type),
+                    // The bounds are the exact powers of two, and exclude NaN
+                    _ if (0.0..u128::MAX as f64).contains(&value) => {
+                        let truncated = value as u128;
+                        if exact && truncated as f64 != value {
+                            return None;
+                        }
+                        Self::__from_u128(truncated, CastMode::Checked)
+                    }
+                    _ if (i128::MIN as f64..0.0).contains(&value) => {
+                        let truncated = value as i128;
+                        if exact && truncated as f64 != value {
+                            return None;
+                        }
+                        Self::__from_i128(truncated, CastMode::Checked)
+                    }
+                    _ => None,
+                }
+            }
+        })*
     };
-    // Handle `pub const CONST` format
-    ($(#[$attr:meta])* pub const $($t:tt)*) => {
-        polymorphic_constant!($(#[$attr])* (pub) const $($t)*);
+}
+
+impl_cast_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Convert to floats by rounding to the nearest value, checking that it is finite and round-trips when required
+macro_rules! impl_cast_for_float {
+    ($($numeric_type:ident),*) => {
+        $(impl Cast for $numeric_type {
+            fn __from_i128(value: i128, mode: CastMode) -> Option<Self> {
+                let result = value as $numeric_type;
+                match mode {
+                    CastMode::Checked => Some(result).filter(|result| result.is_finite()),
+                    // The bound is the exact power of two above the maximum, which saturates when converted back
+                    CastMode::Exact => Some(result).filter(|&result| result < i128::MAX as $numeric_type && result as i128 == value),
+                    CastMode::Saturating | CastMode::Wrapping => Some(result.clamp($numeric_type::MIN, $numeric_type::MAX)),
+                }
+            }
+
+            fn __from_u128(value: u128, mode: CastMode) -> Option<Self> {
+                let result = value as $numeric_type;
+                match mode {
+                    CastMode::Checked => Some(result).filter(|result| result.is_finite()),
+                    CastMode::Exact => Some(result).filter(|&result| result < u128::MAX as $numeric_type && result as u128 == value),
+                    CastMode::Saturating | CastMode::Wrapping => Some(result.clamp($numeric_type::MIN, $numeric_type::MAX)),
+                }
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            fn __from_f64(value: f64, mode: CastMode) -> Option<Self> {
+                let result = value as $numeric_type;
+                match mode {
+                    CastMode::Checked => Some(result).filter(|result| result.is_finite() || !value.is_finite()),
+                    CastMode::Exact => Some(result).filter(|&result| result as f64 == value),
+                    CastMode::Saturating | CastMode::Wrapping if value.is_finite() => Some(result.clamp($numeric_type::MIN, $numeric_type::MAX)),
+                    CastMode::Saturating | CastMode::Wrapping => Some(result),
+                }
+            }
+        })*
     };
-    // Handle `pub (crate) CONST` format and similar
-    ($(#[$attr:meta])* pub ($($vis:tt)+) const $($t:tt)*) => {
-        polymorphic_constant!($(#[$attr])* (pub ($($vis)+)) const $($t)*);
+}
+
+impl_cast_for_float!(f32, f64);
+
+impl ConstValue {
+    /// Convert the value to any primitive number, with the given mode.
+    /// `Saturating` and `Wrapping` always give a value, and floats are truncated toward zero unless `Exact`
+    pub fn cast<T: Cast>(self, mode: CastMode) -> Option<T> {
+        match self {
+            ConstValue::I8(value) => T::__from_i128(value as i128, mode),
+            ConstValue::I16(value) => T::__from_i128(value as i128, mode),
+            ConstValue::I32(value) => T::__from_i128(value as i128, mode),
+            ConstValue::I64(value) => T::__from_i128(value as i128, mode),
+            ConstValue::I128(value) => T::__from_i128(value, mode),
+            ConstValue::Isize(value) => T::__from_i128(value as i128, mode),
+            ConstValue::U8(value) => T::__from_u128(value as u128, mode),
+            ConstValue::U16(value) => T::__from_u128(value as u128, mode),
+            ConstValue::U32(value) => T::__from_u128(value as u128, mode),
+            ConstValue::U64(value) => T::__from_u128(value as u128, mode),
+            ConstValue::U128(value) => T::__from_u128(value, mode),
+            ConstValue::Usize(value) => T::__from_u128(value as u128, mode),
+            ConstValue::F32(value) => T::__from_f64(value as f64, mode),
+            ConstValue::F64(value) => T::__from_f64(value, mode),
+            ConstValue::NonZeroI8(value) => T::__from_i128(value.get() as i128, mode),
+            ConstValue::NonZeroI16(value) => T::__from_i128(value.get() as i128, mode),
+            ConstValue::NonZeroI32(value) => T::__from_i128(value.get() as i128, mode),
+            ConstValue::NonZeroI64(value) => T::__from_i128(value.get() as i128, mode),
+            ConstValue::NonZeroI128(value) => T::__from_i128(value.get(), mode),
+            ConstValue::NonZeroIsize(value) => T::__from_i128(value.get() as i128, mode),
+            ConstValue::NonZeroU8(value) => T::__from_u128(value.get() as u128, mode),
+            ConstValue::NonZeroU16(value) => T::__from_u128(value.get() as u128, mode),
+            ConstValue::NonZeroU32(value) => T::__from_u128(value.get() as u128, mode),
+            ConstValue::NonZeroU64(value) => T::__from_u128(value.get() as u128, mode),
+            ConstValue::NonZeroU128(value) => T::__from_u128(value.get(), mode),
+            ConstValue::NonZeroUsize(value) => T::__from_u128(value.get() as u128, mode),
+        }
+    }
+}
+
+// Name `Cow` in the `schemars` impls, from crates that may be `no_std`
+#[cfg(feature = "schemars")]
+extern crate alloc;
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+pub use alloc::borrow::Cow as __Cow;
+
+// Select the narrowest integer type by its signedness and size, for `smallest_uint` and `smallest_int`
+#[doc(hidden)]
+pub struct __Smallest<const SIGNED: bool, const BYTES: usize>;
+
+#[doc(hidden)]
+pub trait __Pick {
+    type Type;
+}
+
+impl __Pick for __Smallest<false, 1> { type Type = u8; }
+impl __Pick for __Smallest<false, 2> { type Type = u16; }
+impl __Pick for __Smallest<false, 4> { type Type = u32; }
+impl __Pick for __Smallest<false, 8> { type Type = u64; }
+impl __Pick for __Smallest<false, 16> { type Type = u128; }
+impl __Pick for __Smallest<true, 1> { type Type = i8; }
+impl __Pick for __Smallest<true, 2> { type Type = i16; }
+impl __Pick for __Smallest<true, 4> { type Type = i32; }
+impl __Pick for __Smallest<true, 8> { type Type = i64; }
+impl __Pick for __Smallest<true, 16> { type Type = i128; }
+
+// Select a mutable or constant pointer to the register type, for the `ptr` variants
+#[doc(hidden)]
+pub struct __Pointer<T, const CONST: bool>(core::marker::PhantomData<T>);
+
+impl<T> __Pick for __Pointer<T, false> { type Type = *mut T; }
+impl<T> __Pick for __Pointer<T, true> { type Type = *const T; }
+
+// Get the size of the narrowest integer type holding a value
+#[doc(hidden)]
+pub const fn __smallest_bytes(value: i128, signed: bool) -> usize {
+    let mut bytes = 1;
+    while bytes < 16 {
+        let bits = bytes as u32 * 8;
+        let (min, max) = if signed {
+            (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+        } else {
+            (0, (1i128 << bits) - 1)
+        };
+        if value >= min && value <= max {
+            return bytes;
+        }
+        bytes *= 2;
+    }
+    16
+}
+
+// Compare the names of the variants in const contexts, for `has_variant!`
+#[doc(hidden)]
+pub const fn __has_variant(variants: &[&str], variant: &str) -> bool {
+    let mut i = 0;
+    while i < variants.len() {
+        if __str_eq(variants[i], variant) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+// Compare two strings in const contexts
+#[doc(hidden)]
+pub const fn __str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The field of a variant omitted by the `prune_unfit` attribute, as it cannot hold the literal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pruned;
+
+// Select the type of a variant by whether it holds the literal, for `prune_unfit`
+#[doc(hidden)]
+pub struct __Fit<const FITS: bool>;
+
+#[doc(hidden)]
+pub trait __Prune<T> {
+    type Type;
+}
+
+impl<T> __Prune<T> for __Fit<true> { type Type = T; }
+impl<T> __Prune<T> for __Fit<false> { type Type = Pruned; }
+
+// The value of a variant, only evaluated when it fits, implemented by each pruned constant
+#[doc(hidden)]
+pub trait __PruneValue<T, const FITS: bool>
+where
+    __Fit<FITS>: __Prune<T>,
+{
+    const VALUE: <__Fit<FITS> as __Prune<T>>::Type;
+}
+
+// Tell whether a variant holds the literal, from the table of a pruned constant
+#[doc(hidden)]
+pub const fn __fits_variant(fits: &[(&str, bool)], variant: &str) -> bool {
+    let mut i = 0;
+    while i < fits.len() {
+        if __str_eq(fits[i].0, variant) {
+            return fits[i].1;
+        }
+        i += 1;
+    }
+    false
+}
+
+// Count the variants kept, or pruned, by `prune_unfit`
+#[doc(hidden)]
+pub const fn __count_fits(fits: &[(&str, bool)], kept: bool) -> usize {
+    let (mut count, mut i) = (0, 0);
+    while i < fits.len() {
+        if fits[i].1 == kept {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+// List the variants kept, or pruned, by `prune_unfit`, `N` being their count
+#[doc(hidden)]
+pub const fn __list_fits<const N: usize>(fits: &[(&'static str, bool)], kept: bool) -> [&'static str; N] {
+    let mut names = [""; N];
+    let (mut count, mut i) = (0, 0);
+    while i < fits.len() {
+        if fits[i].1 == kept {
+            names[count] = fits[i].0;
+            count += 1;
+        }
+        i += 1;
+    }
+    names
+}
+
+// Get the nanoseconds in a unit of `duration` variants, in const contexts
+#[doc(hidden)]
+pub const fn __duration_nanos(unit: &str) -> u64 {
+    if __str_eq(unit, "ns") {
+        1
+    } else if __str_eq(unit, "us") {
+        1_000
+    } else if __str_eq(unit, "ms") {
+        1_000_000
+    } else if __str_eq(unit, "s") {
+        1_000_000_000
+    } else if __str_eq(unit, "min") {
+        60_000_000_000
+    } else if __str_eq(unit, "h") {
+        3_600_000_000_000
+    } else {
+        panic!("`duration` variants require a unit among `ns`, `us`, `ms`, `s`, `min` and `h`")
+    }
+}
+
+/// Get the factor converting a `uom` unit of a quantity to its base unit, as a numerator and a denominator,
+/// the empty unit being the base unit
+#[doc(hidden)]
+pub const fn __uom_factor(quantity: &str, unit: &str) -> (f64, f64) {
+    if unit.is_empty() {
+        return (1.0, 1.0);
+    }
+    if __str_eq(quantity, "length") && __str_eq(unit, "meter") {
+        (1.0, 1.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "kilometer") {
+        (1_000.0, 1.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "centimeter") {
+        (1.0, 100.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "millimeter") {
+        (1.0, 1_000.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "micrometer") {
+        (1.0, 1_000_000.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "nanometer") {
+        (1.0, 1_000_000_000.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "inch") {
+        (0.0254, 1.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "foot") {
+        (0.3048, 1.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "yard") {
+        (0.9144, 1.0)
+    } else if __str_eq(quantity, "length") && __str_eq(unit, "mile") {
+        (1_609.344, 1.0)
+    } else if __str_eq(quantity, "mass") && __str_eq(unit, "kilogram") {
+        (1.0, 1.0)
+    } else if __str_eq(quantity, "mass") && __str_eq(unit, "gram") {
+        (1.0, 1_000.0)
+    } else if __str_eq(quantity, "mass") && __str_eq(unit, "milligram") {
+        (1.0, 1_000_000.0)
+    } else if __str_eq(quantity, "mass") && __str_eq(unit, "ton") {
+        (1_000.0, 1.0)
+    } else if __str_eq(quantity, "mass") && __str_eq(unit, "pound") {
+        // Rounded as in `uom`, so that the value is read back in the same unit
+        (0.453_592_4, 1.0)
+    } else if __str_eq(quantity, "mass") && __str_eq(unit, "ounce") {
+        (0.028_349_52, 1.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "second") {
+        (1.0, 1.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "millisecond") {
+        (1.0, 1_000.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "microsecond") {
+        (1.0, 1_000_000.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "nanosecond") {
+        (1.0, 1_000_000_000.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "minute") {
+        (60.0, 1.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "hour") {
+        (3_600.0, 1.0)
+    } else if __str_eq(quantity, "time") && __str_eq(unit, "day") {
+        (86_400.0, 1.0)
+    } else if __str_eq(quantity, "velocity") && __str_eq(unit, "meter_per_second") {
+        (1.0, 1.0)
+    } else if __str_eq(quantity, "velocity") && __str_eq(unit, "kilometer_per_second") {
+        (1_000.0, 1.0)
+    } else if __str_eq(quantity, "velocity") && __str_eq(unit, "kilometer_per_hour") {
+        (1_000.0, 3_600.0)
+    } else if __str_eq(quantity, "velocity") && __str_eq(unit, "mile_per_hour") {
+        (1_609.344, 3_600.0)
+    } else if __str_eq(quantity, "velocity") && __str_eq(unit, "foot_per_second") {
+        (0.3048, 1.0)
+    } else if __str_eq(quantity, "velocity") && __str_eq(unit, "knot") {
+        (1_852.0, 3_600.0)
+    } else if __str_eq(quantity, "acceleration") && __str_eq(unit, "meter_per_second_squared") {
+        (1.0, 1.0)
+    } else if __str_eq(quantity, "acceleration") && __str_eq(unit, "foot_per_second_squared") {
+        (0.3048, 1.0)
+    } else if __str_eq(quantity, "acceleration") && __str_eq(unit, "standard_gravity") {
+        (9.806_65, 1.0)
+    } else if __str_eq(quantity, "frequency") && __str_eq(unit, "hertz") {
+        (1.0, 1.0)
+    } else if __str_eq(quantity, "frequency") && __str_eq(unit, "kilohertz") {
+        (1_000.0, 1.0)
+    } else if __str_eq(quantity, "frequency") && __str_eq(unit, "megahertz") {
+        (1_000_000.0, 1.0)
+    } else if __str_eq(quantity, "frequency") && __str_eq(unit, "gigahertz") {
+        (1_000_000_000.0, 1.0)
+    } else {
+        panic!("unknown `uom_unit` for the quantity of the variant")
+    }
+}
+
+/// The float type of `real` variants, `f32` by default and `f64` with the `real-f64` feature
+#[cfg(not(feature = "real-f64"))]
+pub type Real = f32;
+
+/// The float type of `real` variants, `f32` by default and `f64` with the `real-f64` feature
+#[cfg(feature = "real-f64")]
+pub type Real = f64;
+
+/// A value built from a function each time it is read, for the `bigint` and `biguint` variants,
+/// which cannot be constructed in const contexts
+pub struct Lazy<T> {
+    init: fn() -> T,
+}
+
+impl<T> Lazy<T> {
+    /// Wrap the function building the value
+    pub const fn new(init: fn() -> T) -> Self {
+        Lazy { init }
+    }
+
+    /// Build the value
+    pub fn get(&self) -> T {
+        (self.init)()
+    }
+}
+
+// Implemented by hand, the derives requiring the same traits of the built type
+impl<T> Clone for Lazy<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Lazy<T> {}
+
+// Compare and display the built values, rather than the functions
+impl<T: core::fmt::Debug> core::fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Lazy<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+// Check an integer literal of any size, for `bigint` and `biguint` variants,
+// giving back whether it is negative, the start of its digits and their radix
+#[doc(hidden)]
+pub const fn __int_literal(literal: &str, unsigned: bool) -> (bool, usize, u32) {
+    let bytes = literal.as_bytes();
+    let negative = __is_negative(literal);
+    let mut i = negative as usize;
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    if negative && unsigned {
+        panic!("`biguint` variants cannot hold a negative value");
+    }
+    let radix = if i + 1 < bytes.len() && bytes[i] == b'0' {
+        match bytes[i + 1] {
+            b'x' => 16,
+            b'o' => 8,
+            b'b' => 2,
+            _ => 10,
+        }
+    } else {
+        10
     };
-    () => {};
+    if radix != 10 {
+        i += 2;
+    }
+    while i < bytes.len() && bytes[i] == b'_' {
+        i += 1;
+    }
+    let start = i;
+    let mut digits = 0;
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => 16,
+        };
+        if digit as u32 >= radix {
+            panic!("integer variants require an integer literal without suffix");
+        }
+        digits += 1;
+        i += 1;
+    }
+    if digits == 0 {
+        panic!("integer variants require an integer literal without suffix");
+    }
+    (negative, start, radix)
 }
 
-#[macro_export]
+// Read an integer literal as its sign and magnitude, for the range checks of integer variants,
+// the magnitude being `None` beyond `u128`, which no primitive can hold
 #[doc(hidden)]
-macro_rules! __nz_impl {
-    // constally obtain a nonzero struct
-    // Surprisingly fails to compile if $lit is 0 or not in range
-    (@MAKE_VAL $lit:literal, nz_i8   ) => { unsafe { ::std::num::NonZeroI8::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_i16  ) => { unsafe { ::std::num::NonZeroI16::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_i32  ) => { unsafe { ::std::num::NonZeroI32::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_i64  ) => { unsafe { ::std::num::NonZeroI64::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_i128 ) => { unsafe { ::std::num::NonZeroI128::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_isize) => { unsafe { ::std::num::NonZeroIsize::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_u8   ) => { unsafe { ::std::num::NonZeroU8::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_u16  ) => { unsafe { ::std::num::NonZeroU16::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_u32  ) => { unsafe { ::std::num::NonZeroU32::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_u64  ) => { unsafe { ::std::num::NonZeroU64::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_u128 ) => { unsafe { ::std::num::NonZeroU128::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, nz_usize) => { unsafe { ::std::num::NonZeroUsize::new_unchecked($lit) } };
-    (@MAKE_VAL $lit:literal, $numeric_type:ident) => { $lit };
+pub const fn __int_value(literal: &str) -> (bool, Option<u128>) {
+    let bytes = literal.as_bytes();
+    let (negative, mut i, radix) = __int_literal(literal, false);
+    let mut magnitude = Some(0u128);
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        magnitude = match magnitude {
+            Some(value) => match value.checked_mul(radix as u128) {
+                Some(value) => value.checked_add(digit as u128),
+                None => None,
+            },
+            None => None,
+        };
+        i += 1;
+    }
+    (negative, magnitude)
+}
 
-    // Get the full nonzero type from shorthand
-    // Fails in nonstd
-    (@GET_TYPE nz_i8   ) => { ::std::num::NonZeroI8 };
-    (@GET_TYPE nz_i16  ) => { ::std::num::NonZeroI16 };
-    (@GET_TYPE nz_i32  ) => { ::std::num::NonZeroI32 };
-    (@GET_TYPE nz_i64  ) => { ::std::num::NonZeroI64 };
-    (@GET_TYPE nz_i128 ) => { ::std::num::NonZeroI128 };
-    (@GET_TYPE nz_isize) => { ::std::num::NonZeroIsize };
-    (@GET_TYPE nz_u8   ) => { ::std::num::NonZeroU8 };
-    (@GET_TYPE nz_u16  ) => { ::std::num::NonZeroU16 };
-    (@GET_TYPE nz_u32  ) => { ::std::num::NonZeroU32 };
-    (@GET_TYPE nz_u64  ) => { ::std::num::NonZeroU64 };
-    (@GET_TYPE nz_u128 ) => { ::std::num::NonZeroU128 };
-    (@GET_TYPE nz_usize) => { ::std::num::NonZeroUsize };
-    (@GET_TYPE $numeric_type:ident) => { $numeric_type };
+// The most limbs of a `limbs` variant, for values of up to 4096 bits
+const MAX_LIMBS: usize = 64;
+
+// Read how far an integer literal beyond `u128` exceeds `u128::MAX`, for the errors of the integer variants,
+// as the high limbs above `u128` less one, times 2^128, plus the low limbs plus one
+#[doc(hidden)]
+pub const fn __int_excess(literal: &str) -> f64 {
+    let limbs = match read_limbs::<MAX_LIMBS>(literal) {
+        Some(limbs) => limbs,
+        None => return f64::INFINITY,
+    };
+    let mut high = 0.0;
+    let mut k = MAX_LIMBS;
+    while k > 2 {
+        k -= 1;
+        high = high * 18_446_744_073_709_551_616.0 + limbs[k] as f64;
+    }
+    let low = (limbs[1] as u128) << 64 | limbs[0] as u128;
+    (high - 1.0) * ((1u128 << 127) as f64 * 2.0) + low as f64 + 1.0
+}
+
+// Read the magnitude of an integer literal of any size as little-endian `u64` limbs,
+// which is `None` if it needs more than `N` limbs
+const fn read_limbs<const N: usize>(literal: &str) -> Option<[u64; N]> {
+    let bytes = literal.as_bytes();
+    let (_, mut i, radix) = __int_literal(literal, false);
+    let mut limbs = [0u64; N];
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        let mut carry = digit as u128;
+        let mut k = 0;
+        while k < N {
+            let wide = limbs[k] as u128 * radix as u128 + carry;
+            limbs[k] = wide as u64;
+            carry = wide >> 64;
+            k += 1;
+        }
+        if carry != 0 {
+            return None;
+        }
+        i += 1;
+    }
+    Some(limbs)
+}
+
+// Count the limbs needed by a literal, at least one
+#[doc(hidden)]
+pub const fn __limb_count(literal: &str) -> usize {
+    if __is_negative(literal) {
+        panic!("`limbs` variants cannot hold a negative value");
+    }
+    let limbs = match read_limbs::<MAX_LIMBS>(literal) {
+        Some(limbs) => limbs,
+        None => panic!("`limbs` variants hold at most 4096 bits"),
+    };
+    let mut count = MAX_LIMBS;
+    while count > 1 && limbs[count - 1] == 0 {
+        count -= 1;
+    }
+    count
+}
+
+// Read a literal as the `N` limbs counted by `__limb_count`
+#[doc(hidden)]
+pub const fn __limbs<const N: usize>(literal: &str) -> [u64; N] {
+    if __is_negative(literal) {
+        panic!("`limbs` variants cannot hold a negative value");
+    }
+    match read_limbs::<N>(literal) {
+        Some(limbs) => limbs,
+        None => panic!("`limbs` variants hold at most 4096 bits"),
+    }
+}
+
+// Split a computed value into the one or two limbs it needs
+#[doc(hidden)]
+pub const fn __limbs_of<const N: usize>(value: u128) -> [u64; N] {
+    let mut limbs = [0u64; N];
+    limbs[0] = value as u64;
+    if N > 1 {
+        limbs[1] = (value >> 64) as u64;
+    }
+    limbs
+}
+
+// Read a literal as the four little-endian digits of a `u256` or `i256` variant, in two's complement if signed
+#[doc(hidden)]
+pub const fn __digits_256(literal: &str, signed: bool) -> [u64; 4] {
+    let negative = __is_negative(literal);
+    if negative && !signed {
+        panic!("`u256` variants cannot hold a negative value");
+    }
+    let mut digits = match read_limbs::<4>(literal) {
+        Some(digits) => digits,
+        None => panic!("`u256` and `i256` variants hold at most 256 bits"),
+    };
+    if signed {
+        // The magnitude is below 2^255, or equal to it for the minimum
+        let minimum = negative && digits[3] == 1 << 63 && digits[2] == 0 && digits[1] == 0 && digits[0] == 0;
+        if digits[3] >> 63 != 0 && !minimum {
+            panic!("`i256` variants hold values from -2^255 to 2^255 - 1");
+        }
+        if negative {
+            let mut carry = true;
+            let mut k = 0;
+            while k < 4 {
+                let (digit, overflow) = (!digits[k]).overflowing_add(carry as u64);
+                digits[k] = digit;
+                carry = overflow;
+                k += 1;
+            }
+        }
+    }
+    digits
+}
+
+// Sign-extend a computed value to the four digits of an `i256` variant
+#[doc(hidden)]
+pub const fn __digits_of_i128(value: i128) -> [u64; 4] {
+    let extension = if value < 0 { u64::MAX } else { 0 };
+    [value as u64, (value >> 64) as u64, extension, extension]
+}
+
+// Check a value given by its sign and magnitude against the range of an integer type,
+// given by the magnitudes of its minimum and maximum, which is exact for every primitive
+#[doc(hidden)]
+pub const fn __fits(negative: bool, magnitude: u128, range: (u128, u128)) -> bool {
+    if negative {
+        magnitude <= range.0
+    } else {
+        magnitude <= range.1
+    }
+}
+
+// Fail to compile with the nearest value that an integer type holds, and the absolute and relative errors,
+// the value being given by its sign, the magnitude of its integral part, and its signed fractional part,
+// which holds the excess over `u128::MAX` for the literals beyond `u128`
+#[doc(hidden)]
+pub const fn __does_not_fit(name: &str, type_name: &str, negative: bool, magnitude: u128, fraction: f64, range: (u128, u128)) -> ! {
+    let mut message = Message::new().push("`").push(name).push("` does not fit in `").push(type_name).push("`");
+    let fraction = fraction.abs();
+    if fraction.is_finite() {
+        let bound = if negative { range.0 } else { range.1 };
+        let (nearest, error) = if magnitude > bound || (magnitude == bound && fraction > 0.0) {
+            (bound, (magnitude - bound) as f64 + fraction)
+        } else if fraction >= 0.5 {
+            (magnitude + 1, 1.0 - fraction)
+        } else {
+            (magnitude, fraction)
+        };
+        message = message.push(", the nearest value is ");
+        if negative && nearest != 0 {
+            message = message.push("-");
+        }
+        message = message
+            .push_int(nearest)
+            .push(", off by ")
+            .push_float(error)
+            .push(" (")
+            .push_float(error / (magnitude as f64 + fraction) * 100.0)
+            .push("%)");
+    }
+    match core::str::from_utf8(message.bytes.split_at(message.len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("the name of the constant is truncated in the error"),
+    }
+}
+
+// A compile time error message, truncated to its capacity
+struct Message {
+    bytes: [u8; 256],
+    len: usize,
+}
+
+impl Message {
+    const fn new() -> Self {
+        Message { bytes: [0; 256], len: 0 }
+    }
+
+    const fn push(mut self, text: &str) -> Self {
+        let text = text.as_bytes();
+        let mut i = 0;
+        while i < text.len() && self.len < self.bytes.len() {
+            self.bytes[self.len] = text[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    const fn push_int(mut self, value: u128) -> Self {
+        let mut digits = [0u8; 39];
+        let (mut value, mut count) = (value, 0);
+        while count == 0 || value != 0 {
+            digits[digits.len() - 1 - count] = b'0' + (value % 10) as u8;
+            value /= 10;
+            count += 1;
+        }
+        let mut i = digits.len() - count;
+        while i < digits.len() && self.len < self.bytes.len() {
+            self.bytes[self.len] = digits[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    // Write a positive number with six significant digits, without trailing zeros,
+    // and with an exponent outside of `1e-4..1e15`
+    const fn push_float(mut self, value: f64) -> Self {
+        if value == 0.0 {
+            return self.push("0");
+        }
+        let (mut scaled, mut exponent) = (value, 0i32);
+        while scaled >= 10.0 {
+            scaled /= 10.0;
+            exponent += 1;
+        }
+        while scaled < 1.0 {
+            scaled *= 10.0;
+            exponent -= 1;
+        }
+        let mut significand = (scaled * 100_000.0 + 0.5) as u32;
+        if significand >= 1_000_000 {
+            significand /= 10;
+            exponent += 1;
+        }
+        let mut digits = [0u8; 6];
+        let mut i = digits.len();
+        while i > 0 {
+            i -= 1;
+            digits[i] = b'0' + (significand % 10) as u8;
+            significand /= 10;
+        }
+        let mut count = digits.len();
+        while count > 1 && digits[count - 1] == b'0' {
+            count -= 1;
+        }
+
+        let scientific = exponent < -4 || exponent >= 15;
+        // The position of the decimal point after the first digit, padding with zeros on either side
+        let point = if scientific { 1 } else { exponent + 1 };
+        if point <= 0 {
+            self = self.push("0.");
+            let mut zeros = point;
+            while zeros < 0 {
+                self = self.push("0");
+                zeros += 1;
+            }
+        }
+        let mut i = 0;
+        while i < count || (i as i32) < point {
+            if i as i32 == point && point > 0 {
+                self = self.push(".");
+            }
+            self = self.push_int(if i < count { (digits[i] - b'0') as u128 } else { 0 });
+            i += 1;
+        }
+        if scientific {
+            self = self.push("e");
+            if exponent < 0 {
+                self = self.push("-");
+            }
+            self = self.push_int(exponent.unsigned_abs() as u128);
+        }
+        self
+    }
+}
+
+// Read a literal as the sign and magnitude of its bits in a fixed-point type with `frac` fractional bits,
+// rounded to the nearest, ties away from zero, the magnitude being `None` beyond `u128`
+#[doc(hidden)]
+pub const fn __fixed_literal(literal: &str, frac: u32) -> (bool, Option<u128>) {
+    let bytes = literal.as_bytes();
+    let negative = __is_negative(literal);
+    let mut i = negative as usize;
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    let prefixed = i + 1 < bytes.len() && bytes[i] == b'0' && matches!(bytes[i + 1], b'x' | b'o' | b'b');
+    let mut j = i;
+    while j < bytes.len() && !prefixed && !matches!(bytes[j], b'.' | b'e' | b'E') {
+        j += 1;
+    }
+    if prefixed || j == bytes.len() {
+        return match __int_value(literal) {
+            (negative, Some(magnitude)) if magnitude.leading_zeros() >= frac => (negative, Some(magnitude << frac)),
+            (negative, _) => (negative, None),
+        };
+    }
+
+    let mut decimal: Big = [0; BIG_LIMBS];
+    let (mut fraction_digits, mut in_fraction) = (0i32, false);
+    while i < bytes.len() && bytes[i] != b'e' && bytes[i] != b'E' {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                decimal = big_add_small(big_mul_small(decimal, 10), (bytes[i] - b'0') as u32);
+                if in_fraction {
+                    fraction_digits += 1;
+                }
+            }
+            b'.' => in_fraction = true,
+            b'_' => {}
+            _ => panic!("fixed-point variants require an untyped literal"),
+        }
+        i += 1;
+    }
+    let (mut power, mut negative_power) = (0i32, false);
+    if i < bytes.len() {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            negative_power = bytes[i] == b'-';
+            i += 1;
+        }
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => power = power.saturating_mul(10).saturating_add((bytes[i] - b'0') as i32),
+                b'_' => {}
+                _ => panic!("fixed-point variants require an untyped literal"),
+            }
+            i += 1;
+        }
+    }
+    let power = if negative_power { -power } else { power } - fraction_digits;
+
+    // Scale to twice the bits, so that halving after adding one rounds to the nearest
+    let mut scaled = big_shl(decimal, frac + 1);
+    let mut k = 0;
+    while k < power.unsigned_abs() {
+        if power < 0 {
+            scaled = big_div_small(scaled, 10);
+        } else if big_below(scaled, 5) {
+            scaled = big_mul_small(scaled, 10);
+        } else {
+            return (negative, None);
+        }
+        k += 1;
+    }
+    let scaled = big_div_small(big_add_small(scaled, 1), 2);
+    if !big_below(scaled, 4) {
+        return (negative, None);
+    }
+    let magnitude = scaled[0] as u128 | (scaled[1] as u128) << 32 | (scaled[2] as u128) << 64 | (scaled[3] as u128) << 96;
+    (negative, Some(magnitude))
+}
+
+// Read a literal as the sign, 96-bit mantissa and scale of a `Decimal`, for `decimal` variants,
+// which is `None` unless the literal is held exactly, with a scale of at most 28
+#[doc(hidden)]
+pub const fn __decimal_literal(literal: &str) -> (bool, Option<(u128, u32)>) {
+    let bytes = literal.as_bytes();
+    let negative = __is_negative(literal);
+    let mut i = negative as usize;
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    if i + 1 < bytes.len() && bytes[i] == b'0' && matches!(bytes[i + 1], b'x' | b'o' | b'b') {
+        return match __int_value(literal) {
+            (negative, Some(magnitude)) if magnitude >> 96 == 0 => (negative, Some((magnitude, 0))),
+            (negative, _) => (negative, None),
+        };
+    }
+
+    let mut mantissa = Some(0u128);
+    let (mut fraction_digits, mut in_fraction) = (0i32, false);
+    while i < bytes.len() && bytes[i] != b'e' && bytes[i] != b'E' {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                mantissa = match mantissa {
+                    Some(value) => match value.checked_mul(10) {
+                        Some(value) => value.checked_add((bytes[i] - b'0') as u128),
+                        None => None,
+                    },
+                    None => None,
+                };
+                if in_fraction {
+                    fraction_digits += 1;
+                }
+            }
+            b'.' => in_fraction = true,
+            b'_' => {}
+            _ => panic!("`decimal` variants require an untyped literal"),
+        }
+        i += 1;
+    }
+    let (mut power, mut negative_power) = (0i32, false);
+    if i < bytes.len() {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            negative_power = bytes[i] == b'-';
+            i += 1;
+        }
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => power = power.saturating_mul(10).saturating_add((bytes[i] - b'0') as i32),
+                b'_' => {}
+                _ => panic!("`decimal` variants require an untyped literal"),
+            }
+            i += 1;
+        }
+    }
+    let mut mantissa = match mantissa {
+        Some(mantissa) => mantissa,
+        None => return (negative, None),
+    };
+    let mut scale = fraction_digits - if negative_power { -power } else { power };
+
+    // Move the digits into the range of the scale, dropping only trailing zeros
+    while scale < 0 {
+        mantissa = match mantissa.checked_mul(10) {
+            Some(mantissa) => mantissa,
+            None => return (negative, None),
+        };
+        scale += 1;
+    }
+    while scale > 28 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+    if scale > 28 || mantissa >> 96 != 0 {
+        return (negative, None);
+    }
+    (negative, Some((mantissa, scale as u32)))
+}
+
+// Read a literal as the sign, numerator and denominator of a reduced fraction, for `ratio` variants,
+// which is `None` when either part does not fit in `u128`
+#[doc(hidden)]
+pub const fn __ratio_literal(literal: &str) -> (bool, Option<(u128, u128)>) {
+    let bytes = literal.as_bytes();
+    let negative = __is_negative(literal);
+    let mut i = negative as usize;
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    if i + 1 < bytes.len() && bytes[i] == b'0' && matches!(bytes[i + 1], b'x' | b'o' | b'b') {
+        return match __int_value(literal) {
+            (negative, Some(magnitude)) => (negative, Some((magnitude, 1))),
+            (negative, None) => (negative, None),
+        };
+    }
+
+    let mut numerator = Some(0u128);
+    let (mut fraction_digits, mut in_fraction) = (0i32, false);
+    while i < bytes.len() && bytes[i] != b'e' && bytes[i] != b'E' {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                numerator = match numerator {
+                    Some(value) => match value.checked_mul(10) {
+                        Some(value) => value.checked_add((bytes[i] - b'0') as u128),
+                        None => None,
+                    },
+                    None => None,
+                };
+                if in_fraction {
+                    fraction_digits += 1;
+                }
+            }
+            b'.' => in_fraction = true,
+            b'_' => {}
+            _ => panic!("`ratio` variants require an untyped literal"),
+        }
+        i += 1;
+    }
+    let (mut power, mut negative_power) = (0i32, false);
+    if i < bytes.len() {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            negative_power = bytes[i] == b'-';
+            i += 1;
+        }
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => power = power.saturating_mul(10).saturating_add((bytes[i] - b'0') as i32),
+                b'_' => {}
+                _ => panic!("`ratio` variants require an untyped literal"),
+            }
+            i += 1;
+        }
+    }
+    let mut numerator = match numerator {
+        Some(numerator) => numerator,
+        None => return (negative, None),
+    };
+    let mut scale = fraction_digits - if negative_power { -power } else { power };
+    while scale < 0 {
+        numerator = match numerator.checked_mul(10) {
+            Some(numerator) => numerator,
+            None => return (negative, None),
+        };
+        scale += 1;
+    }
+    if numerator == 0 {
+        return (negative, Some((0, 1)));
+    }
+
+    // The denominator is `2^scale * 5^scale`, so only these factors are shared with the numerator
+    let (mut twos, mut fives) = (scale, scale);
+    while twos > 0 && numerator % 2 == 0 {
+        numerator /= 2;
+        twos -= 1;
+    }
+    while fives > 0 && numerator % 5 == 0 {
+        numerator /= 5;
+        fives -= 1;
+    }
+    let mut denominator = 1u128;
+    while twos > 0 || fives > 0 {
+        let factor = if twos > 0 { 2 } else { 5 };
+        denominator = match denominator.checked_mul(factor) {
+            Some(denominator) => denominator,
+            None => return (negative, None),
+        };
+        if twos > 0 {
+            twos -= 1;
+        } else {
+            fives -= 1;
+        }
+    }
+    (negative, Some((numerator, denominator)))
+}
+
+// The greatest common divisor of two integers, in const contexts
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    a
+}
+
+// Convert a literal between two units of integer scales, as the sign, numerator and denominator
+// of a reduced fraction, for the `units` conversions
+#[doc(hidden)]
+pub const fn __unit_ratio(literal: &str, from_scale: u128, to_scale: u128) -> (bool, u128, u128) {
+    let (negative, numerator, denominator) = match __ratio_literal(literal) {
+        (negative, Some((numerator, denominator))) => (negative, numerator, denominator),
+        (_, None) => panic!("the literal converted by `units` does not fit in `u128`"),
+    };
+    // Once the scales are reduced like the literal, only these factors can be shared
+    let shared = gcd(from_scale, to_scale);
+    let (from_scale, to_scale) = (from_scale / shared, to_scale / shared);
+    let (left, right) = (gcd(numerator, to_scale), gcd(from_scale, denominator));
+    match ((numerator / left).checked_mul(from_scale / right), (denominator / right).checked_mul(to_scale / left)) {
+        (Some(numerator), Some(denominator)) => (negative, numerator, denominator),
+        _ => panic!("the conversion requested by `units` overflows `u128`"),
+    }
+}
+
+// Round a fraction to the nearest float with `digits` significant bits and a minimum normal exponent,
+// ties to even, returned as the `f64` holding it exactly. Values beyond the range of `f64` are infinite
+#[doc(hidden)]
+pub const fn __ratio_float(negative: bool, numerator: u128, denominator: u128, digits: u32, min_exp: i32) -> f64 {
+    let digits = digits as i32;
+    let (whole, mut rest) = (numerator / denominator, numerator % denominator);
+
+    // The kept bits followed by the guard bit, the lowest one being at the position `low`,
+    // and whether any bit below the guard bit is set
+    let (mut bits, mut low, sticky);
+    let mut lead = if whole != 0 { Some(127 - whole.leading_zeros() as i32) } else { None };
+    if lowest_kept(lead, digits, min_exp) > 0 {
+        let guard = lowest_kept(lead, digits, min_exp) - 1;
+        bits = whole >> guard;
+        low = guard;
+        sticky = whole & ((1 << guard) - 1) != 0 || rest != 0;
+    } else {
+        bits = whole;
+        low = 0;
+        while low > lowest_kept(lead, digits, min_exp) - 1 {
+            // The next bit of the quotient, doubling the remainder without overflowing
+            let bit = rest >= denominator - rest;
+            rest = if bit { rest - (denominator - rest) } else { rest + rest };
+            bits = bits << 1 | bit as u128;
+            low -= 1;
+            if bits != 0 && lead.is_none() {
+                lead = Some(low);
+            }
+        }
+        sticky = rest != 0;
+    }
+
+    let guard = bits & 1 != 0;
+    bits >>= 1;
+    low += 1;
+    if guard && (sticky || bits & 1 != 0) {
+        bits += 1;
+    }
+    let mut value = bits as f64;
+    while low > 0 {
+        value *= 2.0;
+        low -= 1;
+    }
+    while low < 0 {
+        value /= 2.0;
+        low += 1;
+    }
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+// The position of the lowest significant bit, given the one of the leading bit if already found,
+// subnormal values keeping fewer bits
+const fn lowest_kept(lead: Option<i32>, digits: i32, min_exp: i32) -> i32 {
+    match lead {
+        Some(lead) if lead > min_exp => lead - digits + 1,
+        _ => min_exp - digits + 1,
+    }
+}
+
+// Select the type of a conversion by whether its value is an integer, held exactly in `i128`
+#[doc(hidden)]
+pub struct __Exact<const INTEGER: bool>;
+
+impl __Pick for __Exact<true> { type Type = i128; }
+impl __Pick for __Exact<false> { type Type = f64; }
+
+// Select the type of an integer quotient by its sign, so that unsigned values beyond `i128` are kept
+#[doc(hidden)]
+pub struct __Quotient<const NEGATIVE: bool>;
+
+impl __Pick for __Quotient<true> { type Type = i128; }
+impl __Pick for __Quotient<false> { type Type = u128; }
+
+// The kind of the elements of a table, selecting the accumulator of `sum` and `mean`:
+// 0 for floats, 1 for signed and 2 for unsigned integers
+#[doc(hidden)]
+pub trait __Element {
+    const KIND: u8;
+}
+
+impl __Element for f32 { const KIND: u8 = 0; }
+impl __Element for f64 { const KIND: u8 = 0; }
+impl __Element for i8 { const KIND: u8 = 1; }
+impl __Element for i16 { const KIND: u8 = 1; }
+impl __Element for i32 { const KIND: u8 = 1; }
+impl __Element for i64 { const KIND: u8 = 1; }
+impl __Element for i128 { const KIND: u8 = 1; }
+impl __Element for isize { const KIND: u8 = 1; }
+impl __Element for u8 { const KIND: u8 = 2; }
+impl __Element for u16 { const KIND: u8 = 2; }
+impl __Element for u32 { const KIND: u8 = 2; }
+impl __Element for u64 { const KIND: u8 = 2; }
+impl __Element for u128 { const KIND: u8 = 2; }
+impl __Element for usize { const KIND: u8 = 2; }
+
+#[doc(hidden)]
+pub const fn __element_kind<T: __Element>(_: &[T]) -> u8 {
+    T::KIND
+}
+
+// Select the accumulator of a table by the kind of its elements, integers being summed exactly
+#[doc(hidden)]
+pub struct __Accumulator<const KIND: u8>;
+
+impl __Pick for __Accumulator<0> { type Type = f64; }
+impl __Pick for __Accumulator<1> { type Type = i128; }
+impl __Pick for __Accumulator<2> { type Type = u128; }
+
+// The capacity of the text rendered by `to_str`, enough for any `f64` written without exponent
+#[doc(hidden)]
+pub const __TEXT_CAPACITY: usize = 512;
+
+// Render a literal as decimal text without exponent, for `to_str`, rounded half away from zero
+// to a number of fractional digits if given. Literals in another radix are copied without separators
+#[doc(hidden)]
+pub const fn __render_literal(literal: &str, precision: Option<usize>) -> ([u8; __TEXT_CAPACITY], usize) {
+    let bytes = literal.as_bytes();
+    let negative = __is_negative(literal);
+    let mut text = [0; __TEXT_CAPACITY];
+    let mut len = 0;
+    if negative {
+        text[0] = b'-';
+        len = 1;
+    }
+    let mut i = negative as usize;
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    if i + 1 < bytes.len() && bytes[i] == b'0' && matches!(bytes[i + 1], b'x' | b'o' | b'b') {
+        if precision.is_some() {
+            panic!("the precision of `to_str` requires a decimal literal");
+        }
+        while i < bytes.len() {
+            if bytes[i] != b'_' {
+                text[len] = bytes[i];
+                len += 1;
+            }
+            i += 1;
+        }
+        return (text, len);
+    }
+
+    // Collect the digits, and the position of the decimal point given by the dot and the exponent
+    let mut digits = [0u8; __TEXT_CAPACITY];
+    let (mut count, mut dot) = (0, None);
+    while i < bytes.len() {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                digits[count] = bytes[i] - b'0';
+                count += 1;
+            }
+            b'.' => dot = Some(count),
+            b'_' => {}
+            _ => break,
+        }
+        i += 1;
+    }
+    let mut point = match dot {
+        Some(dot) => dot as isize,
+        None => count as isize,
+    };
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        i += 1;
+        let negative_exponent = i < bytes.len() && bytes[i] == b'-';
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            i += 1;
+        }
+        let mut exponent = 0isize;
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+            if bytes[i] != b'_' {
+                exponent = exponent * 10 + (bytes[i] - b'0') as isize;
+                if exponent > __TEXT_CAPACITY as isize {
+                    panic!("the text of the constant is too long for `to_str`");
+                }
+            }
+            i += 1;
+        }
+        point += if negative_exponent { -exponent } else { exponent };
+    }
+    let padding = match precision {
+        Some(precision) => precision,
+        None => 0,
+    };
+    if count + point.unsigned_abs() + padding + 3 > __TEXT_CAPACITY {
+        panic!("the text of the constant is too long for `to_str`");
+    }
+
+    // Lay the integer digits then the fractional digits out, with at least one integer digit
+    let mut work = [0u8; __TEXT_CAPACITY];
+    let (mut int_len, mut work_len) = (0, 0);
+    if point <= 0 {
+        int_len = 1;
+        work_len = 1 + point.unsigned_abs();
+    }
+    let mut k = 0;
+    while k < count {
+        work[work_len] = digits[k];
+        work_len += 1;
+        k += 1;
+    }
+    if point > 0 {
+        int_len = point as usize;
+        if work_len < int_len {
+            work_len = int_len;
+        }
+    }
+
+    // Round or pad the fractional digits, carrying into the integer digits
+    if let Some(precision) = precision {
+        if work_len > int_len + precision {
+            let mut carry = work[int_len + precision] >= 5;
+            work_len = int_len + precision;
+            let mut k = work_len;
+            while carry {
+                if k == 0 {
+                    let mut j = work_len;
+                    while j > 0 {
+                        work[j] = work[j - 1];
+                        j -= 1;
+                    }
+                    work[0] = 1;
+                    int_len += 1;
+                    work_len += 1;
+                    break;
+                }
+                k -= 1;
+                carry = work[k] == 9;
+                work[k] = if carry { 0 } else { work[k] + 1 };
+            }
+        } else {
+            work_len = int_len + precision;
+        }
+    }
+
+    // Write the digits, without the leading zeros of the integer part
+    let mut start = 0;
+    while start + 1 < int_len && work[start] == 0 {
+        start += 1;
+    }
+    while start < work_len {
+        if start == int_len {
+            text[len] = b'.';
+            len += 1;
+        }
+        text[len] = b'0' + work[start];
+        len += 1;
+        start += 1;
+    }
+    (text, len)
+}
+
+// Round a float literal in the direction given by `#[round(...)]`, from its nearest value
+// The literal is compared exactly with the nearest value, then moved by one step if needed
+#[doc(hidden)]
+pub const fn __round_f32(literal: &str, nearest: f32, mode: u8) -> f32 {
+    let bits = nearest.to_bits();
+    let (exponent, fraction) = ((bits >> 23) & 0xff, (bits & 0x7f_ffff) as u64);
+    let (mantissa, exponent) = if exponent == 0 {
+        (fraction, -149)
+    } else {
+        (fraction | 1 << 23, exponent as i32 - 150)
+    };
+    let magnitude = bits & 0x7fff_ffff;
+    let magnitude = match __round_step(literal, mantissa, exponent, mode) {
+        1 => magnitude + 1,
+        -1 => magnitude - 1,
+        _ => magnitude,
+    };
+    let rounded = f32::from_bits(magnitude | (__is_negative(literal) as u32) << 31);
+    if rounded.is_infinite() {
+        panic!("rounded constant overflows to infinity in `f32`");
+    }
+    rounded
+}
+
+#[doc(hidden)]
+pub const fn __round_f64(literal: &str, nearest: f64, mode: u8) -> f64 {
+    let bits = nearest.to_bits();
+    let (exponent, fraction) = ((bits >> 52) & 0x7ff, bits & 0xf_ffff_ffff_ffff);
+    let (mantissa, exponent) = if exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, exponent as i32 - 1075)
+    };
+    let magnitude = bits & 0x7fff_ffff_ffff_ffff;
+    let magnitude = match __round_step(literal, mantissa, exponent, mode) {
+        1 => magnitude + 1,
+        -1 => magnitude - 1,
+        _ => magnitude,
+    };
+    let rounded = f64::from_bits(magnitude | (__is_negative(literal) as u64) << 63);
+    if rounded.is_infinite() {
+        panic!("rounded constant overflows to infinity in `f64`");
+    }
+    rounded
+}
+
+#[doc(hidden)]
+pub const fn __round_to_f32(value: f64, mode: u8) -> f32 {
+    let nearest = value as f32;
+    let bits = nearest.to_bits();
+    let (lower, higher) = ((nearest as f64) < value, (nearest as f64) > value);
+    let magnitude = bits & 0x7fff_ffff;
+    let magnitude = if (higher && value > 0.0 || lower && value < 0.0) && (mode == 3 || (mode == 1) == (value < 0.0)) {
+        magnitude - 1
+    } else if (lower && value > 0.0 && mode == 1) || (higher && value < 0.0 && mode == 2) {
+        magnitude + 1
+    } else {
+        magnitude
+    };
+    let rounded = f32::from_bits(magnitude | (bits & 0x8000_0000));
+    if rounded.is_infinite() {
+        panic!("rounded constant overflows to infinity in `f32`");
+    }
+    rounded
+}
+
+// Whether the magnitude of the nearest value must move away from zero (1), toward zero (-1), or stay (0)
+// The modes are 1 for `up`, 2 for `down` and 3 for `toward_zero`
+const fn __round_step(literal: &str, mantissa: u64, exponent: i32, mode: u8) -> i8 {
+    let negative = __is_negative(literal);
+    match __compare_literal(literal, mantissa, exponent) {
+        1 if (mode == 1 && !negative) || (mode == 2 && negative) => 1,
+        -1 if mode == 3 || (mode == 1 && negative) || (mode == 2 && !negative) => -1,
+        _ => 0,
+    }
+}
+
+const fn __is_negative(literal: &str) -> bool {
+    !literal.is_empty() && literal.as_bytes()[0] == b'-'
+}
+
+// Count the characters written when displaying a value, to align the columns of a report
+#[doc(hidden)]
+pub fn __display_width(value: &ConstValue) -> usize {
+    struct Counter(usize);
+    impl core::fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+    let mut counter = Counter(0);
+    let _ = core::fmt::write(&mut counter, format_args!("{}", value));
+    counter.0
+}
+
+// Tell whether a variant holds its literal exactly, as "exact", "rounded" or "saturated",
+// or "computed" for the variants initialized from an expression
+#[doc(hidden)]
+pub fn __report_status(literal: Option<&str>, value: ConstValue) -> &'static str {
+    let literal = match literal {
+        Some(literal) => literal,
+        None => return "computed",
+    };
+    let digits = literal.trim_start_matches('-');
+    let prefixed = ["0x", "0o", "0b"].iter().any(|prefix| digits.starts_with(prefix));
+    if prefixed || !digits.contains(['.', 'e', 'E']) {
+        let (negative, magnitude) = __int_value(literal);
+        let stored = match (value.cast::<u128>(CastMode::Exact), value.cast::<i128>(CastMode::Exact)) {
+            (Some(stored), _) => (false, stored),
+            (None, Some(stored)) => (true, stored.unsigned_abs()),
+            (None, None) => return "rounded",
+        };
+        return match magnitude {
+            Some(magnitude) if (negative && magnitude != 0, magnitude) == stored => "exact",
+            _ if matches!(value, ConstValue::F32(_) | ConstValue::F64(_)) => "rounded",
+            _ => "saturated",
+        };
+    }
+
+    let stored = match value {
+        ConstValue::F32(value) => value as f64,
+        ConstValue::F64(value) => value,
+        _ => return "rounded",
+    };
+    let bits = stored.to_bits();
+    let (exponent, fraction) = ((bits >> 52) & 0x7ff, bits & 0xf_ffff_ffff_ffff);
+    let (mantissa, exponent) = match exponent {
+        0 => (fraction, -1074),
+        0x7ff => return "rounded",
+        _ => (fraction | 1 << 52, exponent as i32 - 1075),
+    };
+    match __compare_literal(literal, mantissa, exponent) {
+        0 if stored == 0.0 || stored.is_sign_negative() == __is_negative(literal) => "exact",
+        _ => "rounded",
+    }
+}
+
+// Enough 32 bits limbs for the literals of every float, down to the smallest subnormal
+const BIG_LIMBS: usize = 100;
+type Big = [u32; BIG_LIMBS];
+
+// Compare the magnitude of a decimal literal with `mantissa * 2^exponent`, as -1, 0 or 1
+const fn __compare_literal(literal: &str, mantissa: u64, exponent: i32) -> i8 {
+    let bytes = literal.as_bytes();
+    let mut decimal: Big = [0; BIG_LIMBS];
+    let (mut i, mut fraction_digits, mut in_fraction) = (0, 0i32, false);
+    if i < bytes.len() && bytes[i] == b'-' {
+        i += 1;
+    }
+    while i < bytes.len() && bytes[i] != b'e' && bytes[i] != b'E' {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                decimal = big_add_small(big_mul_small(decimal, 10), (bytes[i] - b'0') as u32);
+                if in_fraction {
+                    fraction_digits += 1;
+                }
+            }
+            b'.' => in_fraction = true,
+            b'_' => {}
+            _ => panic!("`round` requires an untyped float literal"),
+        }
+        i += 1;
+    }
+    let (mut power, mut negative_power) = (0i32, false);
+    if i < bytes.len() {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'-' || bytes[i] == b'+') {
+            negative_power = bytes[i] == b'-';
+            i += 1;
+        }
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => power = power * 10 + (bytes[i] - b'0') as i32,
+                b'_' => {}
+                _ => panic!("`round` requires an untyped float literal"),
+            }
+            i += 1;
+        }
+    }
+    let power = if negative_power { -power } else { power } - fraction_digits;
+
+    // Scale both sides to integers
+    let mut binary: Big = [0; BIG_LIMBS];
+    binary[0] = mantissa as u32;
+    binary[1] = (mantissa >> 32) as u32;
+    let mut j = 0;
+    while j < power.unsigned_abs() {
+        if power > 0 {
+            decimal = big_mul_small(decimal, 10);
+        } else {
+            binary = big_mul_small(binary, 10);
+        }
+        j += 1;
+    }
+    if exponent > 0 {
+        binary = big_shl(binary, exponent as u32);
+    } else {
+        decimal = big_shl(decimal, exponent.unsigned_abs());
+    }
+
+    let mut k = BIG_LIMBS;
+    while k > 0 {
+        k -= 1;
+        if decimal[k] != binary[k] {
+            return if decimal[k] > binary[k] { 1 } else { -1 };
+        }
+    }
+    0
+}
+
+const fn big_mul_small(mut big: Big, factor: u32) -> Big {
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < BIG_LIMBS {
+        let product = big[i] as u64 * factor as u64 + carry;
+        big[i] = product as u32;
+        carry = product >> 32;
+        i += 1;
+    }
+    if carry != 0 {
+        panic!("float literal is too precise to be rounded");
+    }
+    big
+}
+
+const fn big_add_small(mut big: Big, term: u32) -> Big {
+    let mut carry = term as u64;
+    let mut i = 0;
+    while i < BIG_LIMBS && carry != 0 {
+        let sum = big[i] as u64 + carry;
+        big[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    big
+}
+
+const fn big_div_small(mut big: Big, divisor: u32) -> Big {
+    let mut remainder = 0u64;
+    let mut i = BIG_LIMBS;
+    while i > 0 {
+        i -= 1;
+        let current = remainder << 32 | big[i] as u64;
+        big[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    big
+}
+
+// Whether a big number fits in the given number of limbs
+const fn big_below(big: Big, limbs: usize) -> bool {
+    let mut i = limbs;
+    while i < BIG_LIMBS {
+        if big[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn big_shl(big: Big, shift: u32) -> Big {
+    let (limbs, bits) = ((shift / 32) as usize, shift % 32);
+    let mut shifted: Big = [0; BIG_LIMBS];
+    let mut i = BIG_LIMBS;
+    while i > 0 {
+        i -= 1;
+        if big[i] == 0 {
+            continue;
+        }
+        if i + limbs + (bits != 0) as usize >= BIG_LIMBS {
+            panic!("float literal is too precise to be rounded");
+        }
+        shifted[i + limbs] |= big[i] << bits;
+        if bits != 0 {
+            shifted[i + limbs + 1] |= big[i] >> (32 - bits);
+        }
+    }
+    shifted
+}
+
+/// The error returned when a text override of a tunable constant does not fit in every variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverrideError;
+
+impl core::fmt::Display for OverrideError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("the override cannot be parsed into every variant of the constant")
+    }
+}
+
+#[cfg(feature = "hot-reload")]
+extern crate std;
+
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+
+#[cfg(feature = "attribute")]
+pub use polymorphic_constant_macros::polymorphic_constants;
+#[doc(hidden)]
+pub use polymorphic_constant_macros::{__lossy_variant, __paste};
+
+pub use polymorphic_constant_traits::{FromPolymorphic, NarrowFrom, Sign};
+
+/**
+Define one or more polymorphic numerical constants. A constant X of value 10, available in i32 and u32 will read:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const X: i32 | u32 = 10;
+}
+```
+and be used like:
+```
+# use polymorphic_constant::polymorphic_constant;
+# polymorphic_constant! { const X: i32 | u32 = 10; }
+let x_i32 = X.i32;
+```
+
+Float variants hold the nearest value to the literal by default. The `round` attribute
+narrows it `up`, `down`, or `toward_zero` instead, for safety margins requiring directed rounding:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[round(down)]
+    const TENTH: f32 | f64 = 0.1;
+}
+
+assert!((TENTH.f32 as f64) < 0.1 && (TENTH.f64) < 0.1);
+```
+
+Float variants may lose precision silently. The `warn_precision_loss` attribute emits a warning,
+pointing at each `f32` variant whose value differs from the initializer computed in `f64`,
+and telling how it rounds a literal, such as "`f32` variant rounds 3.141592653589793 to 3.1415927".
+The `real` variants are checked when they are `f32`, and the `f16` variants of the `nightly` feature.
+The `f16` variants of `half`, the `bf16`, complex and SIMD variants are not checked:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    // Warns about the `f32` variant only
+    #[warn_precision_loss]
+    const PI: f32 | f64 = 3.141592653589793;
+}
+```
+
+The `reference_test` attribute generates a test with the given name, computing the initializer again at runtime in `f64`,
+and checking that every variant listed when iterating over the constant matches it within a relative tolerance, zero by default.
+It guards derived and computed constants against regressions in their evaluation, and the test only exists under `cfg(test)`:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const WIDTH: f32 | f64 = 320.0;
+    const TAU: f32 | f64 = 6.283185307179586;
+    #[reference_test(test_half_width)]
+    const HALF_WIDTH = WIDTH / 2.0;
+    #[reference_test(test_circumference, tolerance = 1e-7)]
+    const CIRCUMFERENCE = WIDTH * TAU;
+}
+```
+
+The `cfg_value` attribute replaces the value under a `cfg` condition, so that tests can run
+with smaller sizes while other builds keep the real value. Several of them are tried in order:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[cfg_value(test, 16)]
+    const BUFFER_SIZE: u16 | usize = 4096;
+}
+
+assert_eq!(BUFFER_SIZE.usize, 4096);
+```
+
+The initializer can also be an `if cfg(...)` chain, read as one `cfg_value` attribute per condition,
+the `else` branch being the initializer. `native_of(little, big)` selects its value by the byte order of the target,
+for the checksum seeds and magic numbers that must match it:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const SEED: u32 | u64 = if cfg(target_endian = "big") { 0x1234_5678 } else { 0x7856_3412 };
+    const MAGIC: u32 = native_of(0xEFBE_ADDE, 0xDEAD_BEEF);
+}
+
+assert_eq!(SEED.u32.to_ne_bytes(), [0x12, 0x34, 0x56, 0x78]);
+assert_eq!(MAGIC.u32.to_ne_bytes(), [0xDE, 0xAD, 0xBE, 0xEF]);
+```
+
+The `deprecated` attribute applies to the struct, the constant and its companions, so that reading
+the value in any way warns, while the items generated by the macro do not:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[deprecated(note = "use `TIMEOUT_MS`")]
+    const TIMEOUT: u32 | u64 = 5;
+}
+
+#[allow(deprecated)]
+let timeout: u64 = TIMEOUT.into();
+```
+
+Literals out of the range of a variant fail to compile, unless the `saturate` attribute clamps them
+to its minimum or maximum instead, floats being clamped to their largest finite values:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[saturate]
+    const GAIN: u8 | i8 | u16 = 300;
+}
+
+assert_eq!(GAIN.u8, 255);
+assert_eq!(GAIN.i8, 127);
+assert_eq!(GAIN.u16, 300);
+```
+
+The `prune_unfit` attribute omits these variants instead, replacing their field with [`Pruned`],
+for macros generating many constants with the same variants. The omitted variants are listed by `PRUNED`,
+and are not seen by `has_variant!`. As the fields depend on the value, the constant has no conversions,
+and only supports literals and primitive variants:
+```
+# use polymorphic_constant::{polymorphic_constant, has_variant};
+polymorphic_constant! {
+    #[prune_unfit]
+    const LEVEL: u8 | i8 | u16 = 300;
+}
+
+assert_eq!(LEVEL.u16, 300);
+assert_eq!(LEVEL::PRUNED, ["u8", "i8"]);
+assert!(!has_variant!(LEVEL, u8));
+```
+
+Bounded variants, `bounded_u8`, `bounded_i32`, etc, are the types of the `bounded-integer` crate
+with the range given by the `bounds` attribute, and fail to compile if the value is out of it.
+Their companions share the same range, and the crate using the macro must depend on `bounded-integer`:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[bounds(0, 100)]
+    const PERCENT: u8 | bounded_u8 = 42;
+}
+
+let percent: bounded_integer::BoundedU8<0, 100> = PERCENT.into();
+```
+```compile_fail
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[bounds(0, 100)]
+    const PERCENT: u8 | bounded_u8 = 101;
+}
+```
+
+Optional non-zero variants, `opt_nz_u32`, `opt_nz_i64`, etc, hold the value of the non-zero variant in an `Option`,
+for APIs taking optional non-zero parameters. A value of zero fails to compile, unless the `zero_is_none`
+attribute holds it as `None`. Outside of a constant, such as in a companion or a table, zero always fails to compile:
+```
+# use polymorphic_constant::polymorphic_constant;
+# use core::num::NonZeroU32;
+polymorphic_constant! {
+    const RETRIES: u8 | opt_nz_u32 = 3;
+    #[zero_is_none]
+    const TIMEOUT: u64 | opt_nz_u32 = 0;
+}
+
+assert_eq!(RETRIES.opt_nz_u32, NonZeroU32::new(3));
+let timeout: Option<NonZeroU32> = TIMEOUT.into();
+assert_eq!(timeout, None);
+```
+```compile_fail
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const FAILS: u64 | opt_nz_u32 = 0;
+}
+# fn main() { let _ = FAILS; }
+```
+
+The `smallest_uint` and `smallest_int` variants hold the value in the narrowest unsigned or signed type,
+such as `u16` for `300`, which is convenient for packed structures. They are not converted with `Into`,
+the type being selected from the value, and fail to compile if the value is not an integer:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const PORT: smallest_uint = 300;
+    const OFFSET: smallest_int = -129;
+}
+
+let port: u16 = PORT.smallest_uint;
+let offset: i16 = OFFSET.smallest_int;
+```
+
+With the `bigint` feature, the `bigint` and `biguint` variants hold the value as a [`Lazy`] `BigInt` or `BigUint`
+of the `num-bigint` crate, parsed from the literal each time it is read. The literal can exceed every primitive,
+in which case the other variants fail to compile. These variants are converted with `Into`, but are not listed
+when iterating over the constant, and cannot be used with `lookup`, `dump`, `register` or `try_from`:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const MODULUS: biguint = 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+    const SEED: u64 | bigint = 0x1234_5678;
+}
+
+let modulus: num_bigint::BigUint = MODULUS.into();
+let seed = SEED.bigint.get();
+```
+
+The `limbs` variant holds an unsigned value of up to 4096 bits as little-endian `u64` limbs,
+read from the literal at compile-time into an array just long enough to hold it,
+so that moduli can be declared once, next to a `u128` or `biguint` variant where they fit.
+Computed values are limited to `u128`. Limbs are not listed when iterating over the constant:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const P256: limbs = 0xffff_ffff_0000_0001_0000_0000_0000_0000_0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
+    const ORDER: u64 | limbs = 0xffff_ffff_0000_0001;
+}
+
+assert_eq!(P256.limbs.len(), 4);
+assert_eq!(P256.limbs[3], 0xffff_ffff_0000_0001);
+let order: [u64; 1] = ORDER.into();
+```
+
+With the `bnum` feature, the `u256` and `i256` variants hold the `U256` and `I256` types of `bnum`,
+read from literals of up to 256 bits, so that hashes and curve parameters are declared next to their
+`u128` or `u64` variants where they fit. Computed values are limited to `u128` and `i128`.
+They have no companions, and are not listed when iterating over the constant:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const FIELD_PRIME: u256 = 0xffff_ffff_0000_0001_0000_0000_0000_0000_0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
+    const CHAIN_ID: u64 | u256 = 137;
+    const OFFSET: i64 | i256 = -42;
+}
+
+assert_eq!(CHAIN_ID.u256, bnum::types::U256::from(137u64));
+assert_eq!(OFFSET.i256, bnum::types::I256::from(-42i64));
+```
+
+The `le_bytes`, `be_bytes` and `ne_bytes` variants hold the little-endian, big-endian or native-endian
+representation of the value as a `[u8; N]`, for the constants of wire protocols. The value is that of the
+integer type given by `#[bytes(...)]`, or of the widest primitive integer variant, which must hold it.
+Byte variants have no conversions, are not listed when iterating over the constant, and are only available on constants:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const MAGIC: u32 | le_bytes | be_bytes = 0xCAFE_BABE;
+    #[bytes(i16)]
+    const OFFSET: i64 | be_bytes = -2;
+}
+
+assert_eq!(MAGIC.le_bytes, [0xBE, 0xBA, 0xFE, 0xCA]);
+assert_eq!(MAGIC.be_bytes, [0xCA, 0xFE, 0xBA, 0xBE]);
+assert_eq!(OFFSET.be_bytes, [0xFF, 0xFE]);
+```
+
+With the `decimal` feature, the `decimal` variant holds the value as a `Decimal` of the `rust_decimal` crate,
+built from the digits of the literal, so that it can share constants with float variants without being re-typed.
+Literals that cannot be held exactly, with a scale of at most 28, fail to compile:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const FEE_RATE: f64 | decimal = 0.0125;
+}
+
+assert_eq!(FEE_RATE.decimal.to_string(), "0.0125");
+```
+
+With the `rational` feature, the `ratio` variants, from `ratio_i32` to `ratio_u128`, hold the literal exactly
+as a reduced `Ratio` of `num-rational`, so that `0.1` becomes `1/10` next to the rounded float variants.
+Literals whose numerator or denominator do not fit in the integer type fail to compile:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const STEP: f64 | ratio_i64 = 0.1;
+}
+
+assert_eq!((*STEP.ratio_i64.numer(), *STEP.ratio_i64.denom()), (1, 10));
+```
+
+With the `complex` feature, the `c_f32` and `c_f64` variants hold the value of the matching float variant
+as the real part of a `Complex` of `num-complex`, so that the same constant is used in real and complex arithmetic:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const GAIN: f32 | c_f32 = 0.5;
+}
+
+assert_eq!(GAIN.c_f32, num_complex::Complex::new(0.5, 0.0));
+```
+
+With the `ordered-float` feature, the `of_f32` and `of_f64` variants hold the value of the matching float variant
+in an `OrderedFloat`, and the `nn_f32` and `nn_f64` variants in a `NotNan`, which are totally ordered and hashable.
+Values are never NaN, which is still checked before building a `NotNan`, as its only const constructor is `unsafe`:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const THRESHOLD: f64 | of_f64 | nn_f32 = 0.25;
+}
+
+let mut levels = std::collections::BTreeSet::new();
+levels.insert(THRESHOLD.of_f64);
+assert_eq!(THRESHOLD.nn_f32.into_inner(), 0.25);
+```
+
+With the `arbitrary-int` feature, the `u24`, `u40`, `u48` and `u56` variants hold the unsigned integers
+of `arbitrary-int`, as found in register maps and network protocols. Values that do not fit in their bits
+fail to compile, and are clamped to their maximum with `saturate`. They have no companions:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const FRAME_LENGTH: u32 | u24 = 0x12_3456;
+}
+
+assert_eq!(FRAME_LENGTH.u24, arbitrary_int::u24::new(0x12_3456));
+```
+
+With the `uom` feature, the quantity variants `uom_length`, `uom_mass`, `uom_time`, `uom_velocity`,
+`uom_acceleration` and `uom_frequency` hold the value of the `f64` variant as a quantity of `uom`,
+so that it is checked for its dimension wherever it is used.
+The value is counted in the unit given by the `uom_unit` attribute, named as in `uom`, or else in the base unit
+of the quantity. Quantities have no companions, are not listed when iterating over the constant,
+and are not available on `static` items:
+```ignore
+# use polymorphic_constant::polymorphic_constant;
+use uom::si::velocity::meter_per_second;
+
+polymorphic_constant! {
+    const SPEED_OF_LIGHT: f64 | uom_velocity = 299_792_458.0;
+    #[uom_unit(kilometer_per_hour)]
+    const SPEED_LIMIT: f32 | uom_velocity = 90.0;
+}
+
+assert_eq!(SPEED_OF_LIGHT.uom_velocity.get::<meter_per_second>(), SPEED_OF_LIGHT.f64);
+assert_eq!(SPEED_LIMIT.uom_velocity.value, 25.0);
+```
+
+With the `portable_simd` feature, the SIMD variants such as `f32x4` or `i16x8` hold the value of their lane type
+in every lane of a `core::simd` vector, so that kernels load broadcast constants without splatting them at runtime.
+The lane types and counts of 128, 256 and 512 bits vectors are available, and `f32x2`.
+SIMD variants have no companions, and are not listed when iterating over the constant:
+```ignore
+#![feature(portable_simd)]
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const SCALE: f32 | f32x4 | f32x8 = 0.5;
+}
+
+assert_eq!(SCALE.f32x4, core::simd::f32x4::splat(0.5));
+```
+
+Other types are added with the `custom` attribute, giving each variant a const constructor associated with its type.
+The constructor is called with the initializer, and the custom variants can be listed in any position,
+the struct holding them after the other variants.
+They have no conversions or companions, and are not listed when iterating over the constant:
+```
+# use polymorphic_constant::polymorphic_constant;
+mod units {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Meters(pub f64);
+
+    impl Meters {
+        pub const fn new_const(value: f64) -> Self {
+            Meters(value)
+        }
+    }
+}
+
+polymorphic_constant! {
+    #[custom(meters = units::Meters::new_const)]
+    const RADIUS: f32 | f64 | meters = 6371.0;
+}
+
+assert_eq!(RADIUS.meters, units::Meters(6371.0));
+```
+
+The `#![custom(...)]` option registers custom variants for all the constants that follow,
+which then use them like the variants the macro knows:
+```
+# use polymorphic_constant::polymorphic_constant;
+# mod units {
+#     #[derive(Debug, Clone, Copy, PartialEq)]
+#     pub struct Meters(pub f64);
+#     impl Meters {
+#         pub const fn new_const(value: f64) -> Self { Meters(value) }
+#     }
+# }
+polymorphic_constant! {
+    #![custom(meters = units::Meters::new_const)]
+
+    const RADIUS: meters | f64 = 6371.0;
+    const ORBIT: f32 | meters = 42164.0;
+    const HALF: f32 | f64 = 0.5;
+}
+
+assert_eq!(RADIUS.meters, units::Meters(6371.0));
+assert_eq!(ORBIT.meters, units::Meters(42164.0));
+```
+
+The `ipv4` and `ipv6` variants hold an `Ipv4Addr` or `Ipv6Addr` of `core::net`, built from the bits of the value,
+which must fit in a `u32` or `u128`. Their bytes are given by `octets`. Like big integers, they are not listed
+when iterating over the constant. The `socket_addr` attribute combines the address with the `u16` variant
+of a port constant, into a `SocketAddr` companion:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[socket_addr(LISTEN = PORT)]
+    const ADDRESS: u128 | ipv6 = 0x0000_0000_0000_0000_0000_ffff_7f00_0001;
+    const PORT: u16 | u32 = 8080;
+}
+
+assert_eq!(ADDRESS.ipv6.octets()[15], 1);
+assert_eq!(LISTEN.to_string(), "[::ffff:127.0.0.1]:8080");
+```
+
+The `duration` variant holds a `Duration` of `core::time`, counting the value in the unit given
+by the `unit` attribute, or by the initializer. The supported units are `ns`, `us`, `ms`, `s`, `min` and `h`,
+and the value must fit in a `u64`. Like addresses, durations are not listed when iterating over the constant,
+and are not available on `static` items:
+```
+# use polymorphic_constant::polymorphic_constant;
+use core::time::Duration;
+
+polymorphic_constant! {
+    #[unit("ms")]
+    const TIMEOUT: u64 | duration = 250;
+    const RETRY_DELAY: u32 | duration = 2 s;
+}
+
+assert_eq!(TIMEOUT.u64, 250);
+assert_eq!(TIMEOUT.duration, Duration::from_millis(250));
+assert_eq!(RETRY_DELAY.duration, Duration::from_secs(2));
+```
+
+The `ptr` variants, `ptr_u8`, `ptr_u32`, `ptr_usize`, etc, hold the value as the address of a register
+of that type, for memory-mapped I/O, as a `*mut` pointer, or as a `*const` pointer with the `const_ptr` attribute.
+The value must fit in a `usize`. Like the `smallest` variants, pointers are not converted with `Into`,
+and they are not listed when iterating over the constant, nor available on `static` items:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const GPIO_BASE: usize | ptr_u32 = 0x4002_1000;
+    #[const_ptr]
+    const DEVICE_ID: usize | ptr_u16 = 0x1FFF_7A10;
+}
+
+let gpio: *mut u32 = GPIO_BASE.ptr_u32;
+let device_id: *const u16 = DEVICE_ID.ptr_u16;
+assert_eq!(gpio as usize, GPIO_BASE.usize);
+assert_eq!(device_id as usize, 0x1FFF_7A10);
+```
+
+The `bool` variant holds `false` for 0 and `true` for 1, any other value failing to compile,
+so that flags can be given both to FFI as integers and to Rust code as booleans.
+It is not listed when iterating over the constant:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const FEATURE_ENABLED: bool | u8 | u32 = 1;
+}
+
+assert!(FEATURE_ENABLED.bool);
+assert_eq!(FEATURE_ENABLED.u8, 1);
+```
+
+The `char` variant holds the character with the value as its code point, failing to compile
+if it is not a valid Unicode scalar value. It is not listed when iterating over the constant either:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const ASCII_LINE_RETURN: u8 | char = 10;
+}
+
+assert_eq!(ASCII_LINE_RETURN.char, '\n');
+assert_eq!(ASCII_LINE_RETURN.u8, b'\n');
+```
+
+Declared as a `static`, a constant can hold atomic variants, such as `atomic_u32` or `atomic_bool`,
+initialized from the same checked value, to seed counters and flags mutated at runtime.
+Atomics being neither `Clone` nor `Copy`, the struct of a static only derives `Debug`,
+and statics get none of the conversions and methods of constants:
+```
+# use polymorphic_constant::polymorphic_constant;
+use core::sync::atomic::Ordering;
+
+polymorphic_constant! {
+    static REQUESTS: u64 | atomic_u64 = 0;
+    static LOGGING: bool | atomic_bool = 1;
+}
+
+REQUESTS.atomic_u64.fetch_add(1, Ordering::Relaxed);
+LOGGING.atomic_bool.store(false, Ordering::Relaxed);
+assert_eq!(REQUESTS.atomic_u64.load(Ordering::Relaxed), REQUESTS.u64 + 1);
+```
+
+The `to_str` attribute generates a `&'static str` companion holding the literal as decimal text,
+rendered at compile-time, optionally rounded to a number of fractional digits:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[to_str(PI_STR)]
+    #[to_str(PI_SHORT, precision = 2)]
+    const PI: f32 | f64 = 3.141592653589793;
+}
+
+assert_eq!(PI_STR, "3.141592653589793");
+assert_eq!(PI_SHORT, "3.14");
+```
+
+The `str` variant holds the literal itself as a `&'static str`, exactly as written in the source,
+so that logs and golden tests print the full value without going through float formatting.
+Unlike `to_str`, the text is not rendered, and computed values fail to compile.
+It is not listed when iterating over the constant:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const GOLDEN_RATIO: f32 | f64 | str = 1.618_033_988_749_894_848;
+}
+
+assert_eq!(GOLDEN_RATIO.str, "1.618_033_988_749_894_848");
+```
+
+Companion constants derived from the value can be generated with the `also` attribute,
+each with the same variants, and computed in `f64` for float variants.
+They are named after the constant, with the `_RECIP`, `_SQUARED` or `_HALF` suffix, unless given a name:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[also(recip, squared, halved = HALF_PI)]
+    const PI: f32 | f64 = 3.141592653589793;
+}
+
+assert_eq!(PI_RECIP.f64, 0.3183098861837907);
+assert_eq!(HALF_PI.f64, 1.5707963267948966);
+```
+Integer companions fail to compile if they overflow, or if halving an odd value.
+The error names the companion, its operation, and the failing variant:
+```compile_fail
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    // `LIMIT_SQUARED` (squared) overflows in `u8`
+    #[also(squared = LIMIT_SQUARED)]
+    const LIMIT: u8 | u16 = 20;
+}
+```
+
+A literal initializer can be followed by a unit, and converted to other units of the same quantity
+with the `units` attribute, each conversion being a companion constant with the same variants.
+The conversions are computed exactly from the text of the literal, then checked like computed initializers,
+so that integer results keep every digit, and the others are rounded once:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[units(mm = LENGTH_MM, inch = LENGTH_IN)]
+    const LENGTH: u32 | u64 = 254 cm;
+}
+
+assert_eq!(LENGTH_MM.u32, 2540);
+assert_eq!(LENGTH_IN.u64, 100);
+```
+The supported units are `um`, `mm`, `cm`, `m`, `km`, `inch`, `ft`, `yd` and `mi` for lengths,
+`ug`, `mg`, `g`, `kg`, `oz` and `lb` for masses, and `ns`, `us`, `ms`, `s`, `min` and `h` for durations.
+
+The `unit` attribute labels any constant with a unit, returned by its `unit` function.
+Constants initialized with a unit, and their conversions, are labelled with it by default:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[unit("m/s^2")]
+    const GRAVITY: f32 | f64 = 9.80665;
+    #[units(mm = LENGTH_MM)]
+    const LENGTH: u32 | u64 = 254 cm;
+}
+
+assert_eq!(GRAVITY::unit(), "m/s^2");
+assert_eq!(LENGTH::unit(), "cm");
+assert_eq!(LENGTH_MM::unit(), "mm");
+```
+
+Each constant has its own type, so the `ord` attribute implements `PartialEq` and `PartialOrd`
+with the listed constants, in both directions. They must have the same variants,
+and every variant must order the constants the same way, or the compilation fails:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[ord(MAX_SIZE)]
+    const MIN_SIZE: u8 | u32 = 16;
+    const MAX_SIZE: u8 | u32 = 255;
+}
+
+assert!(MIN_SIZE < MAX_SIZE);
+assert!(MAX_SIZE != MIN_SIZE);
+```
+
+Constants can be converted to a constant with fewer variants, listing them with the `narrow_from` attribute,
+and the `narrow_to` const method then keeps the values of the shared variants.
+The conversion fails to compile if the wider constant lacks a variant, or holds it in another type:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const TIMEOUT: u16 | u32 | u64 = 5000;
+    #[narrow_from(TIMEOUT)]
+    const DEFAULT_TIMEOUT: u32 | u64 = 1000;
+}
+
+const NARROWED: DEFAULT_TIMEOUT = TIMEOUT.narrow_to();
+let ms: u64 = NARROWED.into();
+assert_eq!(ms, 5000);
+```
+
+Constants with only float variants can be totally ordered with the `total_ord` attribute,
+which implements `PartialEq`, `Eq`, `PartialOrd` and `Ord` through the `total_cmp` of the widest variant,
+so that their values can be sorted or used as keys. `PartialEq` must then not be derived:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[total_ord]
+    const GAIN: f32 | f64 = 0.5;
+}
+
+let mut gains = [GAIN, GAIN];
+gains.sort();
+assert_eq!(gains[0], GAIN);
+```
+
+`sum`, `mean`, `max_elem` and `min_elem` initializers aggregate the elements of a const array.
+Sums of integers are exact in `i128` or `u128`, and sums of floats are compensated in `f64`,
+then checked for each variant like computed initializers:
+```
+# use polymorphic_constant::polymorphic_constant;
+const KERNEL: [f64; 5] = [0.1, 0.2, 0.4, 0.2, 0.1];
+
+polymorphic_constant! {
+    const KERNEL_SUM: f32 | f64 = sum(KERNEL);
+    const KERNEL_PEAK: f32 | f64 = max_elem(KERNEL);
+}
+
+assert_eq!(KERNEL_SUM.f64, 1.0);
+assert_eq!(KERNEL_PEAK.f32, 0.4);
+```
+
+`ratio_of` initializers divide the widest variants of two constants declared before them.
+Float variants get the quotient of the exact values, rounded once to their own precision, while integer variants
+fail to compile unless the division is exact, instead of silently truncating:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const WIDTH: u16 | u32 = 1920;
+    const HEIGHT: u16 | u32 = 1200;
+    const TILE: u8 | u16 = 40;
+    const ASPECT: f32 | f64 = ratio_of(WIDTH, HEIGHT);
+    const COLUMNS: u8 | u16 = ratio_of(WIDTH, TILE);
+}
+
+assert_eq!(ASPECT.f32, 1.6);
+assert_eq!(COLUMNS.u8, 48);
+```
+
+`digits` and `bits` initializers measure the widest variant of another constant, which must hold an integer,
+as the number of decimal digits or significant bits of its magnitude, so that widths stay in sync with values:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const LIMIT: u32 | u64 = 65_535;
+    const LIMIT_DIGITS: u32 | usize = digits(LIMIT);
+    const LIMIT_BITS: u8 | u32 = bits(LIMIT);
+}
+
+assert_eq!(LIMIT_DIGITS.usize, 5);
+assert_eq!(LIMIT_BITS.u8, 16);
+```
+
+`pack` initializers concatenate previous constants into a wider integer, from the most significant field.
+Each field is named, and takes the widest variant of its constant, which must fit in the given number of bits:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const VERSION_MAJOR: u8 = 2;
+    const VERSION_MINOR: u8 = 7;
+    const VERSION: u16 | u32 = pack(major: VERSION_MAJOR, 8; minor: VERSION_MINOR, 8);
+}
+
+assert_eq!(VERSION.u16, 0x0207);
+```
+
+The `tunable` attribute backs every variant with an atomic in debug builds,
+so that the value returned by `get()` can be tweaked at runtime with `set_override()`.
+The atomics are guarded by a sequence lock, so `get()` never mixes variants from two overrides,
+and waits for an override being written on another thread.
+In release builds, `get()` simply returns the constant and `set_override()` is not available:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[tunable]
+    const SPEED: f32 | f64 = 4.0;
+}
+
+#[cfg(debug_assertions)]
+SPEED.set_override(SPEED { f32: 2.5, f64: 2.5 });
+
+let speed = SPEED.get().f32;
+```
+
+The `flat` attribute replaces the struct in the public API by one plain constant per listed variant.
+The struct is still generated to hold the value, but keeps the default private visibility:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[flat(f32 = TAU_F32, f64 = TAU_F64)]
+    pub const TAU: f32 | f64 = 6.283185307179586;
+}
+
+let tau: f64 = TAU_F64;
+```
+
+The `sort_fields` attribute orders the fields of the struct, and the iteration over the variants,
+by descending size, so that no padding is needed when the struct is also `#[repr(C)]`:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[sort_fields]
+    #[repr(C)]
+    const MASK: u8 | u64 | u16 = 0xFF;
+}
+
+assert_eq!(core::mem::size_of_val(&MASK), 16);
+```
+Variants resolved from aliases are sorted by their size, but custom variants are not sorted, and rejected:
+```compile_fail
+# use polymorphic_constant::polymorphic_constant;
+# mod units {
+#     pub struct Meters(pub f64);
+#     impl Meters {
+#         pub const fn new_const(value: f64) -> Self { Meters(value) }
+#     }
+# }
+polymorphic_constant! {
+    #[sort_fields]
+    #[custom(meters = units::Meters::new_const)]
+    const RADIUS: f32 | f64 | meters = 6371.0;
+}
+```
+
+Each struct implements `Into` for every variant. The `impls` attribute selects other conversions,
+added to the default in order: `from` implements `From` instead, which also provides `Into`,
+`no_into` removes the `Into` impls, and `try_from` recognizes the constant from a [`ConstValue`]:
+```
+# use polymorphic_constant::{polymorphic_constant, ConstValue};
+# use core::convert::TryFrom;
+polymorphic_constant! {
+    #[impls(from, try_from)]
+    const LIMIT: u8 | u32 = 200;
+}
+
+let limit = u32::from(LIMIT);
+assert!(LIMIT::try_from(ConstValue::U8(200)).is_ok());
+assert_eq!(LIMIT::try_from(ConstValue::U8(100)).err(), Some(ConstValue::U8(100)));
+```
+
+Code receiving a constant generically can convert it to a width that is not among its variants with `cast`,
+which reads the widest variant at runtime. The [`CastMode`] decides what happens to values out of range:
+```
+# use polymorphic_constant::{polymorphic_constant, CastMode};
+polymorphic_constant! {
+    const LIMIT: u16 | u32 = 300;
+}
+
+assert_eq!(LIMIT.cast::<u8>(CastMode::Checked), None);
+assert_eq!(LIMIT.cast::<u8>(CastMode::Saturating), Some(255));
+assert_eq!(LIMIT.cast::<u8>(CastMode::Wrapping), Some(44));
+assert_eq!(LIMIT.cast::<f32>(CastMode::Exact), Some(300.0));
+```
+
+Where a variant holds the value without loss, `to_f64` and `to_i128` widen it to `f64` or `i128`.
+Unlike `widest`, their type does not depend on the variants, so const code can use any constant without naming one:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    const SAMPLE_RATE: u16 | u32 = 48000;
+    const GAIN: f32 = 0.5;
+}
+
+const SCALE: f64 = GAIN.to_f64() / SAMPLE_RATE.to_f64();
+const PERIOD: i128 = 1_000_000_000 / SAMPLE_RATE.to_i128();
+
+assert_eq!(SCALE, 0.5 / 48000.0);
+assert_eq!(PERIOD, 20833);
+```
+`to_f64` requires a float variant or an integer variant of at most 32 bits, and `to_i128`
+an integer variant other than `u128`.
+
+The [`Sign`] trait gives the sign of the widest variant, as `IS_ZERO`, `IS_POSITIVE` and `IS_NEGATIVE`
+and the matching methods. Using it fails to compile unless every numeric variant has the same sign,
+which `saturate` or `round` may break, so code can branch on it without picking a type:
+```
+# use polymorphic_constant::{polymorphic_constant, Sign};
+polymorphic_constant! {
+    const OFFSET: i8 | i32 = -12;
+}
+
+const BACKWARDS: bool = OFFSET::IS_NEGATIVE;
+assert!(BACKWARDS && !OFFSET.is_zero());
+```
+
+A `cfg` attribute applies to every item generated for the constant, including its impls and companions.
+A `cfg_attr` applies to both the struct and the constant, except for conditional derives:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #[cfg(target_pointer_width = "64")]
+    #[also(halved = WORD_HALF)]
+    const WORD: u8 | u64 = 64;
+    #[cfg(not(target_pointer_width = "64"))]
+    #[also(halved = WORD_HALF)]
+    const WORD: u8 | u32 = 32;
+    #[cfg_attr(debug_assertions, derive(Hash))]
+    const DEPTH: u8 = 8;
+}
+```
+
+Options applying to the whole invocation are given as inner attributes, before the constants.
+`lookup` generates a function finding the variants of a constant from its name:
+```
+# use polymorphic_constant::{polymorphic_constant, ConstValue};
+polymorphic_constant! {
+    #![lookup(pub fn find_constant)]
+
+    const WIDTH: u16 | u32 = 32;
+    const HEIGHT: u16 | u32 = 16;
+}
+
+assert_eq!(find_constant("HEIGHT"), Some(&[("u16", ConstValue::U16(16)), ("u32", ConstValue::U32(16))][..]));
+assert_eq!(find_constant("DEPTH"), None);
+```
+
+`group` generates a constant holding, for each listed variant, the slice of every constant of the invocation:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #![group(pub const ALL_SIZES: u16 | u32)]
+
+    const WIDTH: u16 | u32 = 32;
+    const HEIGHT: u16 | u32 = 16;
+}
+
+assert_eq!(ALL_SIZES.u32, &[32, 16]);
+```
+
+A `group` block instead gathers its constants as the fields of a struct, and of a constant of the same name,
+so that they can be reached through a single item. The constants are generated as usual, and each field
+keeps the visibility and conditions of its constant, while the attributes of the block apply to the group:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    /// Physical constants
+    pub group PHYSICS {
+        pub const C: u32 | u64 = 299_792_458;
+        pub const G: f32 | f64 = 6.674_30e-11;
+    }
+}
+
+assert_eq!(PHYSICS.C.u64, 299_792_458);
+assert_eq!(PHYSICS.G.f32, G.f32);
+```
+
+`prelude` generates a module holding every constant, but not their types, to be glob imported.
+Invocations inside a function cannot use it, as the module refers to the constants through `super`:
+```
+mod sizes {
+    polymorphic_constant::polymorphic_constant! {
+        #![prelude(pub mod prelude)]
+
+        pub const WIDTH: u16 | u32 = 32;
+        pub const HEIGHT: u16 | u32 = 16;
+    }
+}
+
+use sizes::prelude::*;
+
+let area: u32 = WIDTH.widest() * HEIGHT.widest();
+# assert_eq!(area, 512);
+```
+
+`register` adds a [`Descriptor`] of every constant to a `linkme` distributed slice, with the `linkme` feature,
+so that a binary can list the constants of all its crates. The slice is declared once, in any crate:
+```ignore
+use polymorphic_constant::{polymorphic_constant, Descriptor};
+
+#[linkme::distributed_slice]
+pub static CONSTANTS: [Descriptor];
+
+polymorphic_constant! {
+    #![register(crate::CONSTANTS)]
+
+    const WIDTH: u16 | u32 = 32;
+}
+
+assert_eq!(CONSTANTS[0].name, "WIDTH");
+```
+
+`dump` generates a function writing the name, variant and value of every constant as a table,
+to print the numeric configuration compiled into a program:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #![dump(pub fn dump_constants)]
+
+    const WIDTH: u16 | u32 = 32;
+}
+
+let mut table = String::new();
+dump_constants(&mut table).unwrap();
+assert_eq!(table, "constant  variant  value\nWIDTH     u16      32\nWIDTH     u32      32\n");
+```
+
+`report` generates a similar function, telling for each variant whether it holds its literal exactly,
+was rounded or saturated from it, or was computed from an expression:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #![report(pub fn report_constants)]
+
+    const TENTH: f32 | f64 = 0.1;
+    const HALF: f32 = 0.5;
+}
+
+let mut table = String::new();
+report_constants(&mut table).unwrap();
+assert_eq!(
+    table,
+    "constant  variant  value  status\n\
+     TENTH     f32      0.1    rounded from 0.1\n\
+     TENTH     f64      0.1    rounded from 0.1\n\
+     HALF      f32      0.5    exact\n"
+);
+```
+
+`lints` applies lint levels to the structs and constants generated for the constants that follow it,
+for crates denying lints such as `missing_docs`:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #![lints(allow(missing_docs), deny(unsafe_code))]
+
+    pub const LIMIT: u32 | nz_u32 = 64;
+}
+```
+
+`alias` registers type aliases of the crate, resolved to the variant they name in the constants that follow it,
+so that they are checked like that variant. The field is named after the variant, and has the type of the alias,
+so code that switches the alias reads it through a conversion instead.
+It must come before any `lookup`, `register`, `prelude`, `dump`, `report` or `group` option listing the constants:
+```
+# use polymorphic_constant::polymorphic_constant;
+type Scalar = f32;
+
+polymorphic_constant! {
+    #![alias(Scalar = f32)]
+
+    const GRAVITY: Scalar | f64 = 9.81;
+}
+
+let gravity: Scalar = GRAVITY.into();
+assert_eq!(gravity, 9.81);
+```
+```compile_fail
+# use polymorphic_constant::polymorphic_constant;
+type Index = u8;
+
+polymorphic_constant! {
+    #![alias(Index = u8)]
+
+    const FAILS: Index = 256;
+}
+```
+
+`version` gives the version of a public constant set, so that its changes are migrated over one version.
+The `renamed_from` attribute keeps a deprecated alias under the previous name of a constant,
+and the `removed` attribute deprecates a constant before its deletion. Both fail to compile
+once the version is past the next one, as a reminder to delete the alias or the constant:
+```
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #![version(3)]
+
+    #[renamed_from(MAX_USERS, since = 3)]
+    pub const USER_LIMIT: u16 | u32 = 500;
+    #[removed(since = 2)]
+    pub const LEGACY_PORT: u16 = 8080;
+}
+
+#[allow(deprecated)]
+let limit: u32 = MAX_USERS.into();
+assert_eq!(limit, USER_LIMIT.u32);
+```
+```compile_fail
+# use polymorphic_constant::polymorphic_constant;
+polymorphic_constant! {
+    #![version(4)]
+
+    #[renamed_from(MAX_USERS, since = 2)]
+    pub const USER_LIMIT: u16 | u32 = 500;
+}
+# fn main() { let _ = USER_LIMIT; }
+```
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! polymorphic_constant {
+    // Handle `const CONST` format, and generate each constant separately to limit the recursion
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($nextLine:tt)*) => {
+        // use `()` to explicitly forward the information about private items
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* () const $name : $($numeric_type)|* = $lit;);
+        // Keep munching until the next ;
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle `pub const CONST` format
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub) const $name : $($numeric_type)|* = $lit;);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle `pub (crate) CONST` format and similar
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $($numeric_type)|* = $lit;);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle literals followed by a unit, the unit being kept as the first option
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = $lit:literal $unit:ident; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { (unit $unit) } [] [] [] $(#[$($attr)*])* () const $name : $($numeric_type)|* = $lit;);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = $lit:literal $unit:ident; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { (unit $unit) } [] [] [] $(#[$($attr)*])* (pub) const $name : $($numeric_type)|* = $lit;);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = $lit:literal $unit:ident; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { (unit $unit) } [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $($numeric_type)|* = $lit;);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle `if cfg(...)` initializers, each condition becoming a `cfg_value` attribute, and the last branch the initializer
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* $(pub $(($($vis:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = if cfg($($predicate:tt)*) { $($value:tt)+ } $(else if cfg($($other:tt)*) { $($other_value:tt)+ })* else { $($init:tt)+ }; $($nextLine:tt)*) => {
+        polymorphic_constant!(@CONFIG $config $(#[$($attr)*])* #[cfg_value($($predicate)*, $($value)+)] $(#[cfg_value($($other)*, $($other_value)+)])* $(pub $(($($vis)+))?)? const $name : $($numeric_type)|* = $($init)+; $($nextLine)*);
+    };
+    // Handle `native_of(little, big)` initializers, selected by the byte order of the target
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* $(pub $(($($vis:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = native_of($little:literal, $big:literal $(,)?); $($nextLine:tt)*) => {
+        polymorphic_constant!(@CONFIG $config $(#[$($attr)*])* #[cfg_value(target_endian = "big", $big)] $(pub $(($($vis)+))?)? const $name : $($numeric_type)|* = $little; $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* $(pub $(($($vis:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = native_of($little:expr, $big:expr $(,)?); $($nextLine:tt)*) => {
+        polymorphic_constant!(@CONFIG $config $(#[$($attr)*])* #[cfg_value(target_endian = "big", $big)] $(pub $(($($vis)+))?)? const $name : $($numeric_type)|* = $little; $($nextLine)*);
+    };
+    // Handle `from_file(...)` initializers, read from a file embedded at compile-time
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = from_file($($file:tt)*); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* () const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@FROM_FILE $name, $($file)*)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = from_file($($file:tt)*); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub) const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@FROM_FILE $name, $($file)*)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = from_file($($file:tt)*); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@FROM_FILE $name, $($file)*)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle `pack(...)` initializers, computed from the widest variant of previous constants
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = pack($($pack:tt)*); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* () const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@PACK $($pack)*)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = pack($($pack:tt)*); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub) const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@PACK $($pack)*)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = pack($($pack:tt)*); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@PACK $($pack)*)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle `ratio_of(...)` initializers, divided in the kind of the variants, given by the first one
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $first:ident $(| $numeric_type:ident)* = ratio_of($numerator:ident, $denominator:ident $(,)?); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* () const $name : $first $(| $numeric_type)* = (@EXPR $name $crate::__nz_impl!(@RATIO $name $first $numerator $denominator)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $first:ident $(| $numeric_type:ident)* = ratio_of($numerator:ident, $denominator:ident $(,)?); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub) const $name : $first $(| $numeric_type)* = (@EXPR $name $crate::__nz_impl!(@RATIO $name $first $numerator $denominator)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $first:ident $(| $numeric_type:ident)* = ratio_of($numerator:ident, $denominator:ident $(,)?); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $first $(| $numeric_type)* = (@EXPR $name $crate::__nz_impl!(@RATIO $name $first $numerator $denominator)););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle single argument calls, that may be aggregates over a table
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = $function:ident($table:expr); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* () const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@AGGREGATE $function($table))););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = $function:ident($table:expr); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub) const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@AGGREGATE $function($table))););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = $function:ident($table:expr); $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $($numeric_type)|* = (@EXPR $name $crate::__nz_impl!(@AGGREGATE $function($table))););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Handle computed initializers, checked when narrowed to each variant
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = $init:expr; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* () const $name : $($numeric_type)|* = (@EXPR $name $init););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = $init:expr; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub) const $name : $($numeric_type)|* = (@EXPR $name $init););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = $init:expr; $($nextLine:tt)*) => {
+        polymorphic_constant!(@ATTRS $config {} [] [] [] $(#[$($attr)*])* (pub ($($vis)+)) const $name : $($numeric_type)|* = (@EXPR $name $init););
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Statics hold their atomic variants as atomics, initialized from the checked value
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* $(pub $(($($vis:tt)+))?)? static $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($nextLine:tt)*) => {
+        __nz_impl!(@STATIC $config [] [] $(#[$($attr)*])* ($(pub $(($($vis)+))?)?) $name [$($numeric_type)*] $lit);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* $(pub $(($($vis:tt)+))?)? static $name:ident : $( $numeric_type:ident )|* = $init:expr; $($nextLine:tt)*) => {
+        __nz_impl!(@STATIC $config [] [] $(#[$($attr)*])* ($(pub $(($($vis)+))?)?) $name [$($numeric_type)*] (@EXPR $name $init));
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Derived constants are generated separately, from the variants of the constants declared before them
+    (@CONFIG $config:tt $(#[$($attr:tt)*])* $(pub $(($($vis:tt)+))?)? const $name:ident = $init:expr; $($nextLine:tt)*) => {
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    // Groups generate their constants as usual, then the struct holding them
+    (@CONFIG $config:tt $(#[$attr:meta])* $(pub $(($($v:tt)+))?)? group $group:ident { $($body:tt)* } $($nextLine:tt)*) => {
+        polymorphic_constant!(@CONFIG $config $($body)*);
+        __nz_impl!(@NAMESPACE ($config [$(#[$attr])*] ($(pub $(($($v)+))?)?) $group) [] [] $($body)*);
+        polymorphic_constant!(@CONFIG $config $($nextLine)*);
+    };
+    (@CONFIG $config:tt) => {};
+
+    // Handle the options of the whole invocation
+    (@CONFIG $config:tt #![lookup(fn $lookup:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@LOOKUP () $lookup)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![lookup(pub fn $lookup:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@LOOKUP (pub) $lookup)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![lookup(pub ($($vis:tt)+) fn $lookup:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@LOOKUP (pub ($($vis)+)) $lookup)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![register($($slice:tt)+)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@REGISTER ($($slice)+))) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![prelude(mod $prelude:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@PRELUDE () $prelude)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![prelude(pub mod $prelude:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@PRELUDE (pub) $prelude)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![prelude(pub ($($vis:tt)+) mod $prelude:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@PRELUDE (pub ($($vis)+)) $prelude)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![dump(fn $dump:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@DUMP () $dump)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![dump(pub fn $dump:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@DUMP (pub) $dump)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![dump(pub ($($vis:tt)+) fn $dump:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@DUMP (pub ($($vis)+)) $dump)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![report(fn $report:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@REPORT () $report)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![report(pub fn $report:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@REPORT (pub) $report)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![report(pub ($($vis:tt)+) fn $report:ident)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@REPORT (pub ($($vis)+)) $report)) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    // Apply lint levels to the items generated for the constants that follow
+    (@CONFIG { derive: $derive:tt impls: $impls:tt lints: [$($lints:tt)*] aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } #![lints($($level:ident($($lint:path),* $(,)?)),* $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@CONFIG { derive: $derive impls: $impls lints: [$($lints)* $(#[$level($($lint),*)])*] aliases: $aliases version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    // Register type aliases, resolved to the variant they name in the constants that follow
+    (@CONFIG { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: [$($aliases:tt)*] version: $version:tt strict: $strict:tt customs: $customs:tt } #![alias($($alias:ident = $target:ident),* $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@CONFIG { derive: $derive impls: $impls lints: $lints aliases: [$($aliases)* $(($alias $target))*] version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    // Register custom variants, built by their const constructor in the constants that follow
+    (@CONFIG { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: [$($customs:tt)*] } #![custom($($field:ident = $($path:ident)::+),* $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@CONFIG { derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: $strict customs: [$($customs)* $(($field [$($path)*]))*] } $($t)*);
+    };
+    // Give the version of the constant set, checked by the constants renamed or removed in the constants that follow
+    (@CONFIG { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $old:tt strict: $strict:tt customs: $customs:tt } #![version($version:literal)] $($t:tt)*) => {
+        polymorphic_constant!(@CONFIG { derive: $derive impls: $impls lints: $lints aliases: $aliases version: [$version] strict: $strict customs: $customs } $($t)*);
+    };
+    (@CONFIG $config:tt #![group(const $group:ident : $( $numeric_type:ident )|*)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@GROUP () $group [$($numeric_type)*])) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![group(pub const $group:ident : $( $numeric_type:ident )|*)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@GROUP (pub) $group [$($numeric_type)*])) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+    (@CONFIG $config:tt #![group(pub ($($vis:tt)+) const $group:ident : $( $numeric_type:ident )|*)] $($t:tt)*) => {
+        __nz_impl!(@COLLECT (@ALIASED $config (@GROUP (pub ($($vis)+)) $group [$($numeric_type)*])) [] [] [] $($t)*);
+        polymorphic_constant!(@CONFIG $config $($t)*);
+    };
+
+    // Separate the options of the macro from the attributes passed to the generated items
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[also $also:tt] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (also $also) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[to_str($companion:ident $(, precision = $precision:literal)? $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (to_str $companion ($($precision)?)) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[socket_addr($companion:ident = $port:ident $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (socket_addr $companion $port) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[total_ord] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (total_ord) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[proof] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (proof) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[warn_precision_loss] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (warn_precision_loss) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[reference_test($test:ident $(, tolerance = $tolerance:literal)? $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (reference_test $test [$($tolerance)?]) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[cfg_value($key:ident $(= $key_value:literal)?, $($value:tt)+)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (cfg_value ($key $(= $key_value)?) ($($value)+)) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[cfg_value($key:ident $args:tt, $($value:tt)+)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (cfg_value ($key $args) ($($value)+)) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[saturate] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (saturate) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[zero_is_none] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (zero_is_none) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[const_ptr] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (const_ptr) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[uom_unit($unit:ident)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (uom_unit $unit) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[renamed_from($old:ident, since = $since:literal $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (renamed_from $old $since) } $docs $attrs $cfgs $($t)*);
+    };
+    // Removed constants are deprecated until they are deleted
+    (@ATTRS $config:tt { $($options:tt)* } [$($docs:tt)*] $attrs:tt $cfgs:tt #[removed(since = $since:literal $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (removed $since) } [$($docs)* #[deprecated(note = "this constant is removed from its set, and will be deleted in the next version")]] $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[prune_unfit] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (prune_unfit) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[tunable] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (tunable) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[sort_fields] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (sort_fields) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[ord $ord:tt] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (ord $ord) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[custom($($field:ident = $($path:ident)::+),* $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (custom [$(($field [$($path)*]))*]) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[narrow_from $narrow:tt] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (narrow_from $narrow) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[units $units:tt] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (units $units) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[flat $flat:tt] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (flat $flat) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[round $round:tt] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (round $round) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[unit($label:literal)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (label $label) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[bounds($min:literal, $max:literal $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (bounds $min $max) } $docs $attrs $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt #[bytes($int:ident)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config { $($options)* (bytes $int) } $docs $attrs $cfgs $($t)*);
+    };
+    // Derived constants consume the `strict` attribute before reaching this point
+    (@ATTRS $config:tt $options:tt $docs:tt $attrs:tt $cfgs:tt #[strict] $($t:tt)*) => {
+        ::core::compile_error!("the `strict` attribute only applies to derived constants, declared without variants");
+    };
+    // Add the impls of the constant to those of the configuration
+    (@ATTRS { derive: $derive:tt impls: [$($impls:ident)*] lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } $options:tt $docs:tt $attrs:tt $cfgs:tt #[impls($($impl:ident),* $(,)?)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS { derive: $derive impls: [$($impls)* $($impl)*] lints: $lints aliases: $aliases version: $version strict: $strict customs: $customs } $options $docs $attrs $cfgs $($t)*);
+    };
+    // Documentation is kept apart, to be applied to both the struct and the constant
+    (@ATTRS $config:tt $options:tt [$($docs:tt)*] $attrs:tt $cfgs:tt #[doc $($doc:tt)*] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config $options [$($docs)* #[doc $($doc)*]] $attrs $cfgs $($t)*);
+    };
+    // Deprecation is kept with the documentation, so that using either the struct or the constant warns
+    (@ATTRS $config:tt $options:tt [$($docs:tt)*] $attrs:tt $cfgs:tt #[deprecated $($deprecated:tt)*] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config $options [$($docs)* #[deprecated $($deprecated)*]] $attrs $cfgs $($t)*);
+    };
+    // Conditional derives only apply to the struct, other conditional attributes to both the struct and the constant
+    (@ATTRS $config:tt $options:tt $docs:tt [$($attrs:tt)*] $cfgs:tt #[cfg_attr($predicate:meta, derive $derive:tt)] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config $options $docs [$($attrs)* #[cfg_attr($predicate, derive $derive)]] $cfgs $($t)*);
+    };
+    (@ATTRS $config:tt $options:tt [$($docs:tt)*] $attrs:tt $cfgs:tt #[cfg_attr $($cfg_attr:tt)*] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config $options [$($docs)* #[cfg_attr $($cfg_attr)*]] $attrs $cfgs $($t)*);
+    };
+    // Conditions are kept apart, to be applied to every generated item
+    (@ATTRS $config:tt $options:tt $docs:tt $attrs:tt [$($cfgs:tt)*] #[cfg $($cfg:tt)*] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config $options $docs $attrs [$($cfgs)* #[cfg $($cfg)*]] $($t)*);
+    };
+    (@ATTRS $config:tt $options:tt $docs:tt [$($attrs:tt)*] $cfgs:tt #[$attr:meta] $($t:tt)*) => {
+        polymorphic_constant!(@ATTRS $config $options $docs [$($attrs)* #[$attr]] $cfgs $($t)*);
+    };
+
+    // Generate a single constant and its companions, all behind its conditions
+    // The initializer is either a literal, or a computed `(@EXPR ...)`
+    (@ATTRS $config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt $vis:tt const $name:ident : $( $numeric_type:ident )|* = $lit:tt;) => {
+        // Resolve the aliases registered by `#![alias(...)]`
+        // Then set apart the variants given by `#![custom(...)]` or `#[custom(...)]`, which the macro does not know
+        __nz_impl!(@ALIAS $config (@CUSTOM_STRIP ($config { $($options)* } $docs $attrs $cfgs $vis $name $lit) [$($options)*] $config) [$($numeric_type)*]);
+    };
+    (@CUSTOM_DONE ($config:tt { $($options:tt)* } $docs:tt $attrs:tt $cfgs:tt $vis:tt $name:ident $lit:tt) [$($numeric_type:ident)*]) => {
+        // Reject atomic variants, which only `static` items can hold
+        // Then replace the initializer under the conditions given by `#[cfg_value(...)]`
+        __nz_impl!(@NOT_STATIC $name [$($numeric_type)*] (@CFG_VALUE [$($options)*] [] $cfgs [$($options)*] ($config { $($options)* } $docs $attrs $vis $name) $lit [$($numeric_type)*]));
+    };
+    (@GENERATE $config:tt { $($options:tt)* } $docs:tt $attrs:tt $vis:tt $name:ident $lit:tt [$($numeric_type:ident)*]) => {
+        // Generate the struct, or the plain constants requested by `#[flat(...)]`
+        // Float variants are narrowed as requested by `#[round(...)]`, and literals clamped by `#[saturate]`
+        __nz_impl!(@ROUNDING nearest [$($options)*] ($config $docs $attrs $vis $name [$($numeric_type)*] $lit [$($options)*]));
+
+        // Generate the companions requested by `#[also(...)]`
+        __nz_impl!(@ALSO $config $vis $name [$($numeric_type)*] $lit, [$($options)*]);
+
+        // Generate the text companions requested by `#[to_str(...)]`
+        __nz_impl!(@TO_STR $vis $name $lit [$($options)*]);
+
+        // Combine the address with the ports given by `#[socket_addr(...)]`
+        __nz_impl!(@SOCKET_ADDR $vis $name [$($numeric_type)*] [$($options)*]);
+
+        // Generate the conversions requested by `#[units(...)]`
+        __nz_impl!(@UNITS $config $vis $name [$($numeric_type)*] $lit, [$($options)*]);
+
+        // Label the constant with the unit given by `#[unit(...)]`, or by its initializer
+        __nz_impl!(@LABEL $vis $name () [$($options)*]);
+
+        // Select the type of the `smallest_uint` and `smallest_int` variants from the value
+        __nz_impl!(@SMALLEST $name $lit [$($numeric_type)*]);
+
+        // Count the limbs of the `limbs` variants from the value
+        __nz_impl!(@LIMBS $name $lit [$($numeric_type)*]);
+
+        // Hold the value of the byte variants in the type given by `#[bytes(...)]`, or in the widest integer variant
+        __nz_impl!(@BYTES $name $lit [$($numeric_type)*] [$($options)*]);
+
+        // Give the unit of the duration variants, from `#[unit(...)]` or the initializer
+        __nz_impl!(@DURATION_UNIT $name [$($numeric_type)*] [$($options)*]);
+
+        // Give the range of the bounded variants from `#[bounds(...)]`
+        __nz_impl!(@BOUNDS $name [$($numeric_type)*] [$($options)*] [$($options)*]);
+
+        // Hold zero as `None` in the optional non-zero variants if requested by `#[zero_is_none]`
+        __nz_impl!(@ZERO_IS_NONE $name [$($numeric_type)*] [$($options)*]);
+
+        // Hold `*const` pointers in the pointer variants if requested by `#[const_ptr]`, and `*mut` pointers otherwise
+        __nz_impl!(@POINTER $name [$($numeric_type)*] [$($options)*]);
+
+        // Give the unit of the quantity variants from `#[uom_unit(...)]`, or their base unit
+        __nz_impl!(@UOM_UNIT $name [$($numeric_type)*] [$($options)*]);
+
+        // Generate the aliases requested by `#[renamed_from(...)]`, and check that migrations last one version
+        __nz_impl!(@MIGRATION $config $vis $name [$($options)*]);
+
+        // Warn about the narrowing of float variants if requested by `#[warn_precision_loss]`
+        __nz_impl!(@PRECISION $name [$($numeric_type)*] $lit [$($options)*]);
+
+        // Name the precondition of each checked constructor if requested by `#[proof]`
+        __nz_impl!(@PROOF $name [$($numeric_type)*] $lit [$($options)*] [$($options)*]);
+
+        // Compare the variants with the initializer computed at runtime if requested by `#[reference_test(...)]`
+        __nz_impl!(@REFERENCE_TEST $name $lit [$($options)*]);
+
+        // Compare with the constants listed by `#[ord(...)]`
+        __nz_impl!(@ORD $name [$($numeric_type)*] [$($options)*]);
+
+        // Build the constant from the constants listed by `#[narrow_from(...)]`
+        __nz_impl!(@NARROW $name [$($numeric_type)*] [$($options)*]);
+
+        // Order the values of a float constant if requested by `#[total_ord]`
+        __nz_impl!(@TOTAL_ORD $name [$($numeric_type)*] [$($options)*]);
+
+        // Back the constant with atomics in debug builds if requested by `#[tunable]`
+        __nz_impl!(@TUNABLE $vis $name [$($numeric_type)*] [$($options)*]);
+    };
+
+    // Generate the struct, the constant, and the impls, from the value of each variant
+    (@EMIT { derive: [$($derive:path),*] impls: $impls:tt lints: [$($lints:tt)*] aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } [$($docs:tt)*] [$($attrs:tt)*] ($($vis:tt)*) $name:ident [$($numeric_type:ident)*] { $($values:tt)* } [$(($custom:ident $path:tt $init:tt))*]) => {
+
+        // Generate the struct to hold the constant, archived with the `rkyv` feature and reflected with the `bevy` feature if its variants allow it
+        __nz_impl!(@DERIVE_BLOCKERS [$($docs)*] [$($numeric_type)*] [$($custom)*] (
+            // Remove warnings
+            #[allow(non_camel_case_types, deprecated)]
+            // Apply the lint levels given by `#![lints(...)]`
+            $($lints)*
+            // Derive the traits selected by the configuration
+            #[derive($($derive),*)]
+            // Expend the documentation and attributes passed by the user
+            $($docs)*
+            $($attrs)*
+            // Add the visibility attributes
+            $($vis)*
+            // Create the struct
+            struct $name {
+                // For each type (f32, ...) create a new property
+                // The field keeps the span of the type keyword, for go-to-definition
+                $(
+                    #[doc = ::core::concat!("The value of `", ::core::stringify!($name), "` as `", ::core::stringify!($numeric_type), "`")]
+                    $numeric_type: __nz_impl!(@FIELD_TYPE $name $numeric_type),
+                )*
+                // The variants given by `#[custom(...)]` hold the type their constructor is associated with
+                $(
+                    #[doc = ::core::concat!("The value of `", ::core::stringify!($name), "` as `", ::core::stringify!($custom), "`")]
+                    $custom: __nz_impl!(@CUSTOM_TYPE [] $path),
+                )*
+            }
+        ));
+
+        // Implement the conversion traits selected by the configuration
+        __nz_impl!(@IMPLS { into: false, from: false, try_from: false } $impls $name [$($numeric_type)*]);
+
+        // Raw identifiers such as `r#type` cannot be upper case, and the struct may be deprecated
+        #[allow(non_upper_case_globals, deprecated)]
+        $($lints)*
+        // Document the constant like the struct
+        $($docs)*
+        // Expand the visibility, this time for the constant
+        $($vis)*
+        // Instantiate the struct and create the constant
+        const $name: $name = $name { $($values)* $($custom: __nz_impl!(@CUSTOM_VALUE $path $init),)* };
+
+        // Iterate over the name and value of every variant
+        __nz_impl!(@ITER $name [] [$($numeric_type)*]);
+
+        // List the variants, for `has_variant!`, and find them by type
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __VARIANTS: &'static [&'static ::core::primitive::str] = &[$(::core::stringify!($numeric_type)),*];
+
+            #[doc = ::core::concat!("Get the type of the variant of `", ::core::stringify!($name), "` of type `T`, if it has one")]
+            #[allow(dead_code)]
+            $($vis)* fn variant_of<T: 'static>() -> ::core::option::Option<$crate::NumType> {
+                $(if ::core::any::TypeId::of::<T>() == ::core::any::TypeId::of::<__nz_impl!(@FIELD_TYPE $name $numeric_type)>() {
+                    return $crate::NumType::of::<T>();
+                })*
+                ::core::option::Option::None
+            }
+
+            #[doc = ::core::concat!("Convert `", ::core::stringify!($name), "` to a constant declaring it with `narrow_from`, keeping the variants they share")]
+            #[allow(dead_code)]
+            $($vis)* const fn narrow_to<T: $crate::NarrowFrom<$name>>(self) -> T {
+                T::NARROWED
+            }
+        }
+
+        // Access the most and least precise variants
+        __nz_impl!(@WIDEST ($($vis)*) $name [$($numeric_type)*]);
+        __nz_impl!(@NARROWEST ($($vis)*) $name [$($numeric_type)*]);
+        // Tell the sign of the constant from its widest variant, checking that the other variants agree
+        __nz_impl!(@FIND_WIDEST (@SIGN ($) $name [$($numeric_type)*]) [$($numeric_type)*]);
+        // Convert the widest variant to any primitive at runtime
+        __nz_impl!(@FIND_WIDEST (@CAST ($($vis)*) $name) [$($numeric_type)*]);
+        // Widen to `f64` and `i128` in const contexts, from a variant holding the value without loss
+        __nz_impl!(@TO_F64 ($($vis)*) $name [$($numeric_type)*]);
+        __nz_impl!(@TO_I128 ($($vis)*) $name [$($numeric_type)*]);
+
+        // Optional trait implementations, enabled by the features of this crate
+        __ufmt_impl!($name [$($numeric_type)*]);
+        __schemars_impl!($name [$($numeric_type)*]);
+        __approx_impl!($name [$($numeric_type)*]);
+    };
+
+    // Generate every constant, then the constants derived from them
+    (@BLOCK $config:tt $($t:tt)*) => {
+        polymorphic_constant!(@CONFIG $config $($t)*);
+        __nz_impl!(@DERIVE_EACH $config [] [] $($t)*);
+    };
+
+    // Use the default configuration
+    (@DEFAULT $($t:tt)*) => {
+        polymorphic_constant!(@BLOCK {
+            derive: [::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy]
+            impls: [into]
+            lints: []
+            aliases: []
+            version: []
+            strict: []
+            customs: []
+        } $($t)*);
+    };
+
+    // Entry points
+    (# $($t:tt)*) => { polymorphic_constant!(@DEFAULT # $($t)*); };
+    (group $($t:tt)*) => { polymorphic_constant!(@DEFAULT group $($t)*); };
+    (const $($t:tt)*) => { polymorphic_constant!(@DEFAULT const $($t)*); };
+    (static $($t:tt)*) => { polymorphic_constant!(@DEFAULT static $($t)*); };
+    (pub $($t:tt)*) => { polymorphic_constant!(@DEFAULT pub $($t)*); };
+    () => {};
+}
+
+/**
+Set the defaults of [`polymorphic_constant!`] for the whole crate, so that the same settings
+don't have to be repeated on every block of a large project.
+
+The configuration generates a crate-local `polymorphic_constant!` macro, used by every later invocation
+in place of the one of this crate, which must then not be imported:
+```
+# use polymorphic_constant::polymorphic_config;
+polymorphic_config! {
+    strict = true;
+    derive = [Debug, Clone, Copy, PartialEq];
+}
+
+polymorphic_constant! {
+    const PIXELS: u64 | i64 = 3_000_000_001;
+    const AREA = PIXELS * PIXELS;
+}
+
+assert_eq!(AREA.u64, 9_000_000_006_000_000_001);
+```
+
+The `name` entry names the generated macro instead, so that several configurations can be used side by side:
+```
+# use polymorphic_constant::polymorphic_config;
+polymorphic_config! {
+    name = my_constant;
+    derive = [Debug, Clone, Copy, PartialEq];
+}
+
+my_constant! {
+    const PI: f32 | f64 = 3.141592653589793;
+}
+
+assert_eq!(PI, PI);
+```
+
+The generated macro follows the textual scoping of `macro_rules!`: declare the configuration
+at the top of the crate root, before any `mod`, to make it available everywhere.
+
+Supported entries:
+
+* `name = ident;` the name of the generated macro (defaults to `polymorphic_constant`)
+* `derive = [Trait, ...];` the traits derived by every generated struct (defaults to `Debug, Clone, Copy`)
+* `impls = [impl, ...];` the conversion traits implemented by every generated struct, as with the `impls` attribute
+* `lints = [level(lint, ...), ...];` the lint levels applied to every generated item, as with the `lints` option
+* `aliases = [Alias = type, ...];` the type aliases resolved in every block, as with the `alias` option
+* `customs = [field = Type::constructor, ...];` the custom variants registered in every block, as with the `custom` option
+* `version = number;` the version of the constant sets, as with the `version` option
+* `strict = bool;` whether every derived constant is computed in `i128`, as with the `strict` attribute (defaults to `false`)
+
+The generated structs are always named after their constant, so other entries such as a struct prefix are rejected:
+```compile_fail
+# use polymorphic_constant::polymorphic_config;
+polymorphic_config! {
+    struct_prefix = "Poly";
+}
+```
+*/
+#[macro_export]
+macro_rules! polymorphic_config {
+    ($($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($) {
+            name: [polymorphic_constant]
+            derive: [::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy]
+            impls: [into]
+            lints: []
+            aliases: []
+            version: []
+            strict: []
+            customs: []
+        } $($t)*);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __polymorphic_config {
+    // Read the configuration entries one at a time, in any order
+    (@PARSE ($d:tt) { name: $old:tt derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } name = $name:ident; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: [$name] derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $old:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } derive = [$($derive:path),* $(,)?]; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: [$($derive),*] impls: $impls lints: $lints aliases: $aliases version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $old:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } impls = [$($impl:ident),* $(,)?]; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: [into $($impl)*] lints: $lints aliases: $aliases version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $impls:tt lints: $old:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } lints = [$($level:ident($($lint:path),* $(,)?)),* $(,)?]; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: $impls lints: [$(#[$level($($lint),*)])*] aliases: $aliases version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $old:tt version: $version:tt strict: $strict:tt customs: $customs:tt } aliases = [$($alias:ident = $target:ident),* $(,)?]; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: $impls lints: $lints aliases: [$(($alias $target))*] version: $version strict: $strict customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $old:tt } customs = [$($field:ident = $($path:ident)::+),* $(,)?]; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: $strict customs: [$(($field [$($path)*]))*] } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $old:tt strict: $strict:tt customs: $customs:tt } version = $version:literal; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: $impls lints: $lints aliases: $aliases version: [$version] strict: $strict customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $old:tt customs: $customs:tt } strict = true; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: [strict] customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) { name: $name:tt derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $old:tt customs: $customs:tt } strict = false; $($t:tt)*) => {
+        $crate::__polymorphic_config!(@PARSE ($d) { name: $name derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: [] customs: $customs } $($t)*);
+    };
+    (@PARSE ($d:tt) $config:tt $key:ident = $($t:tt)*) => {
+        ::core::compile_error!(::core::concat!("unknown polymorphic_config! entry `", ::core::stringify!($key), "`"));
+    };
+
+    // Generate the configured macro
+    (@PARSE ($d:tt) { name: [$name:ident] derive: [$($derive:path),*] impls: [$($impl:ident)*] lints: [$($lints:tt)*] aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt }) => {
+        macro_rules! $name {
+            ($d($d t:tt)*) => {
+                $crate::polymorphic_constant!(@BLOCK { derive: [$($derive),*] impls: [$($impl)*] lints: [$($lints)*] aliases: $aliases version: $version strict: $strict customs: $customs } $d($d t)*);
+            };
+        }
+    };
+}
+
+/**
+Define polymorphic constants as associated constants of an existing type.
+
+The constants are declared inside an `impl` block, with the same syntax as [`polymorphic_constant!`]:
+```
+# use polymorphic_constant::polymorphic_assoc_const;
+struct Circle;
+
+polymorphic_assoc_const! {
+    impl Circle {
+        /// The ratio of the circumference to the diameter
+        pub const PI: f32 | f64 = 3.141592653589793;
+    }
+}
+
+let pi: f32 = Circle::PI.into();
+```
+
+The generated structs are not visible outside of the macro,
+so no free item is added to the surrounding module.
+
+The constants can also be declared in a trait, which is then implemented
+for every variant of the first constant, each associated constant being of type `Self`:
+```
+# use polymorphic_constant::polymorphic_assoc_const;
+polymorphic_assoc_const! {
+    pub trait FloatConsts {
+        const PI: f32 | f64 = 3.141592653589793;
+        const E: f32 | f64 = 2.718281828459045;
+    }
+}
+
+fn circumference<T: FloatConsts + core::ops::Mul<Output = T>>(diameter: T) -> T {
+    T::PI * diameter
+}
+
+assert_eq!(circumference(2.0f32), 6.2831855f32);
+```
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! polymorphic_assoc_const {
+    (impl $target:ty { $($body:tt)* }) => {
+        // Hide the generated structs and constants in an anonymous scope
+        const _: () = {
+            polymorphic_constant! { $($body)* }
+
+            polymorphic_assoc_const!(@IMPL $target {} $($body)*);
+        };
+    };
+
+    // Handle the trait format, with every visibility
+    ($(#[$attr:meta])* trait $trait:ident $body:tt) => {
+        polymorphic_assoc_const!(@TRAIT ($(#[$attr])*) $trait $body {} [] [] $body);
+    };
+    ($(#[$attr:meta])* pub trait $trait:ident $body:tt) => {
+        polymorphic_assoc_const!(@TRAIT ($(#[$attr])* pub) $trait $body {} [] [] $body);
+    };
+    ($(#[$attr:meta])* pub ($($vis:tt)+) trait $trait:ident $body:tt) => {
+        polymorphic_assoc_const!(@TRAIT ($(#[$attr])* pub ($($vis)+)) $trait $body {} [] [] $body);
+    };
+
+    // Unwrap the body to be munched
+    (@TRAIT $head:tt $trait:ident $body:tt {} [] [] { $($t:tt)* }) => {
+        polymorphic_assoc_const!(@TRAIT_ITEMS $head $trait $body {} [] [] $($t)*);
+    };
+
+    // Collect the trait items, and the variants of the first constant
+    (@TRAIT_ITEMS $head:tt $trait:ident $body:tt { $($done:tt)* } [$($names:ident)*] []
+        $(#[$attr:meta])* const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($t:tt)*) => {
+        polymorphic_assoc_const!(@TRAIT_ITEMS $head $trait $body { $($done)* $(#[$attr])* const $name: Self; }
+            [$($names)* $name] [$($numeric_type)*] $($t)*);
+    };
+    (@TRAIT_ITEMS $head:tt $trait:ident $body:tt { $($done:tt)* } [$($names:ident)*] [$($types:ident)+]
+        $(#[$attr:meta])* const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($t:tt)*) => {
+        polymorphic_assoc_const!(@TRAIT_ITEMS $head $trait $body { $($done)* $(#[$attr])* const $name: Self; }
+            [$($names)* $name] [$($types)+] $($t)*);
+    };
+    (@TRAIT_ITEMS ($($head:tt)*) $trait:ident { $($body:tt)* } { $($done:tt)* } [$($names:ident)*] [$($types:ident)*]) => {
+        $($head)* trait $trait {
+            $($done)*
+        }
+
+        // Hide the generated structs and constants in an anonymous scope
+        const _: () = {
+            polymorphic_constant! { $($body)* }
+
+            polymorphic_assoc_const!(@TRAIT_IMPL $trait [$($names)*] $($types)*);
+        };
+    };
+
+    // Implement the trait for each variant type
+    (@TRAIT_IMPL $trait:ident [$($names:ident)*] $numeric_type:ident $($types:ident)*) => {
+        impl $trait for __nz_impl!(@GET_TYPE $numeric_type) {
+            $(const $names: Self = $names.$numeric_type;)*
+        }
+        polymorphic_assoc_const!(@TRAIT_IMPL $trait [$($names)*] $($types)*);
+    };
+    (@TRAIT_IMPL $trait:ident [$($names:ident)*]) => {};
+
+    // Collect the associated constants, forwarding the visibility
+    (@IMPL $target:ty { $($done:tt)* } $(#[$attr:meta])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($t:tt)*) => {
+        polymorphic_assoc_const!(@IMPL $target { $($done)* $(#[$attr])* pub ($($vis)+) const $name: $name = $name; } $($t)*);
+    };
+    (@IMPL $target:ty { $($done:tt)* } $(#[$attr:meta])* pub const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($t:tt)*) => {
+        polymorphic_assoc_const!(@IMPL $target { $($done)* $(#[$attr])* pub const $name: $name = $name; } $($t)*);
+    };
+    (@IMPL $target:ty { $($done:tt)* } $(#[$attr:meta])* const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($t:tt)*) => {
+        polymorphic_assoc_const!(@IMPL $target { $($done)* $(#[$attr])* const $name: $name = $name; } $($t)*);
+    };
+    (@IMPL $target:ty { $($done:tt)* }) => {
+        impl $target {
+            $($done)*
+        }
+    };
+}
+
+/**
+Define coefficient matrices in multiple types at once, such as filter banks or color-space conversions.
+
+Each variant holds the matrix as nested arrays, every element being checked like the literal of a constant,
+and the number of rows and columns are given by the `ROWS` and `COLUMNS` constants of the generated struct:
+```
+# use polymorphic_constant::polymorphic_matrix;
+polymorphic_matrix! {
+    /// The conversion from RGB to YCbCr
+    pub const RGB_TO_YCBCR: f32 | f64 = [
+        [0.299, 0.587, 0.114],
+        [-0.168736, -0.331264, 0.5],
+        [0.5, -0.418688, -0.081312],
+    ];
+}
+
+let matrix: [[f32; 3]; 3] = RGB_TO_YCBCR.into();
+assert_eq!(matrix[0][1], 0.587);
+assert_eq!((RGB_TO_YCBCR::ROWS, RGB_TO_YCBCR::COLUMNS), (3, 3));
+```
+
+Rows of different lengths fail to compile. The `flatten` attribute generates a companion
+holding the same elements in row-major order, for APIs expecting a flat buffer:
+```
+# use polymorphic_constant::polymorphic_matrix;
+polymorphic_matrix! {
+    #[flatten(KERNEL_FLAT)]
+    const KERNEL: i16 | i32 = [
+        [1, 2, 1],
+        [2, 4, 2],
+    ];
+}
+
+let kernel: [i32; 6] = KERNEL_FLAT.into();
+assert_eq!(kernel, [1, 2, 1, 2, 4, 2]);
+```
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! polymorphic_matrix {
+    // Keep the `flatten` attribute apart, the other attributes applying to the struct and the constant
+    (@ATTRS $flat:tt [$($attrs:tt)*] #[flatten($flat_name:ident)] $($t:tt)*) => {
+        polymorphic_matrix!(@ATTRS [$flat_name] [$($attrs)*] $($t)*);
+    };
+    (@ATTRS $flat:tt [$($attrs:tt)*] #[$attr:meta] $($t:tt)*) => {
+        polymorphic_matrix!(@ATTRS $flat [$($attrs)* #[$attr]] $($t)*);
+    };
+    (@ATTRS $flat:tt [$($attrs:tt)*] ($($vis:tt)*) $name:ident [$($numeric_type:ident)*] $matrix:tt) => {
+        #[allow(non_camel_case_types, deprecated)]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
+        $($attrs)*
+        $($vis)* struct $name {
+            $(
+                #[doc = ::core::concat!("The elements of `", ::core::stringify!($name), "` as `", ::core::stringify!($numeric_type), "`")]
+                $numeric_type: [[__nz_impl!(@GET_TYPE $numeric_type); $name::COLUMNS]; $name::ROWS],
+            )*
+        }
+
+        #[allow(deprecated)]
+        impl $name {
+            /// The number of rows of the matrix
+            $($vis)* const ROWS: ::core::primitive::usize = polymorphic_matrix!(@ROWS_COUNT $matrix);
+            /// The number of columns of the matrix
+            $($vis)* const COLUMNS: ::core::primitive::usize = polymorphic_matrix!(@COLUMNS_COUNT $matrix);
+        }
+
+        #[allow(non_upper_case_globals, deprecated)]
+        $($attrs)*
+        $($vis)* const $name: $name = $name {
+            $($numeric_type: polymorphic_matrix!(@ROWS $numeric_type $matrix),)*
+        };
+
+        $(polymorphic_matrix!(@INTO $name $numeric_type [[__nz_impl!(@GET_TYPE $numeric_type); $name::COLUMNS]; $name::ROWS]);)*
+
+        polymorphic_matrix!(@FLATTEN ($($vis)*) $name $flat [$($numeric_type)*] $matrix);
+    };
+
+    // Generate the companion holding the elements in row-major order
+    (@FLATTEN $vis:tt $name:ident [] $types:tt $matrix:tt) => {};
+    (@FLATTEN ($($vis:tt)*) $name:ident [$flat:ident] [$($numeric_type:ident)*] $rows:tt) => {
+        #[allow(non_camel_case_types, deprecated)]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
+        #[doc = ::core::concat!("`", ::core::stringify!($name), "` in row-major order")]
+        $($vis)* struct $flat {
+            $(
+                #[doc = ::core::concat!("The elements of `", ::core::stringify!($flat), "` as `", ::core::stringify!($numeric_type), "`")]
+                $numeric_type: [__nz_impl!(@GET_TYPE $numeric_type); $name::ROWS * $name::COLUMNS],
+            )*
+        }
+
+        #[allow(non_upper_case_globals, deprecated)]
+        #[doc = ::core::concat!("`", ::core::stringify!($name), "` in row-major order")]
+        $($vis)* const $flat: $flat = $flat {
+            $($numeric_type: polymorphic_matrix!(@FLAT $numeric_type $rows),)*
+        };
+
+        $(polymorphic_matrix!(@INTO $flat $numeric_type [__nz_impl!(@GET_TYPE $numeric_type); $name::ROWS * $name::COLUMNS]);)*
+    };
+
+    (@INTO $name:ident $numeric_type:ident $array:ty) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $array {
+            fn from(matrix: $name) -> Self {
+                matrix.$numeric_type
+            }
+        }
+    };
+
+    // Check every element like a literal initializer
+    (@ROWS $numeric_type:ident [$([$($lit:literal),* $(,)?])*]) => {
+        [$([$(__nz_impl!(@MAKE_VAL $lit, $numeric_type)),*]),*]
+    };
+    (@FLAT $numeric_type:ident [$([$($lit:literal),* $(,)?])*]) => {
+        [$($(__nz_impl!(@MAKE_VAL $lit, $numeric_type),)*)*]
+    };
+    // The columns are counted in the first row, the other rows failing to compile if their length differs
+    (@ROWS_COUNT [$($row:tt)*]) => {
+        <[&::core::primitive::str]>::len(&[$(::core::stringify!($row)),*])
+    };
+    (@COLUMNS_COUNT [[$($lit:literal),* $(,)?] $($rows:tt)*]) => {
+        <[&::core::primitive::str]>::len(&[$(::core::stringify!($lit)),*])
+    };
+
+    // Entry points, handling every visibility
+    ($(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = [$($rows:tt),+ $(,)?]; $($t:tt)*) => {
+        polymorphic_matrix!(@ATTRS [] [] $(#[$($attr)*])* () $name [$($numeric_type)*] [$($rows)+]);
+        polymorphic_matrix!($($t)*);
+    };
+    ($(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = [$($rows:tt),+ $(,)?]; $($t:tt)*) => {
+        polymorphic_matrix!(@ATTRS [] [] $(#[$($attr)*])* (pub) $name [$($numeric_type)*] [$($rows)+]);
+        polymorphic_matrix!($($t)*);
+    };
+    ($(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = [$($rows:tt),+ $(,)?]; $($t:tt)*) => {
+        polymorphic_matrix!(@ATTRS [] [] $(#[$($attr)*])* (pub ($($vis)+)) $name [$($numeric_type)*] [$($rows)+]);
+        polymorphic_matrix!($($t)*);
+    };
+    () => {};
+}
+
+/**
+Define tunable parameters in multiple types at once, with their default value and limits in one line.
+
+The constant holds the default value, also available as `DEFAULT`, and its `MIN` and `MAX` associated constants hold the limits
+in the same variants. Each variant also gets a `const fn` of the same name, clamping a value of its type
+to the limits. The default value must be within the limits, and each of them must fit in every variant:
+```
+# use polymorphic_constant::polymorphic_limits;
+polymorphic_limits! {
+    /// The number of worker threads
+    pub const WORKERS: u8 | u32 | usize = 8 in 1..=64;
+    const GAIN: f32 | f64 = 1.0 in 0.0..=4.0;
+}
+
+assert_eq!(WORKERS.u32, 8);
+assert_eq!((WORKERS::MIN.usize, WORKERS::MAX.u8), (1, 64));
+assert_eq!(WORKERS::u32(100), 64);
+assert_eq!(GAIN::f64(-0.5), 0.0);
+```
+```compile_fail
+# use polymorphic_constant::polymorphic_limits;
+polymorphic_limits! {
+    const WORKERS: u8 | u32 = 0 in 1..=64;
+}
+# fn main() { let _ = WORKERS; }
+```
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! polymorphic_limits {
+    (@LIMITS $vis:vis const $name:ident [$($numeric_type:ident)*] $min:literal $max:literal) => {
+        #[allow(deprecated)]
+        impl $name {
+            /// The default value, the constant itself
+            $vis const DEFAULT: $name = $name;
+            /// The lowest value of the parameter
+            $vis const MIN: $name = $name {
+                $($numeric_type: __nz_impl!(@VARIANT nearest $name $min, $numeric_type),)*
+            };
+            /// The highest value of the parameter
+            $vis const MAX: $name = $name {
+                $($numeric_type: __nz_impl!(@VARIANT nearest $name $max, $numeric_type),)*
+            };
+            $(
+                #[doc = ::core::concat!("Clamp a `", ::core::stringify!($numeric_type), "` to the limits of `", ::core::stringify!($name), "`")]
+                #[allow(dead_code)]
+                $vis const fn $numeric_type(value: __nz_impl!(@FIELD_TYPE $name $numeric_type)) -> __nz_impl!(@FIELD_TYPE $name $numeric_type) {
+                    if __nz_impl!(@PLAIN $numeric_type, value) < __nz_impl!(@PLAIN $numeric_type, $name::MIN.$numeric_type) {
+                        $name::MIN.$numeric_type
+                    } else if __nz_impl!(@PLAIN $numeric_type, value) > __nz_impl!(@PLAIN $numeric_type, $name::MAX.$numeric_type) {
+                        $name::MAX.$numeric_type
+                    } else {
+                        value
+                    }
+                }
+            )*
+        }
+
+        // The default value is compared with the limits in each variant
+        #[allow(deprecated)]
+        const _: () = {
+            $(
+                if __nz_impl!(@PLAIN $numeric_type, $name::MIN.$numeric_type) > __nz_impl!(@PLAIN $numeric_type, $name.$numeric_type)
+                    || __nz_impl!(@PLAIN $numeric_type, $name.$numeric_type) > __nz_impl!(@PLAIN $numeric_type, $name::MAX.$numeric_type)
+                {
+                    ::core::panic!(::core::concat!(
+                        "the default value of `", ::core::stringify!($name), "` is not within its limits as `", ::core::stringify!($numeric_type), "`"
+                    ));
+                }
+            )*
+        };
+    };
+
+    // Entry points, handling every visibility, the constant itself being generated by the main macro
+    ($(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = $default:literal in $min:literal ..= $max:literal; $($t:tt)*) => {
+        polymorphic_constant! { $(#[$($attr)*])* const $name : $($numeric_type)|* = $default; }
+        polymorphic_limits!(@LIMITS const $name [$($numeric_type)*] $min $max);
+        polymorphic_limits!($($t)*);
+    };
+    ($(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = $default:literal in $min:literal ..= $max:literal; $($t:tt)*) => {
+        polymorphic_constant! { $(#[$($attr)*])* pub const $name : $($numeric_type)|* = $default; }
+        polymorphic_limits!(@LIMITS pub const $name [$($numeric_type)*] $min $max);
+        polymorphic_limits!($($t)*);
+    };
+    ($(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = $default:literal in $min:literal ..= $max:literal; $($t:tt)*) => {
+        polymorphic_constant! { $(#[$($attr)*])* pub ($($vis)+) const $name : $($numeric_type)|* = $default; }
+        polymorphic_limits!(@LIMITS pub ($($vis)+) const $name [$($numeric_type)*] $min $max);
+        polymorphic_limits!($($t)*);
+    };
+    () => {};
+}
+
+/**
+Define sorted tables in multiple types at once, such as breakpoints or calibration curves, with their lookup functions.
+
+Each variant holds the breakpoints as an array, every element being checked like the literal of a constant,
+and the number of breakpoints is given by the `LEN` constant of the generated struct. The breakpoints must be strictly increasing
+in every variant. Each variant also gets a `const fn` of the same name, counting with a binary search
+the breakpoints lower or equal to a value of its type, which is the index of the interval holding it:
+```
+# use polymorphic_constant::polymorphic_table;
+polymorphic_table! {
+    /// The thresholds of the battery levels
+    pub const THRESHOLDS: u8 | u16 | nz_u32 = [10, 25, 50, 75];
+}
+
+let thresholds: [u16; 4] = THRESHOLDS.into();
+assert_eq!(thresholds[2], 50);
+assert_eq!(THRESHOLDS::LEN, 4);
+assert_eq!(THRESHOLDS::u8(5), 0);
+assert_eq!(THRESHOLDS::u16(60), 3);
+assert_eq!(THRESHOLDS::u8(200), 4);
+```
+
+Breakpoints followed by `=>` and as many values make the functions interpolate linearly between the values, in `f64`,
+clamping to the first and last values outside of the breakpoints. The values are available as the `VALUES` constant,
+and interpolated tables only accept the primitive variants:
+```
+# use polymorphic_constant::polymorphic_table;
+polymorphic_table! {
+    const THROTTLE: f32 | f64 = [0.0, 10.0, 20.0] => [0.0, 50.0, 60.0];
+}
+
+assert_eq!(THROTTLE::f32(5.0), 25.0);
+assert_eq!(THROTTLE::f64(15.0), 55.0);
+assert_eq!(THROTTLE::f64(100.0), 60.0);
+assert_eq!(THROTTLE::VALUES.f64[1], 50.0);
+```
+```compile_fail
+# use polymorphic_constant::polymorphic_table;
+polymorphic_table! {
+    const UNSORTED: u8 | u16 = [10, 5, 20];
+}
+# fn main() { let _ = UNSORTED; }
+```
+*/
+#[macro_export(local_inner_macros)]
+macro_rules! polymorphic_table {
+    (@TABLE ($($vis:tt)*) [$($attrs:tt)*] $name:ident [$($numeric_type:ident)*] $lits:tt $values:tt) => {
+        #[allow(non_camel_case_types, deprecated)]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
+        $($attrs)*
+        $($vis)* struct $name {
+            $(
+                #[doc = ::core::concat!("The breakpoints of `", ::core::stringify!($name), "` as `", ::core::stringify!($numeric_type), "`")]
+                $numeric_type: [__nz_impl!(@GET_TYPE $numeric_type); $name::LEN],
+            )*
+        }
+
+        #[allow(deprecated)]
+        impl $name {
+            /// The number of breakpoints of the table
+            $($vis)* const LEN: ::core::primitive::usize = polymorphic_table!(@LEN $lits);
+        }
+
+        #[allow(non_upper_case_globals, deprecated)]
+        $($attrs)*
+        $($vis)* const $name: $name = $name {
+            $($numeric_type: polymorphic_table!(@ELEMENTS $numeric_type $lits),)*
+        };
+
+        $(
+            #[allow(deprecated)]
+            impl ::core::convert::From<$name> for [__nz_impl!(@GET_TYPE $numeric_type); $name::LEN] {
+                fn from(table: $name) -> Self {
+                    table.$numeric_type
+                }
+            }
+        )*
+
+        // The breakpoints are compared in each variant, as rounding may merge them
+        #[allow(deprecated)]
+        const _: () = {
+            $(
+                let mut i = 1;
+                while i < $name::LEN {
+                    if __nz_impl!(@PLAIN $numeric_type, $name.$numeric_type[i - 1]) >= __nz_impl!(@PLAIN $numeric_type, $name.$numeric_type[i]) {
+                        ::core::panic!(::core::concat!(
+                            "the breakpoints of `", ::core::stringify!($name), "` are not strictly increasing as `", ::core::stringify!($numeric_type), "`"
+                        ));
+                    }
+                    i += 1;
+                }
+            )*
+        };
+
+        polymorphic_table!(@LOOKUP $($vis)* const $name [$($numeric_type)*] $values);
+    };
+
+    // Count the breakpoints lower or equal to the value
+    (@LOOKUP $vis:vis const $name:ident [$($numeric_type:ident)*] []) => {
+        #[allow(deprecated)]
+        impl $name {
+            $(
+                #[doc = ::core::concat!("Find the interval of `", ::core::stringify!($name), "` holding a `", ::core::stringify!($numeric_type), "`, with a binary search")]
+                #[allow(dead_code)]
+                $vis const fn $numeric_type(value: __nz_impl!(@GET_TYPE $numeric_type)) -> ::core::primitive::usize {
+                    polymorphic_table!(@SEARCH $name $numeric_type value)
+                }
+            )*
+        }
+    };
+    // Interpolate between the values around the interval
+    (@LOOKUP $vis:vis const $name:ident [$($numeric_type:ident)*] [$values:tt]) => {
+        #[allow(deprecated)]
+        impl $name {
+            /// The values of the table at each breakpoint
+            $vis const VALUES: $name = $name {
+                $($numeric_type: polymorphic_table!(@ELEMENTS $numeric_type $values),)*
+            };
+            $(
+                #[doc = ::core::concat!("Interpolate the values of `", ::core::stringify!($name), "` at a `", ::core::stringify!($numeric_type), "`, clamping to the first and last values")]
+                #[allow(dead_code)]
+                $vis const fn $numeric_type(value: __nz_impl!(@GET_TYPE $numeric_type)) -> __nz_impl!(@GET_TYPE $numeric_type) {
+                    let (x, y) = ($name.$numeric_type, $name::VALUES.$numeric_type);
+                    let index = polymorphic_table!(@SEARCH $name $numeric_type value);
+                    if index == 0 {
+                        y[0]
+                    } else if index == $name::LEN {
+                        y[$name::LEN - 1]
+                    } else {
+                        let (x0, x1) = (x[index - 1] as ::core::primitive::f64, x[index] as ::core::primitive::f64);
+                        let (y0, y1) = (y[index - 1] as ::core::primitive::f64, y[index] as ::core::primitive::f64);
+                        (y0 + (y1 - y0) * (value as ::core::primitive::f64 - x0) / (x1 - x0)) as __nz_impl!(@GET_TYPE $numeric_type)
+                    }
+                }
+            )*
+        }
+    };
+
+    // Check every element like a literal initializer
+    (@ELEMENTS $numeric_type:ident [$($lit:literal)*]) => {
+        [$(__nz_impl!(@MAKE_VAL $lit, $numeric_type)),*]
+    };
+    (@LEN [$($lit:literal)*]) => {
+        <[&::core::primitive::str]>::len(&[$(::core::stringify!($lit)),*])
+    };
+
+    (@SEARCH $name:ident $numeric_type:ident $value:ident) => {{
+        let (mut low, mut high) = (0, $name::LEN);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if __nz_impl!(@PLAIN $numeric_type, $name.$numeric_type[middle]) <= __nz_impl!(@PLAIN $numeric_type, $value) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }};
+
+    // Entry points, handling every visibility
+    ($(#[$($attr:tt)*])* const $name:ident : $( $numeric_type:ident )|* = [$($lit:literal),+ $(,)?] $(=> [$($value:literal),+ $(,)?])?; $($t:tt)*) => {
+        polymorphic_table!(@TABLE () [$(#[$($attr)*])*] $name [$($numeric_type)*] [$($lit)+] [$([$($value)+])?]);
+        polymorphic_table!($($t)*);
+    };
+    ($(#[$($attr:tt)*])* pub const $name:ident : $( $numeric_type:ident )|* = [$($lit:literal),+ $(,)?] $(=> [$($value:literal),+ $(,)?])?; $($t:tt)*) => {
+        polymorphic_table!(@TABLE (pub) [$(#[$($attr)*])*] $name [$($numeric_type)*] [$($lit)+] [$([$($value)+])?]);
+        polymorphic_table!($($t)*);
+    };
+    ($(#[$($attr:tt)*])* pub ($($vis:tt)+) const $name:ident : $( $numeric_type:ident )|* = [$($lit:literal),+ $(,)?] $(=> [$($value:literal),+ $(,)?])?; $($t:tt)*) => {
+        polymorphic_table!(@TABLE (pub ($($vis)+)) [$(#[$($attr)*])*] $name [$($numeric_type)*] [$($lit)+] [$([$($value)+])?]);
+        polymorphic_table!($($t)*);
+    };
+    () => {};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __nz_impl {
+    // constally obtain a nonzero struct
+    // Narrow computed values with a check, as `as` would silently truncate them
+    // Find the `round` option, only changing how float variants are narrowed
+    (@ROUNDING $default:ident [(round ($mode:ident)) $($options:tt)*] $args:tt) => {
+        $crate::__nz_impl!(@ROUNDING $mode [] $args);
+    };
+    (@ROUNDING $mode:ident [$other:tt $($options:tt)*] $args:tt) => {
+        $crate::__nz_impl!(@ROUNDING $mode [$($options)*] $args);
+    };
+    (@ROUNDING $mode:ident [] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt $options:tt)) => {
+        $crate::__nz_impl!(@PRUNING $mode $options ($config $docs $attrs $vis $name $types $lit $options));
+    };
+    // Find the `prune_unfit` option, replacing the variants that cannot hold the literal instead of failing
+    (@PRUNING $mode:ident [(prune_unfit) $($options:tt)*] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt $all:tt)) => {
+        $crate::__nz_impl!(@PRUNE $mode $config $docs $attrs $vis $name $types $lit $all);
+    };
+    (@PRUNING $mode:ident [$other:tt $($options:tt)*] $args:tt) => {
+        $crate::__nz_impl!(@PRUNING $mode [$($options)*] $args);
+    };
+    (@PRUNING $mode:ident [] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt $options:tt)) => {
+        $crate::__nz_impl!(@SATURATING $mode $options ($config $docs $attrs $vis $name $types $lit $options));
+    };
+    // Find the `saturate` option, clamping the literal to the range of each variant instead of failing
+    (@SATURATING $mode:ident [(saturate) $($options:tt)*] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident [$($numeric_type:ident)*] $lit:tt $all:tt)) => {
+        $crate::__nz_impl!(@CUSTOM_FIND $all ($config $docs $attrs $vis $name [$($numeric_type)*] {
+            $($numeric_type: $crate::__nz_impl!(@SATURATE $mode $name $lit, $numeric_type),)*
+        } $lit $all));
+    };
+    (@SATURATING $mode:ident [$other:tt $($options:tt)*] $args:tt) => {
+        $crate::__nz_impl!(@SATURATING $mode [$($options)*] $args);
+    };
+    (@SATURATING $mode:ident [] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident [$($numeric_type:ident)*] $lit:tt $all:tt)) => {
+        $crate::__nz_impl!(@CUSTOM_FIND $all ($config $docs $attrs $vis $name [$($numeric_type)*] {
+            $($numeric_type: $crate::__nz_impl!(@VARIANT $mode $name $lit, $numeric_type),)*
+        } $lit $all));
+    };
+    (@PRUNE $mode:ident $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt (@EXPR $($init:tt)*) $all:tt) => {
+        ::core::compile_error!("`prune_unfit` only applies to literal initializers");
+    };
+    (@PRUNE $mode:ident $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt [(saturate) $($options:tt)*]) => {
+        ::core::compile_error!("`prune_unfit` cannot be combined with `saturate`");
+    };
+    (@PRUNE $mode:ident $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt [(flat $flat:tt) $($options:tt)*]) => {
+        ::core::compile_error!("`prune_unfit` cannot be combined with `flat`");
+    };
+    (@PRUNE $mode:ident $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt [(custom $custom:tt) $($options:tt)*]) => {
+        ::core::compile_error!("`prune_unfit` cannot be combined with `custom`");
+    };
+    (@PRUNE $mode:ident $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@PRUNE $mode $config $docs $attrs $vis $name $types $lit [$($options)*]);
+    };
+    // The struct holds the pruned variants as `Pruned`, so it has no conversions, and cannot be iterated over
+    (@PRUNE $mode:ident { derive: [$($derive:path),*] impls: $impls:tt lints: [$($lints:tt)*] aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } [$($docs:tt)*] [$($attrs:tt)*] ($($vis:tt)*) $name:ident [$($numeric_type:ident)*] $lit:tt []) => {
+        #[allow(non_camel_case_types, deprecated)]
+        $($lints)*
+        #[derive($($derive),*)]
+        $($docs)*
+        $($attrs)*
+        $($vis)* struct $name {
+            $(
+                #[doc = ::core::concat!("The value of `", ::core::stringify!($name), "` as `", ::core::stringify!($numeric_type), "`, or `Pruned` if it does not fit")]
+                $numeric_type: <$crate::__Fit<{ $crate::__fits_variant($name::__FITS, ::core::stringify!($numeric_type)) }> as $crate::__Prune<$crate::__nz_impl!(@GET_TYPE $numeric_type)>>::Type,
+            )*
+        }
+
+        $(
+            // The literal only overflows the floats that are pruned, whose value is never built
+            #[allow(deprecated, overflowing_literals)]
+            impl $crate::__PruneValue<$crate::__nz_impl!(@GET_TYPE $numeric_type), true> for $name {
+                const VALUE: $crate::__nz_impl!(@GET_TYPE $numeric_type) = $crate::__nz_impl!(@VARIANT $mode $name $lit, $numeric_type);
+            }
+
+            #[allow(deprecated)]
+            impl $crate::__PruneValue<$crate::__nz_impl!(@GET_TYPE $numeric_type), false> for $name {
+                const VALUE: $crate::Pruned = $crate::Pruned;
+            }
+        )*
+
+        #[allow(non_upper_case_globals, deprecated)]
+        $($lints)*
+        $($docs)*
+        $($vis)* const $name: $name = $name {
+            $($numeric_type: <$name as $crate::__PruneValue<
+                $crate::__nz_impl!(@GET_TYPE $numeric_type),
+                { $crate::__fits_variant($name::__FITS, ::core::stringify!($numeric_type)) },
+            >>::VALUE,)*
+        };
+
+        // List the kept variants, for `has_variant!`, and the pruned ones
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __FITS: &'static [(&'static ::core::primitive::str, ::core::primitive::bool)] = &[
+                $((::core::stringify!($numeric_type), $crate::__nz_impl!(@PRUNE_FITS $lit, $numeric_type)),)*
+            ];
+
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __VARIANTS: &'static [&'static ::core::primitive::str] =
+                &$crate::__list_fits::<{ $crate::__count_fits($name::__FITS, true) }>($name::__FITS, true);
+
+            #[doc = ::core::concat!("The variants of `", ::core::stringify!($name), "` omitted by `prune_unfit`, as they cannot hold the literal")]
+            #[allow(dead_code)]
+            $($vis)* const PRUNED: &'static [&'static ::core::primitive::str] =
+                &$crate::__list_fits::<{ $crate::__count_fits($name::__FITS, false) }>($name::__FITS, false);
+        }
+    };
+    // Only the range of primitive numbers is known without building the variant
+    (@PRUNE_FITS $lit:literal, f32) => { $crate::__nz_impl!(@PRUNE_FINITE $lit, f32) };
+    (@PRUNE_FITS $lit:literal, f64) => { $crate::__nz_impl!(@PRUNE_FINITE $lit, f64) };
+    (@PRUNE_FITS $lit:literal, i8   ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, i8   ) };
+    (@PRUNE_FITS $lit:literal, i16  ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, i16  ) };
+    (@PRUNE_FITS $lit:literal, i32  ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, i32  ) };
+    (@PRUNE_FITS $lit:literal, i64  ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, i64  ) };
+    (@PRUNE_FITS $lit:literal, i128 ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, i128 ) };
+    (@PRUNE_FITS $lit:literal, isize) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, isize) };
+    (@PRUNE_FITS $lit:literal, u8   ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, u8   ) };
+    (@PRUNE_FITS $lit:literal, u16  ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, u16  ) };
+    (@PRUNE_FITS $lit:literal, u32  ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, u32  ) };
+    (@PRUNE_FITS $lit:literal, u64  ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, u64  ) };
+    (@PRUNE_FITS $lit:literal, u128 ) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, u128 ) };
+    (@PRUNE_FITS $lit:literal, usize) => { $crate::__nz_impl!(@PRUNE_RANGE $lit, usize) };
+    (@PRUNE_FITS $lit:literal, $numeric_type:ident) => {
+        ::core::compile_error!(::core::concat!("`prune_unfit` only applies to primitive integer and float variants, not `", ::core::stringify!($numeric_type), "`"))
+    };
+    (@PRUNE_FINITE $lit:literal, $float:ident) => {{
+        #[allow(overflowing_literals, clippy::excessive_precision)]
+        let nearest: ::core::primitive::$float = $lit;
+        nearest.is_finite()
+    }};
+    (@PRUNE_RANGE $lit:literal, $int:ident) => {
+        match $crate::__int_value(::core::stringify!($lit)) {
+            (negative, ::core::option::Option::Some(magnitude)) => $crate::__fits(negative, magnitude, $crate::__nz_impl!(@RANGE $int)),
+            _ => false,
+        }
+    };
+    // Optional non-zero variants hold `None` when the value is zero, if allowed by `#[zero_is_none]`
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_i8   ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i8   , nz_i8   , $crate::__nz_impl!(@MAKE_VAL $lit, i8   )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_i16  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i16  , nz_i16  , $crate::__nz_impl!(@MAKE_VAL $lit, i16  )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_i32  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i32  , nz_i32  , $crate::__nz_impl!(@MAKE_VAL $lit, i32  )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_i64  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i64  , nz_i64  , $crate::__nz_impl!(@MAKE_VAL $lit, i64  )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_i128 ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i128 , nz_i128 , $crate::__nz_impl!(@MAKE_VAL $lit, i128 )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_isize) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_isize, nz_isize, $crate::__nz_impl!(@MAKE_VAL $lit, isize)) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_u8   ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u8   , nz_u8   , $crate::__nz_impl!(@MAKE_VAL $lit, u8   )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_u16  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u16  , nz_u16  , $crate::__nz_impl!(@MAKE_VAL $lit, u16  )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_u32  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u32  , nz_u32  , $crate::__nz_impl!(@MAKE_VAL $lit, u32  )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_u64  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u64  , nz_u64  , $crate::__nz_impl!(@MAKE_VAL $lit, u64  )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_u128 ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u128 , nz_u128 , $crate::__nz_impl!(@MAKE_VAL $lit, u128 )) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, opt_nz_usize) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_usize, nz_usize, $crate::__nz_impl!(@MAKE_VAL $lit, usize)) };
+    // Quantity variants hold their float variant, converted from the unit of their constant to the base unit
+    (@VARIANT $mode:ident $name:ident $lit:tt, uom_length      ) => { $crate::__nz_impl!(@UOM $name, length      , Length      , $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, uom_mass        ) => { $crate::__nz_impl!(@UOM $name, mass        , Mass        , $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, uom_time        ) => { $crate::__nz_impl!(@UOM $name, time        , Time        , $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, uom_velocity    ) => { $crate::__nz_impl!(@UOM $name, velocity    , Velocity    , $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, uom_acceleration) => { $crate::__nz_impl!(@UOM $name, acceleration, Acceleration, $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@VARIANT $mode:ident $name:ident $lit:tt, uom_frequency   ) => { $crate::__nz_impl!(@UOM $name, frequency   , Frequency   , $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    // Pointer variants hold the address in the pointer type selected for their constant
+    (@VARIANT $mode:ident $name:ident $lit:tt, ptr_u8   ) => { $crate::__nz_impl!(@MAKE_VAL $lit, usize) as _ };
+    (@VARIANT $mode:ident $name:ident $lit:tt, ptr_u16  ) => { $crate::__nz_impl!(@MAKE_VAL $lit, usize) as _ };
+    (@VARIANT $mode:ident $name:ident $lit:tt, ptr_u32  ) => { $crate::__nz_impl!(@MAKE_VAL $lit, usize) as _ };
+    (@VARIANT $mode:ident $name:ident $lit:tt, ptr_u64  ) => { $crate::__nz_impl!(@MAKE_VAL $lit, usize) as _ };
+    (@VARIANT $mode:ident $name:ident $lit:tt, ptr_usize) => { $crate::__nz_impl!(@MAKE_VAL $lit, usize) as _ };
+    // Literals of duration variants are scaled by the unit of their constant, which other variants ignore
+    (@VARIANT $mode:ident $name:ident $lit:literal, duration) => {
+        $crate::__nz_impl!(@DURATION $name, $crate::__nz_impl!(@LITERAL $lit, u64))
+    };
+    // Byte variants hold the representation of the value in the integer type selected for their constant
+    (@VARIANT $mode:ident $name:ident $lit:tt, le_bytes) => { $name::__BYTES.to_le_bytes() };
+    (@VARIANT $mode:ident $name:ident $lit:tt, be_bytes) => { $name::__BYTES.to_be_bytes() };
+    (@VARIANT $mode:ident $name:ident $lit:tt, ne_bytes) => { $name::__BYTES.to_ne_bytes() };
+    (@VARIANT $mode:ident $name:ident $lit:tt, $numeric_type:ident) => { $crate::__nz_impl!(@ROUND $mode $lit, $numeric_type) };
+    (@SATURATE $mode:ident $name:ident (@EXPR $($init:tt)*), $numeric_type:ident) => {
+        ::core::compile_error!("`saturate` only applies to literal initializers")
+    };
+    // Floats overflowing to infinity are clamped to their largest finite value
+    (@SATURATE $mode:ident $name:ident $lit:literal, f32) => { $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f32) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, f64) => { $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, real) => { $crate::__real_impl!((@SATURATE_FLOAT $mode $lit,)) };
+    // `f16` variants are either the unstable primitive or the `half` type, depending on the features of this crate
+    (@SATURATE $mode:ident $name:ident $lit:literal, f16) => { $crate::__f16_impl!((@SATURATE $mode $name $lit,)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nightly_f16) => { $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f16) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, half_f16) => { $crate::__nz_impl!(@SATURATE_HALF $mode $lit, f16) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bf16) => { $crate::__nz_impl!(@SATURATE_HALF $mode $lit, bf16) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, f128) => { $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f128) };
+    // Complex variants hold the value of their float variant, with no imaginary part
+    (@SATURATE $mode:ident $name:ident $lit:literal, c_f32) => { $crate::__complex_impl!(@NEW $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f32)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, c_f64) => { $crate::__complex_impl!(@NEW $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    // Ordered variants wrap their float variant, which is never NaN
+    (@SATURATE $mode:ident $name:ident $lit:literal, of_f32) => { $crate::__ordered_impl!(@ORDERED $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f32)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, of_f64) => { $crate::__ordered_impl!(@ORDERED $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nn_f32) => { $crate::__ordered_impl!(@NOT_NAN f32 $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f32)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nn_f64) => { $crate::__ordered_impl!(@NOT_NAN f64 $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    // Quantity variants clamp their float variant before converting it
+    (@SATURATE $mode:ident $name:ident $lit:literal, uom_length      ) => { $crate::__nz_impl!(@UOM $name, length      , Length      , $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, uom_mass        ) => { $crate::__nz_impl!(@UOM $name, mass        , Mass        , $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, uom_time        ) => { $crate::__nz_impl!(@UOM $name, time        , Time        , $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, uom_velocity    ) => { $crate::__nz_impl!(@UOM $name, velocity    , Velocity    , $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, uom_acceleration) => { $crate::__nz_impl!(@UOM $name, acceleration, Acceleration, $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, uom_frequency   ) => { $crate::__nz_impl!(@UOM $name, frequency   , Frequency   , $crate::__nz_impl!(@SATURATE_FLOAT $mode $lit, f64)) };
+    (@SATURATE_FLOAT $mode:ident $lit:literal, $float:ident) => {{
+        #[allow(overflowing_literals, clippy::excessive_precision)]
+        let value = {
+            let nearest: $crate::__nz_impl!(@GET_TYPE $float) = $lit;
+            if nearest == <$crate::__nz_impl!(@GET_TYPE $float)>::INFINITY {
+                <$crate::__nz_impl!(@GET_TYPE $float)>::MAX
+            } else if nearest == <$crate::__nz_impl!(@GET_TYPE $float)>::NEG_INFINITY {
+                <$crate::__nz_impl!(@GET_TYPE $float)>::MIN
+            } else {
+                $crate::__nz_impl!(@ROUND $mode $lit, $float)
+            }
+        };
+        value
+    }};
+    // The `half` types are built from `f64` in const contexts, where they cannot be compared
+    (@SATURATE_HALF nearest $lit:literal, $half:ident) => {{
+        let half = <$crate::__nz_impl!(@GET_TYPE $half)>::from_f64_const($lit as ::core::primitive::f64);
+        if half.to_f64_const() == ::core::primitive::f64::INFINITY {
+            <$crate::__nz_impl!(@GET_TYPE $half)>::MAX
+        } else if half.to_f64_const() == ::core::primitive::f64::NEG_INFINITY {
+            <$crate::__nz_impl!(@GET_TYPE $half)>::MIN
+        } else {
+            half
+        }
+    }};
+    (@SATURATE_HALF $mode:ident $lit:literal, $half:ident) => { $crate::__nz_impl!(@ROUND $mode $lit, $half) };
+    // Non-zero variants are clamped to their range, zero still failing to compile
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_i8       ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i8       , $crate::__nz_impl!(@SATURATE_NZ $lit, i8   )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_i16      ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i16      , $crate::__nz_impl!(@SATURATE_NZ $lit, i16  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_i32      ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i32      , $crate::__nz_impl!(@SATURATE_NZ $lit, i32  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_i64      ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i64      , $crate::__nz_impl!(@SATURATE_NZ $lit, i64  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_i128     ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i128     , $crate::__nz_impl!(@SATURATE_NZ $lit, i128 )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_isize    ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_isize    , $crate::__nz_impl!(@SATURATE_NZ $lit, isize)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_u8       ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u8       , $crate::__nz_impl!(@SATURATE_NZ $lit, u8   )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_u16      ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u16      , $crate::__nz_impl!(@SATURATE_NZ $lit, u16  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_u32      ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u32      , $crate::__nz_impl!(@SATURATE_NZ $lit, u32  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_u64      ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u64      , $crate::__nz_impl!(@SATURATE_NZ $lit, u64  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_u128     ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u128     , $crate::__nz_impl!(@SATURATE_NZ $lit, u128 )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nz_usize    ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_usize    , $crate::__nz_impl!(@SATURATE_NZ $lit, usize)) };
+    // Optional non-zero variants hold `None` when the clamped literal is zero, if allowed by `#[zero_is_none]`
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_i8   ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i8   , nz_i8   , $crate::__nz_impl!(@SATURATE_NZ $lit, i8   )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_i16  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i16  , nz_i16  , $crate::__nz_impl!(@SATURATE_NZ $lit, i16  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_i32  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i32  , nz_i32  , $crate::__nz_impl!(@SATURATE_NZ $lit, i32  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_i64  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i64  , nz_i64  , $crate::__nz_impl!(@SATURATE_NZ $lit, i64  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_i128 ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_i128 , nz_i128 , $crate::__nz_impl!(@SATURATE_NZ $lit, i128 )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_isize) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_isize, nz_isize, $crate::__nz_impl!(@SATURATE_NZ $lit, isize)) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_u8   ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u8   , nz_u8   , $crate::__nz_impl!(@SATURATE_NZ $lit, u8   )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_u16  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u16  , nz_u16  , $crate::__nz_impl!(@SATURATE_NZ $lit, u16  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_u32  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u32  , nz_u32  , $crate::__nz_impl!(@SATURATE_NZ $lit, u32  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_u64  ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u64  , nz_u64  , $crate::__nz_impl!(@SATURATE_NZ $lit, u64  )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_u128 ) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_u128 , nz_u128 , $crate::__nz_impl!(@SATURATE_NZ $lit, u128 )) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, opt_nz_usize) => { $crate::__nz_impl!(@OPT_NZ $name, opt_nz_usize, nz_usize, $crate::__nz_impl!(@SATURATE_NZ $lit, usize)) };
+    // Variants without a maximum are clamped below it, and bounded variants to their bounds
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_i8   ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, i8   )), nonmax_i8   ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_i16  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, i16  )), nonmax_i16  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_i32  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, i32  )), nonmax_i32  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_i64  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, i64  )), nonmax_i64  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_i128 ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, i128 )), nonmax_i128 ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_isize) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, isize)), nonmax_isize) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_u8   ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, u8   )), nonmax_u8   ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_u16  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, u16  )), nonmax_u16  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_u32  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, u32  )), nonmax_u32  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_u64  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, u64  )), nonmax_u64  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_u128 ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, u128 )), nonmax_u128 ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, nonmax_usize) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_NONMAX $lit, usize)), nonmax_usize) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_i8   ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, i8   )), bounded_i8   ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_i16  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, i16  )), bounded_i16  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_i32  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, i32  )), bounded_i32  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_i64  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, i64  )), bounded_i64  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_i128 ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, i128 )), bounded_i128 ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_isize) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, isize)), bounded_isize) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_u8   ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, u8   )), bounded_u8   ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_u16  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, u16  )), bounded_u16  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_u32  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, u32  )), bounded_u32  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_u64  ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, u64  )), bounded_u64  ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_u128 ) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, u128 )), bounded_u128 ) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bounded_usize) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_BOUNDED $name $lit, usize)), bounded_usize) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, u24) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_UINT $lit, u32, 24)), u24) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, u40) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_UINT $lit, u64, 40)), u40) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, u48) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_UINT $lit, u64, 48)), u48) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, u56) => { $crate::__nz_impl!(@MAKE_VAL (@EXPR $name $crate::__nz_impl!(@SATURATE_UINT $lit, u64, 56)), u56) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, bigint) => { $crate::__nz_impl!(@MAKE_VAL $lit, bigint) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, biguint) => { $crate::__nz_impl!(@MAKE_VAL $lit, biguint) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, limbs) => { $crate::__nz_impl!(@MAKE_VAL $lit, limbs) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, str) => { $crate::__nz_impl!(@MAKE_VAL $lit, str) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, u256) => { $crate::__nz_impl!(@MAKE_VAL $lit, u256) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, i256) => { $crate::__nz_impl!(@MAKE_VAL $lit, i256) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, le_bytes) => { $crate::__nz_impl!(@VARIANT $mode $name $lit, le_bytes) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, be_bytes) => { $crate::__nz_impl!(@VARIANT $mode $name $lit, be_bytes) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ne_bytes) => { $crate::__nz_impl!(@VARIANT $mode $name $lit, ne_bytes) };
+    // Booleans are clamped to `false` below one, and `true` above
+    (@SATURATE $mode:ident $name:ident $lit:literal, bool) => {{
+        let _ = $crate::__nz_impl!(@ROUND_MODE $mode);
+        let value: ::core::primitive::i128 = $lit;
+        value > 0
+    }};
+    // Characters are clamped to the range of code points, surrogates still failing to compile
+    (@SATURATE $mode:ident $name:ident $lit:literal, char) => {{
+        let _ = $crate::__nz_impl!(@ROUND_MODE $mode);
+        let value: ::core::primitive::i128 = $lit;
+        let value = if value < 0 { 0 } else if value > 0x10FFFF { 0x10FFFF } else { value as ::core::primitive::u32 };
+        $crate::__nz_impl!(@CHAR ::core::stringify!($lit), value)
+    }};
+    (@SATURATE $mode:ident $name:ident $lit:literal, duration) => { $crate::__nz_impl!(@VARIANT $mode $name $lit, duration) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ptr_u8   ) => { $crate::__nz_impl!(@SATURATE_INT $lit, usize) as _ };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ptr_u16  ) => { $crate::__nz_impl!(@SATURATE_INT $lit, usize) as _ };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ptr_u32  ) => { $crate::__nz_impl!(@SATURATE_INT $lit, usize) as _ };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ptr_u64  ) => { $crate::__nz_impl!(@SATURATE_INT $lit, usize) as _ };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ptr_usize) => { $crate::__nz_impl!(@SATURATE_INT $lit, usize) as _ };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ipv4) => { $crate::__nz_impl!(@MAKE_VAL $lit, ipv4) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, decimal) => { $crate::__nz_impl!(@MAKE_VAL $lit, decimal) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ratio_i32) => { $crate::__nz_impl!(@MAKE_VAL $lit, ratio_i32) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ratio_i64) => { $crate::__nz_impl!(@MAKE_VAL $lit, ratio_i64) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ratio_i128) => { $crate::__nz_impl!(@MAKE_VAL $lit, ratio_i128) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ratio_u32) => { $crate::__nz_impl!(@MAKE_VAL $lit, ratio_u32) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ratio_u64) => { $crate::__nz_impl!(@MAKE_VAL $lit, ratio_u64) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ratio_u128) => { $crate::__nz_impl!(@MAKE_VAL $lit, ratio_u128) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, ipv6) => { $crate::__nz_impl!(@MAKE_VAL $lit, ipv6) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, smallest_uint) => { $crate::__nz_impl!(@MAKE_VAL $lit, smallest_uint) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, smallest_int) => { $crate::__nz_impl!(@MAKE_VAL $lit, smallest_int) };
+    (@SATURATE $mode:ident $name:ident $lit:literal, $numeric_type:ident) => {
+        $crate::__fixed_kind! { $numeric_type (@SATURATE_FIXED) (@SIMD_KIND $numeric_type (@SATURATE_SIMD $mode $name $lit,) (@SATURATE_OTHER $mode $lit, $numeric_type)) }
+    };
+    (@SATURATE_FIXED $numeric_type:ident $info:tt) => {
+        ::core::compile_error!("`saturate` is not available for fixed-point variants")
+    };
+    (@SATURATE_SIMD $mode:ident $name:ident $lit:literal, $numeric_type:ident ($lane:ident $lanes:literal)) => {
+        $crate::__simd_impl!(@SPLAT $lanes, $crate::__nz_impl!(@SATURATE $mode $name $lit, $lane))
+    };
+    (@SATURATE_OTHER $mode:ident $lit:literal, $numeric_type:ident) => {{
+        let _ = $crate::__nz_impl!(@ROUND_MODE $mode);
+        $crate::__nz_impl!(@SATURATE_INT $lit, $numeric_type)
+    }};
+    // Clamp an integer literal in `i128`, which holds the range of every variant except the top of `u128`
+    (@SATURATE_INT $lit:literal, $numeric_type:ident) => {{
+        type Variant = $crate::__nz_impl!(@GET_TYPE $numeric_type);
+        let value: ::core::primitive::i128 = $lit;
+        let max = if Variant::MAX as ::core::primitive::u128 > ::core::primitive::i128::MAX as ::core::primitive::u128 {
+            ::core::primitive::i128::MAX
+        } else {
+            Variant::MAX as ::core::primitive::i128
+        };
+        if value > max {
+            Variant::MAX
+        } else if value < Variant::MIN as ::core::primitive::i128 {
+            Variant::MIN
+        } else {
+            value as Variant
+        }
+    }};
+    // Unsigned non-zero variants are clamped to one, only a zero literal being zero
+    (@SATURATE_NZ $lit:literal, $numeric_type:ident) => {{
+        let value = $crate::__nz_impl!(@SATURATE_INT $lit, $numeric_type);
+        let literal: ::core::primitive::i128 = $lit;
+        if value == 0 && literal != 0 { 1 } else { value }
+    }};
+    (@SATURATE_NONMAX $lit:literal, $numeric_type:ident) => {{
+        let value = $crate::__nz_impl!(@SATURATE_INT $lit, $numeric_type);
+        if value == <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::MAX { value - 1 } else { value }
+    }};
+    (@SATURATE_BOUNDED $name:ident $lit:literal, $numeric_type:ident) => {{
+        let value = $crate::__nz_impl!(@SATURATE_INT $lit, $numeric_type);
+        let (min, max) = $name::__BOUNDS;
+        if (value as ::core::primitive::i128) < min {
+            min as $crate::__nz_impl!(@GET_TYPE $numeric_type)
+        } else if (value as ::core::primitive::i128) > max {
+            max as $crate::__nz_impl!(@GET_TYPE $numeric_type)
+        } else {
+            value
+        }
+    }};
+    (@SATURATE_UINT $lit:literal, $numeric_type:ident, $bits:literal) => {{
+        let value = $crate::__nz_impl!(@SATURATE_INT $lit, $numeric_type);
+        let max = <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::MAX >> (<$crate::__nz_impl!(@GET_TYPE $numeric_type)>::BITS - $bits);
+        if value > max { max } else { value }
+    }};
+    (@ROUND nearest $lit:tt, $numeric_type:ident) => { $crate::__nz_impl!(@MAKE_VAL $lit, $numeric_type) };
+    (@ROUND $mode:ident $lit:literal, f32) => {
+        $crate::__round_f32(::core::stringify!($lit), $crate::__nz_impl!(@MAKE_VAL $lit, f32), $crate::__nz_impl!(@ROUND_MODE $mode))
+    };
+    (@ROUND $mode:ident $lit:literal, f64) => {
+        $crate::__round_f64(::core::stringify!($lit), $lit, $crate::__nz_impl!(@ROUND_MODE $mode))
+    };
+    // Computed values are already in `f64`, so only their narrowing to `f32` is rounded
+    (@ROUND $mode:ident (@EXPR $name:ident $init:expr), f32) => {
+        $crate::__round_to_f32($crate::__nz_impl!(@FINITE $name, ($init), f64), $crate::__nz_impl!(@ROUND_MODE $mode))
+    };
+    (@ROUND $mode:ident $lit:tt, real) => { $crate::__real_impl!((@ROUND $mode $lit,)) };
+    (@ROUND $mode:ident $lit:tt, f16) => { $crate::__f16_impl!((@ROUND $mode $lit,)) };
+    (@ROUND $mode:ident $lit:tt, nightly_f16) => { ::core::compile_error!("`f16` variants only support the `nearest` rounding") };
+    (@ROUND $mode:ident $lit:tt, half_f16) => { ::core::compile_error!("`f16` variants only support the `nearest` rounding") };
+    (@ROUND $mode:ident $lit:tt, bf16) => { ::core::compile_error!("`bf16` variants only support the `nearest` rounding") };
+    (@ROUND $mode:ident $lit:tt, f128) => { ::core::compile_error!("`f128` variants only support the `nearest` rounding") };
+    (@ROUND $mode:ident $lit:tt, c_f32) => { $crate::__complex_impl!(@NEW $crate::__nz_impl!(@ROUND $mode $lit, f32)) };
+    (@ROUND $mode:ident $lit:tt, c_f64) => { $crate::__complex_impl!(@NEW $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@ROUND $mode:ident $lit:tt, of_f32) => { $crate::__ordered_impl!(@ORDERED $crate::__nz_impl!(@ROUND $mode $lit, f32)) };
+    (@ROUND $mode:ident $lit:tt, of_f64) => { $crate::__ordered_impl!(@ORDERED $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@ROUND $mode:ident $lit:tt, nn_f32) => { $crate::__ordered_impl!(@NOT_NAN f32 $crate::__nz_impl!(@ROUND $mode $lit, f32)) };
+    (@ROUND $mode:ident $lit:tt, nn_f64) => { $crate::__ordered_impl!(@NOT_NAN f64 $crate::__nz_impl!(@ROUND $mode $lit, f64)) };
+    (@ROUND $mode:ident $lit:tt, $numeric_type:ident) => {
+        $crate::__fixed_kind! { $numeric_type (@ROUND_FIXED) (@SIMD_KIND $numeric_type (@ROUND_SIMD $mode $lit,) (@ROUND_OTHER $mode $lit, $numeric_type)) }
+    };
+    (@ROUND_FIXED $numeric_type:ident $info:tt) => {
+        ::core::compile_error!("fixed-point variants only support the `nearest` rounding")
+    };
+    (@ROUND_SIMD $mode:ident $lit:tt, $numeric_type:ident ($lane:ident $lanes:literal)) => {
+        $crate::__simd_impl!(@SPLAT $lanes, $crate::__nz_impl!(@ROUND $mode $lit, $lane))
+    };
+    (@ROUND_OTHER $mode:ident $lit:tt, $numeric_type:ident) => {{
+        let _ = $crate::__nz_impl!(@ROUND_MODE $mode);
+        $crate::__nz_impl!(@MAKE_VAL $lit, $numeric_type)
+    }};
+    (@ROUND_MODE nearest) => { 0 };
+    (@ROUND_MODE up) => { 1 };
+    (@ROUND_MODE down) => { 2 };
+    (@ROUND_MODE toward_zero) => { 3 };
+    (@ROUND_MODE $mode:ident) => {
+        ::core::compile_error!(::core::concat!("unknown rounding `", ::core::stringify!($mode), "`, expected `nearest`, `up`, `down` or `toward_zero`"))
+    };
+    // Optional non-zero variants always hold their non-zero variant outside of a constant
+    (@MAKE_VAL $lit:tt, opt_nz_i8   ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_i8   )) };
+    (@MAKE_VAL $lit:tt, opt_nz_i16  ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_i16  )) };
+    (@MAKE_VAL $lit:tt, opt_nz_i32  ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_i32  )) };
+    (@MAKE_VAL $lit:tt, opt_nz_i64  ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_i64  )) };
+    (@MAKE_VAL $lit:tt, opt_nz_i128 ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_i128 )) };
+    (@MAKE_VAL $lit:tt, opt_nz_isize) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_isize)) };
+    (@MAKE_VAL $lit:tt, opt_nz_u8   ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_u8   )) };
+    (@MAKE_VAL $lit:tt, opt_nz_u16  ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_u16  )) };
+    (@MAKE_VAL $lit:tt, opt_nz_u32  ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_u32  )) };
+    (@MAKE_VAL $lit:tt, opt_nz_u64  ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_u64  )) };
+    (@MAKE_VAL $lit:tt, opt_nz_u128 ) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_u128 )) };
+    (@MAKE_VAL $lit:tt, opt_nz_usize) => { ::core::option::Option::Some($crate::__nz_impl!(@MAKE_VAL $lit, nz_usize)) };
+    // Complex variants wrap their float variant, from a literal or a computed value
+    (@MAKE_VAL $lit:tt, c_f32) => { $crate::__complex_impl!(@NEW $crate::__nz_impl!(@MAKE_VAL $lit, f32)) };
+    (@MAKE_VAL $lit:tt, c_f64) => { $crate::__complex_impl!(@NEW $crate::__nz_impl!(@MAKE_VAL $lit, f64)) };
+    // Ordered variants wrap their float variant, from a literal or a computed value
+    (@MAKE_VAL $lit:tt, of_f32) => { $crate::__ordered_impl!(@ORDERED $crate::__nz_impl!(@MAKE_VAL $lit, f32)) };
+    (@MAKE_VAL $lit:tt, of_f64) => { $crate::__ordered_impl!(@ORDERED $crate::__nz_impl!(@MAKE_VAL $lit, f64)) };
+    (@MAKE_VAL $lit:tt, nn_f32) => { $crate::__ordered_impl!(@NOT_NAN f32 $crate::__nz_impl!(@MAKE_VAL $lit, f32)) };
+    (@MAKE_VAL $lit:tt, nn_f64) => { $crate::__ordered_impl!(@NOT_NAN f64 $crate::__nz_impl!(@MAKE_VAL $lit, f64)) };
+    // The quotient of `ratio_of` is rounded once to the precision of each float variant
+    (@MAKE_VAL (@EXPR $name:ident $krate:tt :: __nz_impl!(@RATIO $ratio:ident $first:ident $numerator:ident $denominator:ident)), f32) => {
+        $crate::__nz_impl!(@FINITE $name, ($crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 24, -126)), f32)
+    };
+    (@MAKE_VAL (@EXPR $name:ident $krate:tt :: __nz_impl!(@RATIO $ratio:ident $first:ident $numerator:ident $denominator:ident)), f64) => {
+        $crate::__nz_impl!(@FINITE $name, ($crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022)), f64)
+    };
+    (@MAKE_VAL (@EXPR $name:ident $krate:tt :: __nz_impl!(@RATIO $ratio:ident $first:ident $numerator:ident $denominator:ident)), nightly_f16) => {
+        $crate::__nz_impl!(@FINITE $name, ($crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 11, -14)), f16)
+    };
+    (@MAKE_VAL (@EXPR $name:ident $krate:tt :: __nz_impl!(@RATIO $ratio:ident $first:ident $numerator:ident $denominator:ident)), half_f16) => {
+        $crate::__nz_impl!(@HALF ::core::stringify!($name), ($crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 11, -14)), f16)
+    };
+    (@MAKE_VAL (@EXPR $name:ident $krate:tt :: __nz_impl!(@RATIO $ratio:ident $first:ident $numerator:ident $denominator:ident)), bf16) => {
+        $crate::__nz_impl!(@HALF ::core::stringify!($name), ($crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 8, -126)), bf16)
+    };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), f32) => { $crate::__nz_impl!(@FINITE $name, ($init), f32) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), f64) => { $crate::__nz_impl!(@FINITE $name, ($init), f64) };
+    // Atomic variants are built from the checked value of the integer they hold
+    (@MAKE_VAL $init:tt, atomic_bool ) => { ::core::sync::atomic::AtomicBool::new($crate::__nz_impl!(@MAKE_VAL $init, bool)) };
+    (@MAKE_VAL $init:tt, atomic_i8   ) => { ::core::sync::atomic::AtomicI8::new($crate::__nz_impl!(@MAKE_VAL $init, i8)) };
+    (@MAKE_VAL $init:tt, atomic_i16  ) => { ::core::sync::atomic::AtomicI16::new($crate::__nz_impl!(@MAKE_VAL $init, i16)) };
+    (@MAKE_VAL $init:tt, atomic_i32  ) => { ::core::sync::atomic::AtomicI32::new($crate::__nz_impl!(@MAKE_VAL $init, i32)) };
+    (@MAKE_VAL $init:tt, atomic_i64  ) => { ::core::sync::atomic::AtomicI64::new($crate::__nz_impl!(@MAKE_VAL $init, i64)) };
+    (@MAKE_VAL $init:tt, atomic_isize) => { ::core::sync::atomic::AtomicIsize::new($crate::__nz_impl!(@MAKE_VAL $init, isize)) };
+    (@MAKE_VAL $init:tt, atomic_u8   ) => { ::core::sync::atomic::AtomicU8::new($crate::__nz_impl!(@MAKE_VAL $init, u8)) };
+    (@MAKE_VAL $init:tt, atomic_u16  ) => { ::core::sync::atomic::AtomicU16::new($crate::__nz_impl!(@MAKE_VAL $init, u16)) };
+    (@MAKE_VAL $init:tt, atomic_u32  ) => { ::core::sync::atomic::AtomicU32::new($crate::__nz_impl!(@MAKE_VAL $init, u32)) };
+    (@MAKE_VAL $init:tt, atomic_u64  ) => { ::core::sync::atomic::AtomicU64::new($crate::__nz_impl!(@MAKE_VAL $init, u64)) };
+    (@MAKE_VAL $init:tt, atomic_usize) => { ::core::sync::atomic::AtomicUsize::new($crate::__nz_impl!(@MAKE_VAL $init, usize)) };
+    (@MAKE_VAL $init:tt, f16) => { $crate::__f16_impl!((@MAKE_VAL $init,)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nightly_f16) => { $crate::__nz_impl!(@FINITE $name, ($init), f16) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), half_f16) => { $crate::__nz_impl!(@HALF ::core::stringify!($name), ($init), f16) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bf16) => { $crate::__nz_impl!(@HALF ::core::stringify!($name), ($init), bf16) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), f128) => { $crate::__nz_impl!(@FINITE $name, ($init), f128) };
+    // The float type of `real` variants is selected by a feature of this crate
+    (@MAKE_VAL $lit:tt, real) => { $crate::__real_impl!((@MAKE_VAL $lit,)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_i8   ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i8   , $crate::__nz_impl!(@CHECKED $name, ($init), i8   )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_i16  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i16  , $crate::__nz_impl!(@CHECKED $name, ($init), i16  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_i32  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i32  , $crate::__nz_impl!(@CHECKED $name, ($init), i32  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_i64  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i64  , $crate::__nz_impl!(@CHECKED $name, ($init), i64  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_i128 ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_i128 , $crate::__nz_impl!(@CHECKED $name, ($init), i128 )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_isize) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_isize, $crate::__nz_impl!(@CHECKED $name, ($init), isize)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_u8   ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u8   , $crate::__nz_impl!(@CHECKED $name, ($init), u8   )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_u16  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u16  , $crate::__nz_impl!(@CHECKED $name, ($init), u16  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_u32  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u32  , $crate::__nz_impl!(@CHECKED $name, ($init), u32  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_u64  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u64  , $crate::__nz_impl!(@CHECKED $name, ($init), u64  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_u128 ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_u128 , $crate::__nz_impl!(@CHECKED $name, ($init), u128 )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nz_usize) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($name), nz_usize, $crate::__nz_impl!(@CHECKED $name, ($init), usize)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_i8   ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_i8   , $crate::__nz_impl!(@CHECKED $name, ($init), i8   )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_i16  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_i16  , $crate::__nz_impl!(@CHECKED $name, ($init), i16  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_i32  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_i32  , $crate::__nz_impl!(@CHECKED $name, ($init), i32  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_i64  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_i64  , $crate::__nz_impl!(@CHECKED $name, ($init), i64  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_i128 ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_i128 , $crate::__nz_impl!(@CHECKED $name, ($init), i128 )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_isize) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_isize, $crate::__nz_impl!(@CHECKED $name, ($init), isize)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_u8   ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_u8   , $crate::__nz_impl!(@CHECKED $name, ($init), u8   )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_u16  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_u16  , $crate::__nz_impl!(@CHECKED $name, ($init), u16  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_u32  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_u32  , $crate::__nz_impl!(@CHECKED $name, ($init), u32  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_u64  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_u64  , $crate::__nz_impl!(@CHECKED $name, ($init), u64  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_u128 ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_u128 , $crate::__nz_impl!(@CHECKED $name, ($init), u128 )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), nonmax_usize) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($name), nonmax_usize, $crate::__nz_impl!(@CHECKED $name, ($init), usize)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_i8   ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_i8   , $crate::__nz_impl!(@CHECKED $name, ($init), i8   )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_i16  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_i16  , $crate::__nz_impl!(@CHECKED $name, ($init), i16  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_i32  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_i32  , $crate::__nz_impl!(@CHECKED $name, ($init), i32  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_i64  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_i64  , $crate::__nz_impl!(@CHECKED $name, ($init), i64  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_i128 ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_i128 , $crate::__nz_impl!(@CHECKED $name, ($init), i128 )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_isize) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_isize, $crate::__nz_impl!(@CHECKED $name, ($init), isize)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_u8   ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_u8   , $crate::__nz_impl!(@CHECKED $name, ($init), u8   )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_u16  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_u16  , $crate::__nz_impl!(@CHECKED $name, ($init), u16  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_u32  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_u32  , $crate::__nz_impl!(@CHECKED $name, ($init), u32  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_u64  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_u64  , $crate::__nz_impl!(@CHECKED $name, ($init), u64  )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_u128 ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_u128 , $crate::__nz_impl!(@CHECKED $name, ($init), u128 )) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bounded_usize) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($name), bounded_usize, $crate::__nz_impl!(@CHECKED $name, ($init), usize)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), u24) => { $crate::__nz_impl!(@UINT ::core::stringify!($name), u24, 24, $crate::__nz_impl!(@CHECKED $name, ($init), u32)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), u40) => { $crate::__nz_impl!(@UINT ::core::stringify!($name), u40, 40, $crate::__nz_impl!(@CHECKED $name, ($init), u64)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), u48) => { $crate::__nz_impl!(@UINT ::core::stringify!($name), u48, 48, $crate::__nz_impl!(@CHECKED $name, ($init), u64)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), u56) => { $crate::__nz_impl!(@UINT ::core::stringify!($name), u56, 56, $crate::__nz_impl!(@CHECKED $name, ($init), u64)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bool) => {
+        $crate::__nz_impl!(@BOOL ::core::stringify!($name), $crate::__nz_impl!(@CHECKED $name, ($init), i128))
+    };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), char) => {
+        $crate::__nz_impl!(@CHAR ::core::stringify!($name), $crate::__nz_impl!(@CHECKED $name, ($init), u32))
+    };
+    // Addresses are built from their bits, which must fit in the matching unsigned integer
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), duration) => { $crate::__nz_impl!(@DURATION $name, $crate::__nz_impl!(@CHECKED $name, ($init), u64)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), decimal) => { $crate::__nz_impl!(@DECIMAL_COMPUTED $name, ($init)) };
+    // Computed ratios must be integers, like the computed values of `decimal` variants
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ratio_i32) => { $crate::__rational_impl!(@NEW $crate::__nz_impl!(@CHECKED $name, ($init), i32), 1) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ratio_i64) => { $crate::__rational_impl!(@NEW $crate::__nz_impl!(@CHECKED $name, ($init), i64), 1) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ratio_i128) => { $crate::__rational_impl!(@NEW $crate::__nz_impl!(@CHECKED $name, ($init), i128), 1) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ratio_u32) => { $crate::__rational_impl!(@NEW $crate::__nz_impl!(@CHECKED $name, ($init), u32), 1) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ratio_u64) => { $crate::__rational_impl!(@NEW $crate::__nz_impl!(@CHECKED $name, ($init), u64), 1) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ratio_u128) => { $crate::__rational_impl!(@NEW $crate::__nz_impl!(@CHECKED $name, ($init), u128), 1) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ipv4) => { ::core::net::Ipv4Addr::from_bits($crate::__nz_impl!(@CHECKED $name, ($init), u32)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ipv6) => { ::core::net::Ipv6Addr::from_bits($crate::__nz_impl!(@CHECKED $name, ($init), u128)) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), bigint) => {
+        ::core::compile_error!("`bigint` variants require a literal initializer")
+    };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), limbs) => { $crate::__limbs_of($crate::__nz_impl!(@CHECKED $name, ($init), u128)) };
+    // Computed 256 bits values are limited to `u128` and `i128`, like limbs
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), u256) => { $crate::__bnum_impl!(@UNSIGNED $crate::__limbs_of($crate::__nz_impl!(@CHECKED $name, ($init), u128))) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), i256) => { $crate::__bnum_impl!(@SIGNED $crate::__digits_of_i128($crate::__nz_impl!(@CHECKED $name, ($init), i128))) };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), le_bytes) => { ::core::compile_error!("`le_bytes` variants are only available on constants") };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), be_bytes) => { ::core::compile_error!("`be_bytes` variants are only available on constants") };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), ne_bytes) => { ::core::compile_error!("`ne_bytes` variants are only available on constants") };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), biguint) => {
+        ::core::compile_error!("`biguint` variants require a literal initializer")
+    };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), str) => {
+        ::core::compile_error!("`str` variants require a literal initializer")
+    };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), smallest_uint) => { ($init) as _ };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), smallest_int ) => { ($init) as _ };
+    (@MAKE_VAL (@EXPR $name:ident $init:expr), $numeric_type:ident) => {
+        $crate::__fixed_kind! { $numeric_type (@FIXED_CHECKED $name, ($init),) (@SIMD_KIND $numeric_type (@MAKE_SIMD (@EXPR $name $init),) (@CHECKED $name, ($init), $numeric_type)) }
+    };
+    // Compare the sign and magnitude of the computed value with the range of the variant, whatever its type
+    (@CHECKED $name:ident, $value:expr, $numeric_type:ident) => {{
+        let value = $value;
+        let zero = if true { 0 as _ } else { value };
+        let negative = value < zero;
+        let magnitude = if negative { (value as ::core::primitive::i128).unsigned_abs() } else { value as ::core::primitive::u128 };
+        let narrowed = value as $crate::__nz_impl!(@GET_TYPE $numeric_type);
+        // Cast back to the type of the computed value, so that floats must also be integral
+        let back = if true { narrowed as _ } else { value };
+        if !$crate::__fits(negative, magnitude, $crate::__nz_impl!(@RANGE $numeric_type)) || back != value {
+            // The integral part being exact in `f64` for floats, and equal to the value for integers, leaves the fraction
+            let whole = magnitude as ::core::primitive::f64;
+            let fraction = if negative { value as ::core::primitive::f64 + whole } else { value as ::core::primitive::f64 - whole };
+            $crate::__does_not_fit(::core::stringify!($name), ::core::stringify!($numeric_type), negative, magnitude, fraction, $crate::__nz_impl!(@RANGE $numeric_type));
+        }
+        narrowed
+    }};
+    // Read an integer literal into a variant, from its text, rather than relying on the `overflowing_literals` lint
+    (@LITERAL $lit:literal, $numeric_type:ident) => {
+        match $crate::__int_value(::core::stringify!($lit)) {
+            (negative, ::core::option::Option::Some(magnitude)) if $crate::__fits(negative, magnitude, $crate::__nz_impl!(@RANGE $numeric_type)) => {
+                if negative {
+                    (magnitude as ::core::primitive::i128).wrapping_neg() as $crate::__nz_impl!(@GET_TYPE $numeric_type)
+                } else {
+                    magnitude as $crate::__nz_impl!(@GET_TYPE $numeric_type)
+                }
+            }
+            (negative, ::core::option::Option::Some(magnitude)) => {
+                $crate::__does_not_fit(::core::stringify!($lit), ::core::stringify!($numeric_type), negative, magnitude, 0.0, $crate::__nz_impl!(@RANGE $numeric_type))
+            }
+            // The magnitude is beyond `u128`, the excess is added to `u128::MAX`
+            (negative, ::core::option::Option::None) => $crate::__does_not_fit(
+                ::core::stringify!($lit),
+                ::core::stringify!($numeric_type),
+                negative,
+                ::core::primitive::u128::MAX,
+                $crate::__int_excess(::core::stringify!($lit)),
+                $crate::__nz_impl!(@RANGE $numeric_type),
+            ),
+        }
+    };
+    // The magnitudes of the minimum and maximum of an integer type
+    (@RANGE $numeric_type:ident) => {
+        (
+            (<$crate::__nz_impl!(@GET_TYPE $numeric_type)>::MIN as ::core::primitive::i128).unsigned_abs(),
+            <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::MAX as ::core::primitive::u128,
+        )
+    };
+    // Fixed-point variants are built from their bits, the literal being scaled exactly from its text,
+    // and computed values in `f64`, both rounded to the nearest
+    (@FIXED_LITERAL $lit:literal, $numeric_type:ident ($fixed:ident $bits:ident $frac:literal)) => {
+        match $crate::__fixed_literal(::core::stringify!($lit), $frac) {
+            (negative, ::core::option::Option::Some(magnitude)) if $crate::__fits(negative, magnitude, $crate::__nz_impl!(@RANGE $bits)) => {
+                <$crate::__fixed_impl!(@TYPE $fixed)>::from_bits(if negative {
+                    (magnitude as ::core::primitive::i128).wrapping_neg() as ::core::primitive::$bits
+                } else {
+                    magnitude as ::core::primitive::$bits
+                })
+            }
+            _ => ::core::panic!(::core::concat!("`", ::core::stringify!($lit), "` does not fit in `", ::core::stringify!($numeric_type), "`")),
+        }
+    };
+    (@FIXED_CHECKED $name:ident, $value:expr, $numeric_type:ident ($fixed:ident $bits:ident $frac:literal)) => {{
+        let scaled = $value as ::core::primitive::f64 * (1u64 << $frac) as ::core::primitive::f64;
+        let rounded = if scaled < 0.0 { scaled - 0.5 } else { scaled + 0.5 };
+        let bits = rounded as ::core::primitive::i128;
+        if rounded != rounded || !$crate::__fits(bits < 0, bits.unsigned_abs(), $crate::__nz_impl!(@RANGE $bits)) {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` does not fit in `", ::core::stringify!($numeric_type), "`"));
+        }
+        <$crate::__fixed_impl!(@TYPE $fixed)>::from_bits(bits as ::core::primitive::$bits)
+    }};
+    // Computed `decimal` values must be integers, which are exact in any scale
+    (@DECIMAL_COMPUTED $name:ident, $value:expr) => {{
+        let value = $value;
+        let zero = if true { 0 as _ } else { value };
+        let negative = value < zero;
+        let magnitude = if negative { (value as ::core::primitive::i128).unsigned_abs() } else { value as ::core::primitive::u128 };
+        let back = if true { (value as ::core::primitive::i128) as _ } else { value };
+        if magnitude >> 96 != 0 || back != value {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` does not fit in `decimal`"));
+        }
+        $crate::__decimal_impl!(@PARTS negative, magnitude, 0)
+    }};
+    (@NZ_COMPUTED $what:expr, $numeric_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!(::core::concat!("`", $what, "` is zero in `", ::core::stringify!($numeric_type), "`")),
+        }
+    };
+    // Zero is only held as `None` by the constants with `#[zero_is_none]`
+    (@OPT_NZ $name:ident, $numeric_type:ident, $nz_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $nz_type)>::new($value) {
+            ::core::option::Option::Some(value) => ::core::option::Option::Some(value),
+            ::core::option::Option::None if $name::__ZERO_IS_NONE => ::core::option::Option::None,
+            ::core::option::Option::None => ::core::panic!(::core::concat!(
+                "`", ::core::stringify!($name), "` is zero in `", ::core::stringify!($numeric_type), "`, which requires `#[zero_is_none]`"
+            )),
+        }
+    };
+    // Convert the value from the unit of the constant to the base unit held by `uom` quantities
+    (@UOM $name:ident, $quantity:ident, $type:ident, $value:expr) => {{
+        let (numerator, denominator) = $crate::__uom_factor(::core::stringify!($quantity), $name::__UOM_UNIT);
+        $crate::__uom_impl!(@NEW $type, $value * numerator / denominator)
+    }};
+
+    // Fails to compile if the value is the maximum of the type, which `nonmax` types reserve
+    (@NONMAX $what:expr, $numeric_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!(::core::concat!("`", $what, "` is the maximum of `", ::core::stringify!($numeric_type), "`")),
+        }
+    };
+
+    // Fails to compile if the value overflows to infinity in the `half` type
+    (@HALF $what:expr, $value:expr, $half:ident) => {{
+        let value = $value as ::core::primitive::f64;
+        let half = <$crate::__nz_impl!(@GET_TYPE $half)>::from_f64_const(value);
+        if half.to_f64_const().is_infinite() && !value.is_infinite() {
+            ::core::panic!(::core::concat!("`", $what, "` overflows to infinity in `", ::core::stringify!($half), "`"));
+        }
+        half
+    }};
+
+    // Fails to compile unless the value is 0 or 1, which `bool` variants hold as `false` and `true`
+    (@BOOL $what:expr, $value:expr) => {
+        match $value {
+            0 => false,
+            1 => true,
+            _ => ::core::panic!(::core::concat!("`", $what, "` is neither 0 nor 1, and does not fit in `bool`")),
+        }
+    };
+
+    // Fails to compile unless the value is a Unicode scalar value, which `char` variants hold as a character
+    (@CHAR $what:expr, $value:expr) => {
+        match ::core::primitive::char::from_u32($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!(::core::concat!("`", $what, "` is not a valid `char`")),
+        }
+    };
+
+    // Fails to compile if the value is outside of the bounds given by `#[bounds(...)]`
+    (@BOUNDED $what:expr, $numeric_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!(::core::concat!("`", $what, "` is out of the bounds of `", ::core::stringify!($numeric_type), "`")),
+        }
+    };
+    // Fails to compile if the value does not fit in the bits of the `arbitrary-int` type, whose constructor would panic anyway
+    (@UINT $what:expr, $numeric_type:ident, $bits:literal, $value:expr) => {{
+        let value = $value;
+        if value >> $bits != 0 {
+            ::core::panic!(::core::concat!("`", $what, "` does not fit in `", ::core::stringify!($numeric_type), "`"));
+        }
+        <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new(value)
+    }};
+
+    // Fails to compile if the literal is zero or out of range, without `unsafe`
+    (@MAKE_VAL $lit:literal, nz_i8   ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_i8   , $crate::__nz_impl!(@LITERAL $lit, i8   )) };
+    (@MAKE_VAL $lit:literal, nz_i16  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_i16  , $crate::__nz_impl!(@LITERAL $lit, i16  )) };
+    (@MAKE_VAL $lit:literal, nz_i32  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_i32  , $crate::__nz_impl!(@LITERAL $lit, i32  )) };
+    (@MAKE_VAL $lit:literal, nz_i64  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_i64  , $crate::__nz_impl!(@LITERAL $lit, i64  )) };
+    (@MAKE_VAL $lit:literal, nz_i128 ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_i128 , $crate::__nz_impl!(@LITERAL $lit, i128 )) };
+    (@MAKE_VAL $lit:literal, nz_isize) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_isize, $crate::__nz_impl!(@LITERAL $lit, isize)) };
+    (@MAKE_VAL $lit:literal, nz_u8   ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_u8   , $crate::__nz_impl!(@LITERAL $lit, u8   )) };
+    (@MAKE_VAL $lit:literal, nz_u16  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_u16  , $crate::__nz_impl!(@LITERAL $lit, u16  )) };
+    (@MAKE_VAL $lit:literal, nz_u32  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_u32  , $crate::__nz_impl!(@LITERAL $lit, u32  )) };
+    (@MAKE_VAL $lit:literal, nz_u64  ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_u64  , $crate::__nz_impl!(@LITERAL $lit, u64  )) };
+    (@MAKE_VAL $lit:literal, nz_u128 ) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_u128 , $crate::__nz_impl!(@LITERAL $lit, u128 )) };
+    (@MAKE_VAL $lit:literal, nz_usize) => { $crate::__nz_impl!(@NZ_COMPUTED ::core::stringify!($lit), nz_usize, $crate::__nz_impl!(@LITERAL $lit, usize)) };
+    (@MAKE_VAL $lit:literal, nonmax_i8   ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_i8   , $crate::__nz_impl!(@LITERAL $lit, i8   )) };
+    (@MAKE_VAL $lit:literal, nonmax_i16  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_i16  , $crate::__nz_impl!(@LITERAL $lit, i16  )) };
+    (@MAKE_VAL $lit:literal, nonmax_i32  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_i32  , $crate::__nz_impl!(@LITERAL $lit, i32  )) };
+    (@MAKE_VAL $lit:literal, nonmax_i64  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_i64  , $crate::__nz_impl!(@LITERAL $lit, i64  )) };
+    (@MAKE_VAL $lit:literal, nonmax_i128 ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_i128 , $crate::__nz_impl!(@LITERAL $lit, i128 )) };
+    (@MAKE_VAL $lit:literal, nonmax_isize) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_isize, $crate::__nz_impl!(@LITERAL $lit, isize)) };
+    (@MAKE_VAL $lit:literal, nonmax_u8   ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_u8   , $crate::__nz_impl!(@LITERAL $lit, u8   )) };
+    (@MAKE_VAL $lit:literal, nonmax_u16  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_u16  , $crate::__nz_impl!(@LITERAL $lit, u16  )) };
+    (@MAKE_VAL $lit:literal, nonmax_u32  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_u32  , $crate::__nz_impl!(@LITERAL $lit, u32  )) };
+    (@MAKE_VAL $lit:literal, nonmax_u64  ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_u64  , $crate::__nz_impl!(@LITERAL $lit, u64  )) };
+    (@MAKE_VAL $lit:literal, nonmax_u128 ) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_u128 , $crate::__nz_impl!(@LITERAL $lit, u128 )) };
+    (@MAKE_VAL $lit:literal, nonmax_usize) => { $crate::__nz_impl!(@NONMAX ::core::stringify!($lit), nonmax_usize, $crate::__nz_impl!(@LITERAL $lit, usize)) };
+    (@MAKE_VAL $lit:literal, bounded_i8   ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_i8   , $crate::__nz_impl!(@LITERAL $lit, i8   )) };
+    (@MAKE_VAL $lit:literal, bounded_i16  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_i16  , $crate::__nz_impl!(@LITERAL $lit, i16  )) };
+    (@MAKE_VAL $lit:literal, bounded_i32  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_i32  , $crate::__nz_impl!(@LITERAL $lit, i32  )) };
+    (@MAKE_VAL $lit:literal, bounded_i64  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_i64  , $crate::__nz_impl!(@LITERAL $lit, i64  )) };
+    (@MAKE_VAL $lit:literal, bounded_i128 ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_i128 , $crate::__nz_impl!(@LITERAL $lit, i128 )) };
+    (@MAKE_VAL $lit:literal, bounded_isize) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_isize, $crate::__nz_impl!(@LITERAL $lit, isize)) };
+    (@MAKE_VAL $lit:literal, bounded_u8   ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_u8   , $crate::__nz_impl!(@LITERAL $lit, u8   )) };
+    (@MAKE_VAL $lit:literal, bounded_u16  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_u16  , $crate::__nz_impl!(@LITERAL $lit, u16  )) };
+    (@MAKE_VAL $lit:literal, bounded_u32  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_u32  , $crate::__nz_impl!(@LITERAL $lit, u32  )) };
+    (@MAKE_VAL $lit:literal, bounded_u64  ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_u64  , $crate::__nz_impl!(@LITERAL $lit, u64  )) };
+    (@MAKE_VAL $lit:literal, bounded_u128 ) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_u128 , $crate::__nz_impl!(@LITERAL $lit, u128 )) };
+    (@MAKE_VAL $lit:literal, bounded_usize) => { $crate::__nz_impl!(@BOUNDED ::core::stringify!($lit), bounded_usize, $crate::__nz_impl!(@LITERAL $lit, usize)) };
+    (@MAKE_VAL $lit:literal, u24) => { $crate::__nz_impl!(@UINT ::core::stringify!($lit), u24, 24, $crate::__nz_impl!(@LITERAL $lit, u32)) };
+    (@MAKE_VAL $lit:literal, u40) => { $crate::__nz_impl!(@UINT ::core::stringify!($lit), u40, 40, $crate::__nz_impl!(@LITERAL $lit, u64)) };
+    (@MAKE_VAL $lit:literal, u48) => { $crate::__nz_impl!(@UINT ::core::stringify!($lit), u48, 48, $crate::__nz_impl!(@LITERAL $lit, u64)) };
+    (@MAKE_VAL $lit:literal, u56) => { $crate::__nz_impl!(@UINT ::core::stringify!($lit), u56, 56, $crate::__nz_impl!(@LITERAL $lit, u64)) };
+    // Big integers are parsed from the text of the literal, which may not fit in any primitive
+    (@MAKE_VAL $lit:literal, bool) => { $crate::__nz_impl!(@BOOL ::core::stringify!($lit), $crate::__nz_impl!(@LITERAL $lit, i128)) };
+    (@MAKE_VAL $lit:literal, char) => { $crate::__nz_impl!(@CHAR ::core::stringify!($lit), $crate::__nz_impl!(@LITERAL $lit, u32)) };
+    (@MAKE_VAL $lit:tt, uom_length      ) => { ::core::compile_error!("`uom_length` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, uom_mass        ) => { ::core::compile_error!("`uom_mass` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, uom_time        ) => { ::core::compile_error!("`uom_time` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, uom_velocity    ) => { ::core::compile_error!("`uom_velocity` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, uom_acceleration) => { ::core::compile_error!("`uom_acceleration` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, uom_frequency   ) => { ::core::compile_error!("`uom_frequency` variants are only available on constants") };
+    (@MAKE_VAL $lit:literal, duration) => { ::core::compile_error!("`duration` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, ptr_u8   ) => { ::core::compile_error!("`ptr_u8` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, ptr_u16  ) => { ::core::compile_error!("`ptr_u16` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, ptr_u32  ) => { ::core::compile_error!("`ptr_u32` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, ptr_u64  ) => { ::core::compile_error!("`ptr_u64` variants are only available on constants") };
+    (@MAKE_VAL $lit:tt, ptr_usize) => { ::core::compile_error!("`ptr_usize` variants are only available on constants") };
+    (@MAKE_VAL $lit:literal, decimal) => {
+        match $crate::__decimal_literal(::core::stringify!($lit)) {
+            (negative, ::core::option::Option::Some((mantissa, scale))) => $crate::__decimal_impl!(@PARTS negative, mantissa, scale),
+            _ => ::core::panic!(::core::concat!("`", ::core::stringify!($lit), "` does not fit in `decimal`")),
+        }
+    };
+    (@MAKE_VAL $lit:literal, ratio_i32 ) => { $crate::__nz_impl!(@RATIO_LITERAL $lit, ratio_i32  i32) };
+    (@MAKE_VAL $lit:literal, ratio_i64 ) => { $crate::__nz_impl!(@RATIO_LITERAL $lit, ratio_i64  i64) };
+    (@MAKE_VAL $lit:literal, ratio_i128) => { $crate::__nz_impl!(@RATIO_LITERAL $lit, ratio_i128 i128) };
+    (@MAKE_VAL $lit:literal, ratio_u32 ) => { $crate::__nz_impl!(@RATIO_LITERAL $lit, ratio_u32  u32) };
+    (@MAKE_VAL $lit:literal, ratio_u64 ) => { $crate::__nz_impl!(@RATIO_LITERAL $lit, ratio_u64  u64) };
+    (@MAKE_VAL $lit:literal, ratio_u128) => { $crate::__nz_impl!(@RATIO_LITERAL $lit, ratio_u128 u128) };
+    // The reduced numerator and denominator must both fit in the integer type
+    (@RATIO_LITERAL $lit:literal, $numeric_type:ident $int:ident) => {
+        match $crate::__ratio_literal(::core::stringify!($lit)) {
+            (negative, ::core::option::Option::Some((numerator, denominator)))
+                if $crate::__fits(negative, numerator, $crate::__nz_impl!(@RANGE $int))
+                    && $crate::__fits(false, denominator, $crate::__nz_impl!(@RANGE $int)) =>
+            {
+                let numerator = if negative { (numerator as ::core::primitive::$int).wrapping_neg() } else { numerator as ::core::primitive::$int };
+                $crate::__rational_impl!(@NEW numerator, denominator as ::core::primitive::$int)
+            }
+            _ => ::core::panic!(::core::concat!("`", ::core::stringify!($lit), "` does not fit in `", ::core::stringify!($numeric_type), "`")),
+        }
+    };
+    (@MAKE_VAL $lit:literal, ipv4) => { ::core::net::Ipv4Addr::from_bits($crate::__nz_impl!(@LITERAL $lit, u32)) };
+    (@MAKE_VAL $lit:literal, ipv6) => { ::core::net::Ipv6Addr::from_bits($crate::__nz_impl!(@LITERAL $lit, u128)) };
+    (@MAKE_VAL $lit:literal, bigint ) => { $crate::__bigint_impl!(@VALUE BigInt $lit) };
+    (@MAKE_VAL $lit:literal, biguint) => { $crate::__bigint_impl!(@VALUE BigUint $lit) };
+    (@MAKE_VAL $lit:literal, limbs) => { $crate::__limbs(::core::stringify!($lit)) };
+    // The text of the literal, which the other variants read as a number
+    (@MAKE_VAL $lit:literal, str) => { ::core::stringify!($lit) };
+    (@MAKE_VAL $lit:literal, u256) => { $crate::__bnum_impl!(@UNSIGNED $crate::__digits_256(::core::stringify!($lit), false)) };
+    (@MAKE_VAL $lit:literal, i256) => { $crate::__bnum_impl!(@SIGNED $crate::__digits_256(::core::stringify!($lit), true)) };
+    (@MAKE_VAL $lit:literal, le_bytes) => { ::core::compile_error!("`le_bytes` variants are only available on constants") };
+    (@MAKE_VAL $lit:literal, be_bytes) => { ::core::compile_error!("`be_bytes` variants are only available on constants") };
+    (@MAKE_VAL $lit:literal, ne_bytes) => { ::core::compile_error!("`ne_bytes` variants are only available on constants") };
+    // The narrowing to f32 is intended, only the widest float should be checked for precision
+    (@MAKE_VAL $lit:literal, f32) => {{
+        #[allow(clippy::excessive_precision)]
+        let value: ::core::primitive::f32 = $lit;
+        value
+    }};
+    // The unstable float types are only named with the `nightly` feature
+    (@MAKE_VAL $lit:literal, nightly_f16) => {{
+        #[allow(clippy::excessive_precision)]
+        let value: $crate::__nightly_impl!(@TYPE f16) = $lit;
+        value
+    }};
+    (@MAKE_VAL $lit:literal, f128) => {{
+        let value: $crate::__nightly_impl!(@TYPE f128) = $lit;
+        value
+    }};
+    (@MAKE_VAL $lit:literal, half_f16) => { $crate::__nz_impl!(@HALF ::core::stringify!($lit), $lit, f16) };
+    (@MAKE_VAL $lit:literal, bf16) => { $crate::__nz_impl!(@HALF ::core::stringify!($lit), $lit, bf16) };
+    (@MAKE_VAL $lit:literal, f64) => { $lit };
+    // The type of the smallest variants is picked from the literal, which always fits
+    (@MAKE_VAL $lit:literal, smallest_uint) => { $lit };
+    (@MAKE_VAL $lit:literal, smallest_int) => { $lit };
+    (@MAKE_VAL $lit:literal, $numeric_type:ident) => {
+        $crate::__fixed_kind! { $numeric_type (@FIXED_LITERAL $lit,) (@SIMD_KIND $numeric_type (@MAKE_SIMD $lit,) (@LITERAL $lit, $numeric_type)) }
+    };
+    // SIMD variants splat the value of their lane type
+    (@MAKE_SIMD $lit:tt, $numeric_type:ident ($lane:ident $lanes:literal)) => {
+        $crate::__simd_impl!(@SPLAT $lanes, $crate::__nz_impl!(@MAKE_VAL $lit, $lane))
+    };
+
+    // Generate a method returning the first variant found in a list ordered by precision
+    (@WIDEST $vis:tt $name:ident $types:tt) => {
+        $crate::__nz_impl!(@FIND_WIDEST (@METHOD ($vis $name widest "widest")) $types);
+    };
+    (@FIND_WIDEST $callback:tt $types:tt) => {
+        $crate::__nz_impl!(@FIND $callback $types [
+            f128 f64 real f32 f16 bf16
+            i128 u128 nz_i128 nz_u128 nonmax_i128 nonmax_u128
+            i64 u64 nz_i64 nz_u64 nonmax_i64 nonmax_u64 c_longlong c_ulonglong
+            isize usize nz_isize nz_usize nonmax_isize nonmax_usize c_long c_ulong c_ssize_t c_size_t
+            i32 u32 nz_i32 nz_u32 nonmax_i32 nonmax_u32 c_int c_uint
+            i16 u16 nz_i16 nz_u16 nonmax_i16 nonmax_u16 c_short c_ushort
+            i8 u8 nz_i8 nz_u8 nonmax_i8 nonmax_u8 c_char c_schar c_uchar
+        ]);
+    };
+    (@SIGN ($d:tt) $name:ident [$($numeric_type:ident)*] $widest:ident) => {
+        #[allow(deprecated)]
+        impl $crate::Sign for $name {
+            // Every variant with a plain value must agree, which rounding or saturation may break,
+            // failing to compile only where the sign is used, as the provided items are generic
+            const SIGN: ::core::option::Option<::core::cmp::Ordering> = {
+                let expected = $crate::__nz_impl!(@SIGN_OF $widest, $name.$widest);
+                #[allow(unused_macros)]
+                macro_rules! __sign {
+                    $(($numeric_type) => {
+                        if $crate::__nz_impl!(@SIGN_OF $numeric_type, $name.$numeric_type) != expected {
+                            ::core::panic!(::core::concat!(
+                                "the `", ::core::stringify!($numeric_type), "` variant of `", ::core::stringify!($name), "` does not have the sign of the `", ::core::stringify!($widest), "` variant"
+                            ));
+                        }
+                    };)*
+                }
+                $crate::__nz_impl!(@SIGN_CHECK ($d) __sign [$($numeric_type)*] [
+                    f128 f64 real f32 f16 bf16
+                    i128 u128 nz_i128 nz_u128 nonmax_i128 nonmax_u128
+                    i64 u64 nz_i64 nz_u64 nonmax_i64 nonmax_u64 c_longlong c_ulonglong
+                    isize usize nz_isize nz_usize nonmax_isize nonmax_usize c_long c_ulong c_ssize_t c_size_t
+                    i32 u32 nz_i32 nz_u32 nonmax_i32 nonmax_u32 c_int c_uint
+                    i16 u16 nz_i16 nz_u16 nonmax_i16 nonmax_u16 c_short c_ushort
+                    i8 u8 nz_i8 nz_u8 nonmax_i8 nonmax_u8 c_char c_schar c_uchar
+                ]);
+                match expected {
+                    -1 => ::core::option::Option::Some(::core::cmp::Ordering::Less),
+                    0 => ::core::option::Option::Some(::core::cmp::Ordering::Equal),
+                    1 => ::core::option::Option::Some(::core::cmp::Ordering::Greater),
+                    _ => ::core::option::Option::None,
+                }
+            };
+        }
+    };
+    // Check the variants with a plain value, through a local macro matching any candidate
+    (@SIGN_CHECK ($d:tt) $check:ident [$($numeric_type:ident)*] [$($candidates:ident)*]) => {
+        macro_rules! __plain {
+            $(($candidates) => { $check!($candidates); };)*
+            ($d other:ident) => {};
+        }
+        $(__plain!($numeric_type);)*
+    };
+    // The sign as -1, 0 or 1, and 2 for NaN
+    (@SIGN_OF $numeric_type:ident, $value:expr) => {{
+        let value = $crate::__nz_impl!(@PLAIN $numeric_type, $value);
+        let zero = if true { 0 as _ } else { value };
+        if value > zero { 1 } else if value < zero { -1 } else if value == zero { 0 } else { 2 }
+    }};
+    (@NARROWEST $vis:tt $name:ident $types:tt) => {
+        $crate::__nz_impl!(@FIND (@METHOD ($vis $name narrowest "narrowest")) $types [
+            bf16 f16 f32 real f64 f128
+            u8 i8 nz_u8 nz_i8 nonmax_u8 nonmax_i8 c_uchar c_char c_schar
+            u16 i16 nz_u16 nz_i16 nonmax_u16 nonmax_i16 c_ushort c_short
+            u32 i32 nz_u32 nz_i32 nonmax_u32 nonmax_i32 c_uint c_int
+            usize isize nz_usize nz_isize nonmax_usize nonmax_isize c_size_t c_ssize_t c_ulong c_long
+            u64 i64 nz_u64 nz_i64 nonmax_u64 nonmax_i64 c_ulonglong c_longlong
+            u128 i128 nz_u128 nz_i128 nonmax_u128 nonmax_i128
+        ]);
+    };
+    (@CAST ($($vis:tt)*) $name:ident $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc = ::core::concat!("Convert `", ::core::stringify!($name), "` to any primitive number from its widest variant, with the given mode")]
+            #[allow(dead_code)]
+            $($vis)* fn cast<T: $crate::Cast>(&self, mode: $crate::CastMode) -> ::core::option::Option<T> {
+                $crate::__nz_impl!(@CONST_VALUE $numeric_type, self.$numeric_type).cast(mode)
+            }
+        }
+    };
+    // Only the floats and the integers of at most 32 bits are widened to `f64` without loss
+    (@TO_F64 $vis:tt $name:ident $types:tt) => {
+        $crate::__nz_impl!(@FIND (@WIDEN ($vis $name to_f64 f64)) $types [
+            f64 real f32 f16 bf16
+            i32 u32 nz_i32 nz_u32 nonmax_i32 nonmax_u32 bounded_i32 bounded_u32 u24 c_int c_uint
+            i16 u16 nz_i16 nz_u16 nonmax_i16 nonmax_u16 bounded_i16 bounded_u16 c_short c_ushort
+            i8 u8 nz_i8 nz_u8 nonmax_i8 nonmax_u8 bounded_i8 bounded_u8 c_char c_schar c_uchar
+        ]);
+    };
+    // Every integer is widened to `i128` without loss, except those of `u128`
+    (@TO_I128 $vis:tt $name:ident $types:tt) => {
+        $crate::__nz_impl!(@FIND (@WIDEN ($vis $name to_i128 i128)) $types [
+            i128 nz_i128 nonmax_i128 bounded_i128
+            i64 u64 nz_i64 nz_u64 nonmax_i64 nonmax_u64 bounded_i64 bounded_u64 u40 u48 u56 c_longlong c_ulonglong
+            isize usize nz_isize nz_usize nonmax_isize nonmax_usize bounded_isize bounded_usize c_long c_ulong c_ssize_t c_size_t
+            i32 u32 nz_i32 nz_u32 nonmax_i32 nonmax_u32 bounded_i32 bounded_u32 u24 c_int c_uint
+            i16 u16 nz_i16 nz_u16 nonmax_i16 nonmax_u16 bounded_i16 bounded_u16 c_short c_ushort
+            i8 u8 nz_i8 nz_u8 nonmax_i8 nonmax_u8 bounded_i8 bounded_u8 c_char c_schar c_uchar
+        ]);
+    };
+    (@WIDEN (($($vis:tt)*) $name:ident $method:ident $wide:ident) $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc = ::core::concat!(
+                "Widen `", ::core::stringify!($name), "` to `", ::core::stringify!($wide),
+                "` without loss, from its `", ::core::stringify!($numeric_type), "` variant"
+            )]
+            #[allow(dead_code, clippy::unnecessary_cast)]
+            $($vis)* const fn $method(&self) -> ::core::primitive::$wide {
+                $crate::__nz_impl!(@PLAIN $numeric_type, self.$numeric_type) as ::core::primitive::$wide
+            }
+        }
+    };
+    // Look for each candidate in turn in the variants, through a local macro matching any variant,
+    // and call back with the first one found
+    // Candidates are checked four at a time, as the lists are long enough to reach the recursion limit
+    (@FIND $callback:tt $types:tt $candidates:tt) => {
+        $crate::__nz_impl!(@FIND_IN ($) $callback $types $candidates);
+    };
+    (@FIND_IN ($d:tt) $callback:tt [$($numeric_type:ident)*] [$($candidates:ident)*]) => {
+        #[allow(deprecated)]
+        const _: () = {
+            macro_rules! __find {
+                $(($numeric_type $d($d rest:ident)*) => { $crate::__nz_impl!(@CALLBACK $callback $numeric_type); };)*
+                $(($d a:ident $numeric_type $d($d rest:ident)*) => { $crate::__nz_impl!(@CALLBACK $callback $numeric_type); };)*
+                $(($d a:ident $d b:ident $numeric_type $d($d rest:ident)*) => { $crate::__nz_impl!(@CALLBACK $callback $numeric_type); };)*
+                $(($d a:ident $d b:ident $d c:ident $numeric_type $d($d rest:ident)*) => { $crate::__nz_impl!(@CALLBACK $callback $numeric_type); };)*
+                ($d a:ident $d b:ident $d c:ident $d e:ident $d($d rest:ident)*) => { __find!($d($d rest)*); };
+                ($d($d other:ident)*) => {};
+            }
+            __find!($($candidates)*);
+        };
+    };
+    (@CALLBACK ($($callback:tt)*) $numeric_type:ident) => {
+        $crate::__nz_impl!($($callback)* $numeric_type);
+    };
+    (@METHOD (($($vis:tt)*) $name:ident $method:ident $doc:literal) $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc = ::core::concat!("Get the ", $doc, " variant of `", ::core::stringify!($name), "`, `", ::core::stringify!($numeric_type), "`")]
+            #[allow(dead_code)]
+            $($vis)* const fn $method(&self) -> $crate::__nz_impl!(@GET_TYPE $numeric_type) {
+                self.$numeric_type
+            }
+        }
+    };
+
+    // Find the `unit` label, falling back to the unit of the initializer
+    (@LABEL $vis:tt $name:ident $fallback:tt [(label $label:literal) $($options:tt)*]) => {
+        $crate::__nz_impl!(@LABEL_IMPL $vis $name ($label));
+    };
+    (@LABEL $vis:tt $name:ident $fallback:tt [(unit $unit:ident) $($options:tt)*]) => {
+        $crate::__nz_impl!(@LABEL $vis $name (::core::stringify!($unit)) [$($options)*]);
+    };
+    (@LABEL $vis:tt $name:ident $fallback:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@LABEL $vis $name $fallback [$($options)*]);
+    };
+    (@LABEL $vis:tt $name:ident () []) => {};
+    (@LABEL $vis:tt $name:ident $fallback:tt []) => {
+        $crate::__nz_impl!(@LABEL_IMPL $vis $name $fallback);
+    };
+    (@LABEL_IMPL ($($vis:tt)*) $name:ident ($label:expr)) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc = ::core::concat!("Get the unit of `", ::core::stringify!($name), "`")]
+            #[allow(dead_code)]
+            $($vis)* const fn unit() -> &'static ::core::primitive::str {
+                $label
+            }
+        }
+    };
+
+    // Generate the constant with each value of `#[cfg_value(...)]` under its condition,
+    // and with its initializer when none of them hold
+    (@CFG_VALUE [(cfg_value ($($predicate:tt)*) ($value:literal)) $($options:tt)*] [$(not $not:tt)*] [$($cfgs:tt)*] $sort:tt ($config:tt $options_all:tt $docs:tt $attrs:tt $vis:tt $name:ident) $lit:tt $types:tt) => {
+        #[cfg(all($(not $not,)* $($predicate)*))]
+        $($cfgs)*
+        $crate::__nz_impl!(@SORT $sort ($config $options_all $docs $attrs $vis $name $value) $types);
+        $crate::__nz_impl!(@CFG_VALUE [$($options)*] [$(not $not)* not($($predicate)*)] [$($cfgs)*] $sort ($config $options_all $docs $attrs $vis $name) $lit $types);
+    };
+    (@CFG_VALUE [(cfg_value ($($predicate:tt)*) ($init:expr)) $($options:tt)*] [$(not $not:tt)*] [$($cfgs:tt)*] $sort:tt ($config:tt $options_all:tt $docs:tt $attrs:tt $vis:tt $name:ident) $lit:tt $types:tt) => {
+        #[cfg(all($(not $not,)* $($predicate)*))]
+        $($cfgs)*
+        $crate::__nz_impl!(@SORT $sort ($config $options_all $docs $attrs $vis $name (@EXPR $name $init)) $types);
+        $crate::__nz_impl!(@CFG_VALUE [$($options)*] [$(not $not)* not($($predicate)*)] [$($cfgs)*] $sort ($config $options_all $docs $attrs $vis $name) $lit $types);
+    };
+    (@CFG_VALUE [$other:tt $($options:tt)*] $not:tt $cfgs:tt $sort:tt $args:tt $lit:tt $types:tt) => {
+        $crate::__nz_impl!(@CFG_VALUE [$($options)*] $not $cfgs $sort $args $lit $types);
+    };
+    (@CFG_VALUE [] [$(not $not:tt)*] [$($cfgs:tt)*] $sort:tt ($config:tt $options_all:tt $docs:tt $attrs:tt $vis:tt $name:ident) $lit:tt $types:tt) => {
+        // Reorder the variants first if requested by `#[sort_fields]`
+        #[cfg(all($(not $not),*))]
+        $($cfgs)*
+        $crate::__nz_impl!(@SORT $sort ($config $options_all $docs $attrs $vis $name $lit) $types);
+    };
+
+    // Find the `sort_fields` option, and order the variants by descending size
+    (@SORT [(sort_fields) $($options:tt)*] ($config:tt { $($all:tt)* } $($args:tt)*) $types:tt) => {
+        $crate::__nz_impl!(@SORT_CUSTOM [$($all)*] ($config { $($all)* } $($args)*) $types);
+    };
+    (@SORT [$other:tt $($options:tt)*] $args:tt $types:tt) => {
+        $crate::__nz_impl!(@SORT [$($options)*] $args $types);
+    };
+    (@SORT [] ($($args:tt)*) $types:tt) => {
+        $crate::polymorphic_constant!(@GENERATE $($args)* $types);
+    };
+    // The size of the custom variants is unknown to the macro, which lists them last
+    (@SORT_CUSTOM [(custom $custom:tt) $($options:tt)*] $args:tt $types:tt) => {
+        ::core::compile_error!("`sort_fields` cannot be combined with `custom`, list the variants in the order of their size instead");
+    };
+    (@SORT_CUSTOM [$other:tt $($options:tt)*] $args:tt $types:tt) => {
+        $crate::__nz_impl!(@SORT_CUSTOM [$($options)*] $args $types);
+    };
+    (@SORT_CUSTOM [] $args:tt $types:tt) => {
+        $crate::__nz_impl!(@SORT_EACH $args [] [] [] [] [] [] $types);
+    };
+    // Sort each variant into the bucket of its size, pointer-sized variants being between 64 and 32 bits
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [ipv6 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* ipv6] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [duration $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* duration] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [char $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* char] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [ipv4 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* ipv4] $b4 $b5 [$($rest)*]);
+    };
+    // `Decimal` is made of four `u32`
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [decimal $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* decimal] $b4 $b5 [$($rest)*]);
+    };
+    // `Complex` is aligned as its float type
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [c_f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* c_f64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [uom_length $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* uom_length] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [uom_mass $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* uom_mass] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [uom_time $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* uom_time] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [uom_velocity $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* uom_velocity] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [uom_acceleration $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* uom_acceleration] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [uom_frequency $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* uom_frequency] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [c_f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* c_f32] $b4 $b5 [$($rest)*]);
+    };
+    // `OrderedFloat` and `NotNan` are aligned as their float type
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [of_f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* of_f64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [nn_f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* nn_f64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [of_f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* of_f32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [nn_f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* nn_f32] $b4 $b5 [$($rest)*]);
+    };
+    // `Ratio` is aligned as its integer type
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [ratio_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* ratio_i128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [ratio_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* ratio_u128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [ratio_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* ratio_i64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [ratio_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* ratio_u64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [ratio_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* ratio_i32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [ratio_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* ratio_u32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [bigint $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* bigint] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [biguint $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* biguint] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [limbs $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* limbs] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    // The types of `bnum` are arrays of `u64` digits
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [u256 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* u256] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [i256 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* i256] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    // Byte arrays are aligned as bytes
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [le_bytes $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* le_bytes] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [be_bytes $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* be_bytes] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [ne_bytes $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* ne_bytes] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [f128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* f128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* i128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* u128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [nz_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* nz_i128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [nz_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* nz_u128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* i64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* u64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* f64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [nz_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* nz_i64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [nz_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* nz_u64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [isize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* isize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* usize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    // String slices are aligned as pointers
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [str $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* str] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [ptr_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* ptr_u8] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [ptr_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* ptr_u16] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [ptr_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* ptr_u32] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [ptr_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* ptr_u64] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [ptr_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* ptr_usize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [nz_isize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* nz_isize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [nz_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* nz_usize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* i32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* u32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* f32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [real $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* real] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [nz_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* nz_i32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [nz_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* nz_u32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [f16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* f16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [bf16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* bf16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [i16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* i16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* u16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [nz_i16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* nz_i16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [nz_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* nz_u16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [i8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* i8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [bool $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* bool] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* u8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [nz_i8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* nz_i8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [nz_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* nz_u8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [nonmax_i8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* nonmax_i8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [nonmax_i16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* nonmax_i16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [nonmax_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* nonmax_i32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [nonmax_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* nonmax_i64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [nonmax_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* nonmax_i128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [nonmax_isize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* nonmax_isize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [nonmax_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* nonmax_u8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [nonmax_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* nonmax_u16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [nonmax_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* nonmax_u32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [nonmax_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* nonmax_u64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [nonmax_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* nonmax_u128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [nonmax_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* nonmax_usize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [bounded_i8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* bounded_i8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [bounded_i16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* bounded_i16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [bounded_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* bounded_i32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [bounded_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* bounded_i64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [bounded_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* bounded_i128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [bounded_isize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* bounded_isize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [bounded_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* bounded_u8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [bounded_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* bounded_u16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [bounded_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* bounded_u32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [bounded_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* bounded_u64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [bounded_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* bounded_u128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [bounded_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* bounded_usize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    // The types of `arbitrary-int` are held in the next primitive
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [u24 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* u24] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [u40 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* u40] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [u48 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* u48] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [u56 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* u56] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    // The narrowest types are sorted last, their size depending on the value
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [smallest_uint $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* smallest_uint] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [smallest_int $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* smallest_int] [$($rest)*]);
+    };
+    // C types are sorted by their usual size, which can differ on some targets
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [c_char $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* c_char] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [c_schar $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* c_schar] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [c_uchar $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* c_uchar] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [c_short $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* c_short] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [c_ushort $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* c_ushort] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [c_int $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* c_int] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [c_uint $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* c_uint] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [c_long $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* c_long] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [c_ulong $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* c_ulong] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [c_ssize_t $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* c_ssize_t] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [c_size_t $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* c_size_t] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [c_longlong $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* c_longlong] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [c_ulonglong $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* c_ulonglong] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    // `Option` of a non-zero type is aligned as its integer type
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [opt_nz_i8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* opt_nz_i8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [opt_nz_i16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* opt_nz_i16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [opt_nz_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* opt_nz_i32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [opt_nz_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* opt_nz_i64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [opt_nz_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* opt_nz_i128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [opt_nz_isize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* opt_nz_isize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] [opt_nz_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* opt_nz_u8] [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt [opt_nz_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* opt_nz_u16] $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt [opt_nz_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* opt_nz_u32] $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt [opt_nz_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* opt_nz_u64] $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [opt_nz_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* opt_nz_u128] $b1 $b2 $b3 $b4 $b5 [$($rest)*]);
+    };
+    (@SORT_EACH $args:tt $b0:tt $b1:tt [$($b2:tt)*] $b3:tt $b4:tt $b5:tt [opt_nz_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 [$($b2)* opt_nz_usize] $b3 $b4 $b5 [$($rest)*]);
+    };
+    // Fixed-point variants are sorted by the size of their bits
+    (@SORT_EACH $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__fixed_kind! { $other (@SORT_FIXED $args $b0 $b1 $b2 $b3 $b4 $b5 [$($rest)*]) (@SIMD_KIND $other (@SORT_SIMD $args $b0 $b1 $b2 $b3 $b4 $b5 [$($rest)*]) (@SORT_UNKNOWN $other)) }
+    };
+    (@SORT_FIXED $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt $rest:tt $numeric_type:ident ($fixed:ident i32 $frac:literal)) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* $numeric_type] $b4 $b5 $rest);
+    };
+    (@SORT_FIXED $args:tt $b0:tt $b1:tt $b2:tt [$($b3:tt)*] $b4:tt $b5:tt $rest:tt $numeric_type:ident ($fixed:ident u32 $frac:literal)) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 [$($b3)* $numeric_type] $b4 $b5 $rest);
+    };
+    (@SORT_FIXED $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt $rest:tt $numeric_type:ident ($fixed:ident i16 $frac:literal)) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* $numeric_type] $b5 $rest);
+    };
+    (@SORT_FIXED $args:tt $b0:tt $b1:tt $b2:tt $b3:tt [$($b4:tt)*] $b5:tt $rest:tt $numeric_type:ident ($fixed:ident u16 $frac:literal)) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 [$($b4)* $numeric_type] $b5 $rest);
+    };
+    (@SORT_FIXED $args:tt $b0:tt $b1:tt $b2:tt $b3:tt $b4:tt [$($b5:tt)*] $rest:tt $numeric_type:ident ($fixed:ident $bits:ident $frac:literal)) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 $b1 $b2 $b3 $b4 [$($b5)* $numeric_type] $rest);
+    };
+    // SIMD vectors are aligned as their size, only `f32x2` being smaller than 16 bytes
+    (@SORT_SIMD $args:tt $b0:tt [$($b1:tt)*] $b2:tt $b3:tt $b4:tt $b5:tt $rest:tt f32x2 $info:tt) => {
+        $crate::__nz_impl!(@SORT_EACH $args $b0 [$($b1)* f32x2] $b2 $b3 $b4 $b5 $rest);
+    };
+    (@SORT_SIMD $args:tt [$($b0:tt)*] $b1:tt $b2:tt $b3:tt $b4:tt $b5:tt $rest:tt $numeric_type:ident $info:tt) => {
+        $crate::__nz_impl!(@SORT_EACH $args [$($b0)* $numeric_type] $b1 $b2 $b3 $b4 $b5 $rest);
+    };
+    (@SORT_UNKNOWN $numeric_type:ident) => {
+        ::core::compile_error!(::core::concat!("unknown variant `", ::core::stringify!($numeric_type), "`"));
+    };
+    (@SORT_EACH ($($args:tt)*) [$($b0:tt)*] [$($b1:tt)*] [$($b2:tt)*] [$($b3:tt)*] [$($b4:tt)*] [$($b5:tt)*] []) => {
+        $crate::polymorphic_constant!(@GENERATE $($args)* [$($b0)* $($b1)* $($b2)* $($b3)* $($b4)* $($b5)*]);
+    };
+
+    // Read the selected conversions, later entries overriding earlier ones
+    (@IMPLS { into: $into:tt, from: $from:tt, try_from: $try_from:tt } [into $($impls:ident)*] $name:ident $types:tt) => {
+        $crate::__nz_impl!(@IMPLS { into: true, from: $from, try_from: $try_from } [$($impls)*] $name $types);
+    };
+    (@IMPLS { into: $into:tt, from: $from:tt, try_from: $try_from:tt } [no_into $($impls:ident)*] $name:ident $types:tt) => {
+        $crate::__nz_impl!(@IMPLS { into: false, from: $from, try_from: $try_from } [$($impls)*] $name $types);
+    };
+    (@IMPLS { into: $into:tt, from: $from:tt, try_from: $try_from:tt } [from $($impls:ident)*] $name:ident $types:tt) => {
+        $crate::__nz_impl!(@IMPLS { into: $into, from: true, try_from: $try_from } [$($impls)*] $name $types);
+    };
+    (@IMPLS { into: $into:tt, from: $from:tt, try_from: $try_from:tt } [try_from $($impls:ident)*] $name:ident $types:tt) => {
+        $crate::__nz_impl!(@IMPLS { into: $into, from: $from, try_from: true } [$($impls)*] $name $types);
+    };
+    (@IMPLS $flags:tt [$impl:ident $($impls:ident)*] $name:ident $types:tt) => {
+        ::core::compile_error!(::core::concat!("unknown impl `", ::core::stringify!($impl), "`, expected `into`, `no_into`, `from` or `try_from`"));
+    };
+    (@IMPLS { into: $into:tt, from: $from:tt, try_from: $try_from:tt } [] $name:ident $types:tt) => {
+        $crate::__nz_impl!(@IMPL_INTO $into $from $name $types);
+        $crate::__nz_impl!(@IMPL_FROM $from $name $types);
+        $crate::__nz_impl!(@IMPL_POLYMORPHIC $into $from $name $types);
+        $crate::__nz_impl!(@IMPL_TRY_FROM $try_from $name $types);
+    };
+
+    // `From` already provides `Into` through the blanket impl of core
+    (@IMPL_INTO true false $name:ident [$($numeric_type:ident)*]) => {
+        $($crate::__nz_impl!(@IMPL_INTO_EACH $name $numeric_type);)*
+    };
+    (@IMPL_INTO $into:tt $from:tt $name:ident $types:tt) => {};
+    (@IMPL_FROM true $name:ident [$($numeric_type:ident)*]) => {
+        $($crate::__nz_impl!(@IMPL_FROM_EACH $name $numeric_type);)*
+    };
+    (@IMPL_FROM false $name:ident $types:tt) => {};
+    // The narrowest types and the pointer types are selected by a projection, which coherence cannot tell apart from other types
+    // Big integers are converted to the value built by their variant
+    (@IMPL_INTO_EACH $name:ident bigint) => { $crate::__nz_impl!(@IMPL_BIG $name bigint BigInt); };
+    (@IMPL_INTO_EACH $name:ident biguint) => { $crate::__nz_impl!(@IMPL_BIG $name biguint BigUint); };
+    (@IMPL_INTO_EACH $name:ident smallest_uint) => {};
+    (@IMPL_INTO_EACH $name:ident smallest_int) => {};
+    (@IMPL_INTO_EACH $name:ident ptr_u8) => {};
+    (@IMPL_INTO_EACH $name:ident ptr_u16) => {};
+    (@IMPL_INTO_EACH $name:ident ptr_u32) => {};
+    (@IMPL_INTO_EACH $name:ident ptr_u64) => {};
+    (@IMPL_INTO_EACH $name:ident ptr_usize) => {};
+    // `real` variants alias a float type, whose impls would conflict with the ones of its variant
+    (@IMPL_INTO_EACH $name:ident real) => {};
+    // Byte variants of different orders share their array type
+    (@IMPL_INTO_EACH $name:ident le_bytes) => {};
+    (@IMPL_INTO_EACH $name:ident be_bytes) => {};
+    (@IMPL_INTO_EACH $name:ident ne_bytes) => {};
+    (@IMPL_INTO_EACH $name:ident $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl ::core::convert::Into<$crate::__nz_impl!(@FIELD_TYPE $name $numeric_type)> for $name {
+            fn into(self) -> $crate::__nz_impl!(@FIELD_TYPE $name $numeric_type) {
+                self.$numeric_type
+            }
+        }
+    };
+    (@IMPL_FROM_EACH $name:ident bigint) => { $crate::__nz_impl!(@IMPL_BIG $name bigint BigInt); };
+    (@IMPL_FROM_EACH $name:ident biguint) => { $crate::__nz_impl!(@IMPL_BIG $name biguint BigUint); };
+    (@IMPL_FROM_EACH $name:ident smallest_uint) => {};
+    (@IMPL_FROM_EACH $name:ident smallest_int) => {};
+    (@IMPL_FROM_EACH $name:ident ptr_u8) => {};
+    (@IMPL_FROM_EACH $name:ident ptr_u16) => {};
+    (@IMPL_FROM_EACH $name:ident ptr_u32) => {};
+    (@IMPL_FROM_EACH $name:ident ptr_u64) => {};
+    (@IMPL_FROM_EACH $name:ident ptr_usize) => {};
+    (@IMPL_FROM_EACH $name:ident real) => {};
+    (@IMPL_FROM_EACH $name:ident le_bytes) => {};
+    (@IMPL_FROM_EACH $name:ident be_bytes) => {};
+    (@IMPL_FROM_EACH $name:ident ne_bytes) => {};
+    (@IMPL_FROM_EACH $name:ident $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__nz_impl!(@FIELD_TYPE $name $numeric_type) {
+            fn from(value: $name) -> Self {
+                value.$numeric_type
+            }
+        }
+    };
+    // `FromPolymorphic` follows the `Into` impls, and conflicts wherever they would
+    (@IMPL_POLYMORPHIC false false $name:ident $types:tt) => {};
+    (@IMPL_POLYMORPHIC $into:tt $from:tt $name:ident [$($numeric_type:ident)*]) => {
+        $($crate::__nz_impl!(@IMPL_POLYMORPHIC_EACH $name $numeric_type);)*
+    };
+    (@IMPL_POLYMORPHIC_EACH $name:ident bigint) => { $crate::__nz_impl!(@IMPL_POLYMORPHIC_BIG $name bigint BigInt); };
+    (@IMPL_POLYMORPHIC_EACH $name:ident biguint) => { $crate::__nz_impl!(@IMPL_POLYMORPHIC_BIG $name biguint BigUint); };
+    (@IMPL_POLYMORPHIC_EACH $name:ident smallest_uint) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident smallest_int) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident ptr_u8) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident ptr_u16) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident ptr_u32) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident ptr_u64) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident ptr_usize) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident real) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident le_bytes) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident be_bytes) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident ne_bytes) => {};
+    (@IMPL_POLYMORPHIC_EACH $name:ident $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl $crate::FromPolymorphic<$name> for $crate::__nz_impl!(@FIELD_TYPE $name $numeric_type) {
+            fn from_polymorphic(constant: $name) -> Self {
+                constant.$numeric_type
+            }
+        }
+    };
+    (@IMPL_POLYMORPHIC_BIG $name:ident $variant:ident $big:ident) => {
+        #[allow(deprecated)]
+        impl $crate::FromPolymorphic<$name> for $crate::__bigint_impl!(@TYPE $big) {
+            fn from_polymorphic(constant: $name) -> Self {
+                constant.$variant.get()
+            }
+        }
+    };
+    (@IMPL_BIG $name:ident $variant:ident $big:ident) => {
+        #[allow(deprecated)]
+        impl ::core::convert::From<$name> for $crate::__bigint_impl!(@TYPE $big) {
+            fn from(value: $name) -> Self {
+                value.$variant.get()
+            }
+        }
+    };
+    // Recognize the constant from any of its variants, giving back the value otherwise
+    (@IMPL_TRY_FROM true $name:ident [$($numeric_type:ident)*]) => {
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<$crate::ConstValue> for $name {
+            type Error = $crate::ConstValue;
+
+            fn try_from(value: $crate::ConstValue) -> ::core::result::Result<Self, Self::Error> {
+                $(if value == $crate::__nz_impl!(@CONST_VALUE $numeric_type, $name.$numeric_type) {
+                    return ::core::result::Result::Ok($name);
+                })*
+                ::core::result::Result::Err(value)
+            }
+        }
+    };
+    (@IMPL_TRY_FROM false $name:ident $types:tt) => {};
+
+    // Skip the booleans, characters, big integers and addresses, which have no `ConstValue`, then implement the iteration over the other variants
+    (@ITER $name:ident $kept:tt [bool $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [char $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [duration $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [decimal $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [c_f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [c_f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [uom_length $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [uom_mass $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [uom_time $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [uom_velocity $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [uom_acceleration $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [uom_frequency $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [of_f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [of_f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [nn_f32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [nn_f64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ratio_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ratio_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ratio_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ratio_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ratio_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ratio_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ipv4 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ipv6 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ptr_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ptr_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ptr_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ptr_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ptr_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [bigint $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [biguint $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [limbs $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [str $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [u256 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [i256 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [le_bytes $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [be_bytes $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [ne_bytes $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ITER $name $kept [$($rest)*]);
+    };
+    (@ITER $name:ident $kept:tt [$numeric_type:ident $($rest:ident)*]) => {
+        $crate::__simd_kind! { $numeric_type (@ITER_SIMD $name $kept [$($rest)*]) (@ITER_KEEP $name $kept $numeric_type [$($rest)*]) }
+    };
+    (@ITER_SIMD $name:ident $kept:tt $rest:tt $numeric_type:ident $info:tt) => {
+        $crate::__nz_impl!(@ITER $name $kept $rest);
+    };
+    (@ITER_KEEP $name:ident [$($kept:ident)*] $numeric_type:ident $rest:tt) => {
+        $crate::__nz_impl!(@ITER $name [$($kept)* $numeric_type] $rest);
+    };
+    (@ITER $name:ident [$($numeric_type:ident)*] []) => {
+        #[allow(deprecated)]
+        impl ::core::iter::IntoIterator for $name {
+            type Item = (&'static ::core::primitive::str, $crate::ConstValue);
+            type IntoIter = ::core::array::IntoIter<Self::Item, { <[&::core::primitive::str]>::len(&[$(::core::stringify!($numeric_type)),*]) }>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter([
+                    $((::core::stringify!($numeric_type), $crate::__nz_impl!(@CONST_VALUE $numeric_type, self.$numeric_type)),)*
+                ])
+            }
+        }
+    };
+
+    // Gather the custom variants registered by `#![custom(...)]`, then those given by the `custom` option,
+    // each of which must be a variant of the constant
+    (@CUSTOM_STRIP $args:tt $options:tt { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } $types:tt) => {
+        $crate::__nz_impl!(@CUSTOM_GATHER $args $options $customs $types);
+    };
+    (@CUSTOM_GATHER $args:tt [(custom [$(($field:ident $path:tt))*]) $($options:tt)*] [$($customs:tt)*] $types:tt) => {
+        $(
+            const _: () = if !$crate::__nz_impl!(@CUSTOM_LISTED $field $types) {
+                ::core::panic!(::core::concat!("the variant `", ::core::stringify!($field), "` given by `custom` is not listed by the constant"));
+            };
+        )*
+        $crate::__nz_impl!(@CUSTOM_GATHER $args [$($options)*] [$($customs)* $(($field $path))*] $types);
+    };
+    (@CUSTOM_GATHER $args:tt [$other:tt $($options:tt)*] $customs:tt $types:tt) => {
+        $crate::__nz_impl!(@CUSTOM_GATHER $args [$($options)*] $customs $types);
+    };
+    (@CUSTOM_GATHER $args:tt [] [] $types:tt) => {
+        $crate::polymorphic_constant!(@CUSTOM_DONE $args $types);
+    };
+    (@CUSTOM_GATHER $args:tt [] $customs:tt $types:tt) => {
+        $crate::__nz_impl!(@CUSTOM_SPLIT ($) $customs $args $types);
+    };
+    (@CUSTOM_LISTED $field:ident [$($numeric_type:ident)*]) => {
+        $crate::__has_variant(&[$(::core::stringify!($numeric_type)),*], ::core::stringify!($field))
+    };
+    // Split the variants through a local macro matching any of the custom variants, wherever they are listed,
+    // the struct holding them after the other variants
+    (@CUSTOM_SPLIT ($d:tt) [$(($field:ident $path:tt))+] $args:tt [$($numeric_type:ident)*]) => {
+        macro_rules! __custom_split {
+            $(($d args:tt [$d($d types:ident)*] [$d($d customs:tt)*] $field $d($d rest:ident)*) => {
+                __custom_split!($d args [$d($d types)*] [$d($d customs)* ($field $path)] $d($d rest)*);
+            };)+
+            ($d args:tt [$d($d types:ident)*] $d customs:tt $d other:ident $d($d rest:ident)*) => {
+                __custom_split!($d args [$d($d types)* $d other] $d customs $d($d rest)*);
+            };
+            ($d args:tt [] $d customs:tt) => {
+                ::core::compile_error!("the variants given by `custom` require at least one other variant");
+            };
+            ($d args:tt $d types:tt []) => { $crate::polymorphic_constant!(@CUSTOM_DONE $d args $d types); };
+            ($d args:tt $d types:tt $d customs:tt) => { $crate::__nz_impl!(@CUSTOM_FOUND $d args $d customs $d types); };
+        }
+        __custom_split!($args [] [] $($numeric_type)*);
+    };
+    // Give the custom variants listed by the constant as its first option, found before any other `custom` option
+    (@CUSTOM_FOUND ($config:tt { $($options:tt)* } $($args:tt)*) [$($custom:tt)*] $types:tt) => {
+        $crate::polymorphic_constant!(@CUSTOM_DONE ($config { (custom [$($custom)*]) $($options)* } $($args)*) $types);
+    };
+    // Give the initializer to the constructor of each custom variant
+    (@CUSTOM_FIND [(custom [$(($field:ident $path:tt))*]) $($options:tt)*] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $values:tt $lit:tt $all:tt)) => {
+        $crate::__nz_impl!(@FLAT $config $docs $attrs $vis $name $types $values [$(($field $path $lit))*] $all);
+    };
+    (@CUSTOM_FIND [$other:tt $($options:tt)*] $args:tt) => {
+        $crate::__nz_impl!(@CUSTOM_FIND [$($options)*] $args);
+    };
+    (@CUSTOM_FIND [] ($config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $values:tt $lit:tt $all:tt)) => {
+        $crate::__nz_impl!(@FLAT $config $docs $attrs $vis $name $types $values [] $all);
+    };
+    (@CUSTOM_VALUE [$($path:ident)*] (@EXPR $name:ident $init:expr)) => { $($path)::*($init) };
+    (@CUSTOM_VALUE [$($path:ident)*] $lit:literal) => { $($path)::*($lit) };
+    // The type is the path of the constructor without its last segment
+    (@CUSTOM_TYPE [] [$last:ident]) => {
+        ::core::compile_error!("`custom` requires a constructor associated with its type, such as `Meters::new`")
+    };
+    (@CUSTOM_TYPE [$($type:ident)*] [$last:ident]) => { $($type)::* };
+    (@CUSTOM_TYPE [$($type:ident)*] [$first:ident $($rest:ident)+]) => { $crate::__nz_impl!(@CUSTOM_TYPE [$($type)* $first] [$($rest)*]) };
+
+    // Find the `flat` option, and hide the struct behind one plain constant per listed variant
+    (@FLAT $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $values:tt $custom:tt [(flat ($($numeric_type:ident = $flat:ident),* $(,)?)) $($options:tt)*]) => {
+        $crate::polymorphic_constant!(@EMIT $config [#[doc(hidden)]] $attrs () $name $types $values $custom);
+        $($crate::__nz_impl!(@FLAT_CONST $docs $vis $name $numeric_type $flat);)*
+    };
+    (@FLAT $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $values:tt $custom:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@FLAT $config $docs $attrs $vis $name $types $values $custom [$($options)*]);
+    };
+    (@FLAT $config:tt $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $values:tt $custom:tt []) => {
+        $crate::polymorphic_constant!(@EMIT $config $docs $attrs $vis $name $types $values $custom);
+    };
+    (@FLAT_CONST [$($docs:tt)*] ($($vis:tt)*) $name:ident $numeric_type:ident $flat:ident) => {
+        $($docs)*
+        #[doc = ::core::concat!("The value of `", ::core::stringify!($name), "` as `", ::core::stringify!($numeric_type), "`")]
+        $($vis)* const $flat: $crate::__nz_impl!(@FIELD_TYPE $name $numeric_type) = $name.$numeric_type;
+    };
+
+    // Find the `to_str` options, and render the literal as text at compile-time for each
+    (@TO_STR $vis:tt $name:ident (@EXPR $($init:tt)*) [(to_str $($args:tt)*) $($options:tt)*]) => {
+        ::core::compile_error!("`to_str` companions require a literal initializer");
+    };
+    (@TO_STR ($($vis:tt)*) $name:ident $lit:literal [(to_str $companion:ident ($($precision:literal)?)) $($options:tt)*]) => {
+        #[doc = ::core::concat!("`", ::core::stringify!($name), "` as text")]
+        $($vis)* const $companion: &::core::primitive::str = {
+            const TEXT: &([::core::primitive::u8; $crate::__TEXT_CAPACITY], ::core::primitive::usize) =
+                &$crate::__render_literal(::core::stringify!($lit), $crate::__nz_impl!(@TO_STR_PRECISION $($precision)?));
+            match ::core::str::from_utf8(TEXT.0.split_at(TEXT.1).0) {
+                ::core::result::Result::Ok(text) => text,
+                ::core::result::Result::Err(_) => ::core::unreachable!(),
+            }
+        };
+        $crate::__nz_impl!(@TO_STR ($($vis)*) $name $lit [$($options)*]);
+    };
+    (@TO_STR $vis:tt $name:ident $lit:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@TO_STR $vis $name $lit [$($options)*]);
+    };
+    (@TO_STR $vis:tt $name:ident $lit:tt []) => {};
+    (@TO_STR_PRECISION $precision:literal) => { ::core::option::Option::Some($precision) };
+    (@TO_STR_PRECISION) => { ::core::option::Option::None };
+
+    // Find the `socket_addr` options, and combine the address variant with the `u16` variant of each port
+    (@SOCKET_ADDR $vis:tt $name:ident $types:tt [(socket_addr $companion:ident $port:ident) $($options:tt)*]) => {
+        $crate::__nz_impl!(@SOCKET_ADDR_FIND ($vis $name $companion $port) [] $types);
+        $crate::__nz_impl!(@SOCKET_ADDR $vis $name $types [$($options)*]);
+    };
+    (@SOCKET_ADDR $vis:tt $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@SOCKET_ADDR $vis $name $types [$($options)*]);
+    };
+    (@SOCKET_ADDR $vis:tt $name:ident $types:tt []) => {};
+    // Prefer the `ipv6` variant, then the `ipv4` one
+    (@SOCKET_ADDR_FIND $args:tt $found:tt [ipv6 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SOCKET_ADDR_IMPL $args ipv6);
+    };
+    (@SOCKET_ADDR_FIND $args:tt $found:tt [ipv4 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SOCKET_ADDR_FIND $args [ipv4] [$($rest)*]);
+    };
+    (@SOCKET_ADDR_FIND $args:tt $found:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SOCKET_ADDR_FIND $args $found [$($rest)*]);
+    };
+    (@SOCKET_ADDR_FIND $args:tt [$found:ident] []) => {
+        $crate::__nz_impl!(@SOCKET_ADDR_IMPL $args $found);
+    };
+    (@SOCKET_ADDR_FIND $args:tt [] []) => {
+        ::core::compile_error!("`socket_addr` requires an `ipv4` or `ipv6` variant");
+    };
+    (@SOCKET_ADDR_IMPL (($($vis:tt)*) $name:ident $companion:ident $port:ident) $numeric_type:ident) => {
+        #[doc = ::core::concat!("`", ::core::stringify!($name), "` with the port `", ::core::stringify!($port), "`")]
+        #[allow(deprecated)]
+        $($vis)* const $companion: ::core::net::SocketAddr = ::core::net::SocketAddr::new(
+            $crate::__nz_impl!(@IP_ADDR $numeric_type, $name.$numeric_type),
+            $port.u16,
+        );
+    };
+    (@IP_ADDR ipv4, $value:expr) => { ::core::net::IpAddr::V4($value) };
+    (@IP_ADDR ipv6, $value:expr) => { ::core::net::IpAddr::V6($value) };
+
+    // Find the `also` options, and generate each companion in turn
+    (@ALSO $config:tt $vis:tt $name:ident $types:tt (@EXPR $($init:tt)*), [(also $also:tt) $($options:tt)*]) => {
+        ::core::compile_error!("`also` companions require a literal initializer");
+    };
+    (@ALSO $config:tt $vis:tt $name:ident $types:tt $lit:tt, [(also ($($op:ident $(= $companion:ident)?),* $(,)?)) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@ALSO_NAME (@ALSO_EACH $config $vis $name $types $lit, $op) $name $op $($companion)?);)*
+        $crate::__nz_impl!(@ALSO $config $vis $name $types $lit, [$($options)*]);
+    };
+    (@ALSO $config:tt $vis:tt $name:ident $types:tt $lit:tt, [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@ALSO $config $vis $name $types $lit, [$($options)*]);
+    };
+    (@ALSO $config:tt $vis:tt $name:ident $types:tt $lit:tt, []) => {};
+    (@ALSO_EACH $config:tt $vis:tt $name:ident [$($numeric_type:ident)*] $lit:literal, $op:ident $companion:ident) => {
+        $crate::polymorphic_constant!(@EMIT $config [
+            #[doc = ::core::concat!("`", ::core::stringify!($name), "` ", $crate::__nz_impl!(@ALSO_DOC $op))]
+        ] [] $vis $companion [$($numeric_type)*] {
+            $($numeric_type: $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, $numeric_type),)*
+        } []);
+    };
+
+    // Give the name of a companion to the callback, derived from the constant unless given explicitly
+    (@ALSO_NAME ($($callback:tt)*) $name:ident $op:ident $companion:ident) => {
+        $crate::__nz_impl!($($callback)* $companion);
+    };
+    (@ALSO_NAME ($($callback:tt)*) $name:ident recip) => {
+        $crate::__paste! { $crate::__nz_impl!($($callback)* [<$name _RECIP>]); }
+    };
+    (@ALSO_NAME ($($callback:tt)*) $name:ident squared) => {
+        $crate::__paste! { $crate::__nz_impl!($($callback)* [<$name _SQUARED>]); }
+    };
+    (@ALSO_NAME ($($callback:tt)*) $name:ident halved) => {
+        $crate::__paste! { $crate::__nz_impl!($($callback)* [<$name _HALF>]); }
+    };
+    (@ALSO_NAME $callback:tt $name:ident $op:ident) => {
+        $crate::__nz_impl!(@ALSO_DOC $op);
+    };
+
+    (@ALSO_DOC recip) => { "inverted" };
+    (@ALSO_DOC squared) => { "squared" };
+    (@ALSO_DOC halved) => { "halved" };
+    (@ALSO_DOC $op:ident) => {
+        ::core::compile_error!(::core::concat!("unknown companion `", ::core::stringify!($op), "`, expected `recip`, `squared` or `halved`"))
+    };
+
+    // Compute the companions in f64, and check that narrowing them to f32 stays finite
+    // Failures name the companion, its operation and the variant
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, f64) => { $crate::__nz_impl!(@ALSO_FLOAT $op $companion $lit, f64) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, f32) => { $crate::__nz_impl!(@ALSO_FLOAT $op $companion $lit, f32) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, real) => { $crate::__real_impl!((@ALSO_FLOAT $op $companion $lit,)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, f16) => { $crate::__f16_impl!((@ALSO_VAL $op $companion $lit,)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nightly_f16) => { $crate::__nz_impl!(@ALSO_FLOAT $op $companion $lit, f16) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, half_f16) => {
+        ::core::compile_error!("companions are not available for `f16` variants of the `half` feature")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bf16) => {
+        ::core::compile_error!("companions are not available for `bf16` variants")
+    };
+    // f128 companions are computed in f128 itself, going through f64 would lose its extra precision
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, f128) => {{
+        let value: $crate::__nightly_impl!(@TYPE f128) = $lit;
+        $crate::__nz_impl!(@ALSO_FLOAT $op $companion value, f128)
+    }};
+    (@ALSO_FLOAT recip $companion:ident $lit:expr, $float:ident) => {{
+        if $lit == 0.0 {
+            ::core::panic!($crate::__nz_impl!(@ALSO_ERROR recip $companion $float, "divides by zero"));
+        }
+        $crate::__nz_impl!(@FINITE $companion, (1.0 / $lit), $float)
+    }};
+    (@ALSO_FLOAT squared $companion:ident $lit:expr, $float:ident) => { $crate::__nz_impl!(@FINITE $companion, ($lit * $lit), $float) };
+    (@ALSO_FLOAT halved $companion:ident $lit:expr, $float:ident) => { $crate::__nz_impl!(@FINITE $companion, ($lit / 2.0), $float) };
+    (@FINITE $name:ident, $value:expr, $float:ident) => {{
+        let value = $value as $crate::__nz_impl!(@GET_TYPE $float);
+        if value > <$crate::__nz_impl!(@GET_TYPE $float)>::MAX || value < <$crate::__nz_impl!(@GET_TYPE $float)>::MIN {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` overflows to infinity in `", ::core::stringify!($float), "`"));
+        }
+        value
+    }};
+    (@ALSO_ERROR $op:ident $companion:ident $numeric_type:ident, $error:literal) => {
+        ::core::concat!("`", ::core::stringify!($companion), "` (", ::core::stringify!($op), ") ", $error, " in `", ::core::stringify!($numeric_type), "`")
+    };
+
+    // Compute the companions in the integer type itself, failing on overflow or inexact results
+    (@ALSO_VAL recip $companion:ident $lit:literal, $numeric_type:ident) => {
+        ::core::compile_error!("`recip` companions are only available for float variants")
+    };
+    // Compute the non-zero companions in their primitive type, and check the result
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_i8   ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_i8   , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_i16  ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_i16  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_i32  ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_i32  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_i64  ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_i64  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_i128 ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_i128 , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_isize) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_isize, $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, isize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_u8   ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_u8   , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_u16  ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_u16  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_u32  ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_u32  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_u64  ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_u64  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_u128 ) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_u128 , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nz_usize) => { $crate::__nz_impl!(@NZ_CHECKED $op $companion nz_usize, $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, usize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_i8   ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_i8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_i16  ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_i16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_i32  ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_i32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_i64  ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_i64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_i128 ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_i128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_isize) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_isize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_u8   ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_u8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_u16  ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_u16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_u32  ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_u32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_u64  ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_u64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_u128 ) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_u128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, opt_nz_usize) => { ::core::option::Option::Some($crate::__nz_impl!(@ALSO_VAL $op $companion $lit, nz_usize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_i8   ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_i8   , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_i16  ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_i16  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_i32  ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_i32  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_i64  ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_i64  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_i128 ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_i128 , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_isize) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_isize, $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, isize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_u8   ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_u8   , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_u16  ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_u16  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_u32  ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_u32  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_u64  ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_u64  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_u128 ) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_u128 , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nonmax_usize) => { $crate::__nz_impl!(@NONMAX_CHECKED $op $companion nonmax_usize, $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, usize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_i8   ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_i8   , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_i16  ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_i16  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_i32  ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_i32  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_i64  ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_i64  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_i128 ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_i128 , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, i128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_isize) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_isize, $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, isize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_u8   ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_u8   , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u8   )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_u16  ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_u16  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u16  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_u32  ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_u32  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u32  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_u64  ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_u64  , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u64  )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_u128 ) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_u128 , $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, u128 )) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bounded_usize) => { $crate::__nz_impl!(@BOUNDED_CHECKED $op $companion bounded_usize, $crate::__nz_impl!(@ALSO_VAL $op $companion $lit, usize)) };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, u24) => {
+        ::core::compile_error!("companions are not available for `u24` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, u40) => {
+        ::core::compile_error!("companions are not available for `u40` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, u48) => {
+        ::core::compile_error!("companions are not available for `u48` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, u56) => {
+        ::core::compile_error!("companions are not available for `u56` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bool) => {
+        ::core::compile_error!("companions are not available for `bool` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, char) => {
+        ::core::compile_error!("companions are not available for `char` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, duration) => {
+        ::core::compile_error!("companions are not available for `duration` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, decimal) => {
+        ::core::compile_error!("companions are not available for `decimal` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, c_f32) => {
+        ::core::compile_error!("companions are not available for `c_f32` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, c_f64) => {
+        ::core::compile_error!("companions are not available for `c_f64` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, uom_length) => {
+        ::core::compile_error!("companions are not available for `uom_length` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, uom_mass) => {
+        ::core::compile_error!("companions are not available for `uom_mass` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, uom_time) => {
+        ::core::compile_error!("companions are not available for `uom_time` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, uom_velocity) => {
+        ::core::compile_error!("companions are not available for `uom_velocity` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, uom_acceleration) => {
+        ::core::compile_error!("companions are not available for `uom_acceleration` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, uom_frequency) => {
+        ::core::compile_error!("companions are not available for `uom_frequency` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, of_f32) => {
+        ::core::compile_error!("companions are not available for `of_f32` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, of_f64) => {
+        ::core::compile_error!("companions are not available for `of_f64` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nn_f32) => {
+        ::core::compile_error!("companions are not available for `nn_f32` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, nn_f64) => {
+        ::core::compile_error!("companions are not available for `nn_f64` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ratio_i32) => {
+        ::core::compile_error!("companions are not available for `ratio_i32` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ratio_i64) => {
+        ::core::compile_error!("companions are not available for `ratio_i64` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ratio_i128) => {
+        ::core::compile_error!("companions are not available for `ratio_i128` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ratio_u32) => {
+        ::core::compile_error!("companions are not available for `ratio_u32` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ratio_u64) => {
+        ::core::compile_error!("companions are not available for `ratio_u64` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ratio_u128) => {
+        ::core::compile_error!("companions are not available for `ratio_u128` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ipv4) => {
+        ::core::compile_error!("companions are not available for `ipv4` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ipv6) => {
+        ::core::compile_error!("companions are not available for `ipv6` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, bigint) => {
+        ::core::compile_error!("companions are not available for `bigint` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, biguint) => {
+        ::core::compile_error!("companions are not available for `biguint` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, limbs) => {
+        ::core::compile_error!("companions are not available for `limbs` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, str) => {
+        ::core::compile_error!("companions are not available for `str` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ptr_u8) => {
+        ::core::compile_error!("companions are not available for `ptr_u8` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ptr_u16) => {
+        ::core::compile_error!("companions are not available for `ptr_u16` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ptr_u32) => {
+        ::core::compile_error!("companions are not available for `ptr_u32` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ptr_u64) => {
+        ::core::compile_error!("companions are not available for `ptr_u64` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ptr_usize) => {
+        ::core::compile_error!("companions are not available for `ptr_usize` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, u256) => {
+        ::core::compile_error!("companions are not available for `u256` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, i256) => {
+        ::core::compile_error!("companions are not available for `i256` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, le_bytes) => {
+        ::core::compile_error!("companions are not available for `le_bytes` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, be_bytes) => {
+        ::core::compile_error!("companions are not available for `be_bytes` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, ne_bytes) => {
+        ::core::compile_error!("companions are not available for `ne_bytes` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, smallest_uint) => {
+        ::core::compile_error!("companions are not available for `smallest_uint` variants")
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, smallest_int) => {
+        ::core::compile_error!("companions are not available for `smallest_int` variants")
+    };
+    (@NZ_CHECKED $op:ident $companion:ident $numeric_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!($crate::__nz_impl!(@ALSO_ERROR $op $companion $numeric_type, "is zero")),
+        }
+    };
+    (@NONMAX_CHECKED $op:ident $companion:ident $numeric_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!($crate::__nz_impl!(@ALSO_ERROR $op $companion $numeric_type, "is the maximum")),
+        }
+    };
+    (@BOUNDED_CHECKED $op:ident $companion:ident $numeric_type:ident, $value:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($value) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::panic!($crate::__nz_impl!(@ALSO_ERROR $op $companion $numeric_type, "is out of bounds")),
+        }
+    };
+    (@ALSO_VAL $op:ident $companion:ident $lit:literal, $numeric_type:ident) => {
+        $crate::__fixed_kind! { $numeric_type (@ALSO_FIXED) (@SIMD_KIND $numeric_type (@ALSO_SIMD) (@ALSO_INT $op $companion $lit, $numeric_type)) }
+    };
+    (@ALSO_FIXED $numeric_type:ident $info:tt) => {
+        ::core::compile_error!("companions are not available for fixed-point variants")
+    };
+    (@ALSO_SIMD $numeric_type:ident $info:tt) => {
+        ::core::compile_error!("companions are not available for SIMD variants")
+    };
+    (@ALSO_INT squared $companion:ident $lit:literal, $numeric_type:ident) => {{
+        let value: $crate::__nz_impl!(@GET_TYPE $numeric_type) = $lit;
+        match value.checked_mul(value) {
+            ::core::option::Option::Some(squared) => squared,
+            ::core::option::Option::None => ::core::panic!($crate::__nz_impl!(@ALSO_ERROR squared $companion $numeric_type, "overflows")),
+        }
+    }};
+    (@ALSO_INT halved $companion:ident $lit:literal, $numeric_type:ident) => {{
+        let value: $crate::__nz_impl!(@GET_TYPE $numeric_type) = $lit;
+        if value % 2 != 0 {
+            ::core::panic!($crate::__nz_impl!(@ALSO_ERROR halved $companion $numeric_type, "is inexact for an odd value"));
+        }
+        value / 2
+    }};
+
+    // Collect the name, variants, conditions and visibility of every constant of the invocation, then call back
+    (@COLLECT $callback:tt $found:tt [$($cfgs:tt)*] $deprecated:tt #[cfg $($cfg:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found [$($cfgs)* #[cfg $($cfg)*]] $deprecated $($t)*);
+    };
+    (@COLLECT $callback:tt $found:tt $cfgs:tt [$($deprecated:tt)*] #[deprecated $($attr:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found $cfgs [$($deprecated)* #[deprecated $($attr)*]] $($t)*);
+    };
+    (@COLLECT $callback:tt $found:tt $cfgs:tt $deprecated:tt #[$($attr:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found $cfgs $deprecated $($t)*);
+    };
+    (@COLLECT $callback:tt [$($found:tt)*] $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = pack($($pack:tt)*); $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback [$($found)* $name [$($numeric_type)*] $cfgs ($(pub $(($($v)+))?)?) $deprecated (expr)] [] [] $($t)*);
+    };
+    (@COLLECT $callback:tt [$($found:tt)*] $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = $lit:literal $unit:ident; $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback [$($found)* $name [$($numeric_type)*] $cfgs ($(pub $(($($v)+))?)?) $deprecated (expr)] [] [] $($t)*);
+    };
+    (@COLLECT $callback:tt [$($found:tt)*] $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = $lit:literal; $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback [$($found)* $name [$($numeric_type)*] $cfgs ($(pub $(($($v)+))?)?) $deprecated (lit $lit)] [] [] $($t)*);
+    };
+    (@COLLECT $callback:tt [$($found:tt)*] $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback [$($found)* $name [$($numeric_type)*] $cfgs ($(pub $(($($v)+))?)?) $deprecated (expr)] [] [] $($t)*);
+    };
+    // Statics are not constants, and are not collected
+    (@COLLECT $callback:tt $found:tt $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? static $name:ident : $( $numeric_type:ident )|* = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found [] [] $($t)*);
+    };
+    (@COLLECT $callback:tt $found:tt $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? const $name:ident = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found [] [] $($t)*);
+    };
+    (@COLLECT $callback:tt $found:tt $cfgs:tt $deprecated:tt #![$($option:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found $cfgs $deprecated $($t)*);
+    };
+    // The constants of a group are collected like the others, the attributes of the group only applying to its struct
+    (@COLLECT $callback:tt $found:tt $cfgs:tt $deprecated:tt $(pub $(($($v:tt)+))?)? group $group:ident { $($body:tt)* } $($t:tt)*) => {
+        $crate::__nz_impl!(@COLLECT $callback $found [] [] $($body)* $($t)*);
+    };
+    (@COLLECT ($($callback:tt)*) $found:tt [] []) => {
+        $crate::__nz_impl!($($callback)* $found);
+    };
+    // Resolve the aliases in the variants of the collected constants, then call back
+    (@ALIASED { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: [$($aliases:tt)*] version: $version:tt strict: $strict:tt customs: $customs:tt } $callback:tt $found:tt) => {
+        $crate::__nz_impl!(@ALIASED_EACH [
+            (nm_i8 nonmax_i8) (nm_i16 nonmax_i16) (nm_i32 nonmax_i32) (nm_i64 nonmax_i64) (nm_i128 nonmax_i128) (nm_isize nonmax_isize)
+            (nm_u8 nonmax_u8) (nm_u16 nonmax_u16) (nm_u32 nonmax_u32) (nm_u64 nonmax_u64) (nm_u128 nonmax_u128) (nm_usize nonmax_usize)
+            $($aliases)*
+        ] $callback [] $found);
+    };
+    (@ALIASED_EACH $aliases:tt $callback:tt $done:tt [$name:ident $types:tt $cfgs:tt $vis:tt $deprecated:tt $source:tt $($found:tt)*]) => {
+        $crate::__nz_impl!(@RESOLVE ($) $aliases (@ALIASED_NEXT $aliases $callback $done $name ($cfgs $vis $deprecated $source) [$($found)*]) $types);
+    };
+    (@ALIASED_NEXT $aliases:tt $callback:tt [$($done:tt)*] $name:ident ($($entry:tt)*) $found:tt $types:tt) => {
+        $crate::__nz_impl!(@ALIASED_EACH $aliases $callback [$($done)* $name $types $($entry)*] $found);
+    };
+    (@ALIASED_EACH $aliases:tt ($($callback:tt)*) $done:tt []) => {
+        $crate::__nz_impl!($($callback)* $done);
+    };
+
+    // Call back with the variants, each `nm_*` variant being replaced by the `nonmax_*` variant it abbreviates,
+    // and each alias registered by `#![alias(...)]` by the variant it names, through a local macro matching any of the aliases
+    (@ALIAS { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: [$($aliases:tt)*] version: $version:tt strict: $strict:tt customs: $customs:tt } $callback:tt $types:tt) => {
+        $crate::__nz_impl!(@RESOLVE ($) [
+            (nm_i8 nonmax_i8) (nm_i16 nonmax_i16) (nm_i32 nonmax_i32) (nm_i64 nonmax_i64) (nm_i128 nonmax_i128) (nm_isize nonmax_isize)
+            (nm_u8 nonmax_u8) (nm_u16 nonmax_u16) (nm_u32 nonmax_u32) (nm_u64 nonmax_u64) (nm_u128 nonmax_u128) (nm_usize nonmax_usize)
+            $($aliases)*
+        ] $callback $types);
+    };
+    (@RESOLVE ($d:tt) [] ($($callback:tt)*) $types:tt) => {
+        $crate::__nz_impl!($($callback)* $types);
+    };
+    // The callback is passed along rather than written in the local macro, which would split negative literals
+    (@RESOLVE ($d:tt) [$(($alias:ident $target:ident))+] $callback:tt [$($numeric_type:ident)*]) => {
+        macro_rules! __resolve {
+            $(($d callback:tt [$d($d resolved:ident)*] $alias $d($d rest:ident)*) => { __resolve!($d callback [$d($d resolved)* $target] $d($d rest)*); };)+
+            ($d callback:tt [$d($d resolved:ident)*] $d other:ident $d($d rest:ident)*) => { __resolve!($d callback [$d($d resolved)* $d other] $d($d rest)*); };
+            (($d($d callback:tt)*) [$d($d resolved:ident)*]) => { $crate::__nz_impl!($d($d callback)* [$d($d resolved)*]); };
+        }
+        __resolve!($callback [] $($numeric_type)*);
+    };
+
+    // Walk the block again, deriving the variants of `const NAME = ...;` from the constants declared before
+    (@DERIVE_EACH { derive: $derive:tt impls: $impls:tt lints: [$($lints:tt)*] aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } $known:tt $attrs:tt #![lints($($level:ident($($lint:path),* $(,)?)),* $(,)?)] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH { derive: $derive impls: $impls lints: [$($lints)* $(#[$level($($lint),*)])*] aliases: $aliases version: $version strict: $strict customs: $customs } $known $attrs $($t)*);
+    };
+    (@DERIVE_EACH { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: [$($aliases:tt)*] version: $version:tt strict: $strict:tt customs: $customs:tt } $known:tt $attrs:tt #![alias($($alias:ident = $target:ident),* $(,)?)] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH { derive: $derive impls: $impls lints: $lints aliases: [$($aliases)* $(($alias $target))*] version: $version strict: $strict customs: $customs } $known $attrs $($t)*);
+    };
+    (@DERIVE_EACH { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: [$($customs:tt)*] } $known:tt $attrs:tt #![custom($($field:ident = $($path:ident)::+),* $(,)?)] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH { derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: $strict customs: [$($customs)* $(($field [$($path)*]))*] } $known $attrs $($t)*);
+    };
+    (@DERIVE_EACH { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $old:tt strict: $strict:tt customs: $customs:tt } $known:tt $attrs:tt #![version($version:literal)] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH { derive: $derive impls: $impls lints: $lints aliases: $aliases version: [$version] strict: $strict customs: $customs } $known $attrs $($t)*);
+    };
+    (@DERIVE_EACH $config:tt $known:tt $attrs:tt #![$($option:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config $known $attrs $($t)*);
+    };
+    // Mark strict constants at the start of their attributes
+    (@DERIVE_EACH $config:tt $known:tt [$($attrs:tt)*] #[strict] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config $known [strict $($attrs)*] $($t)*);
+    };
+    (@DERIVE_EACH $config:tt $known:tt [$($attrs:tt)*] #[$($attr:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config $known [$($attrs)* #[$($attr)*]] $($t)*);
+    };
+    (@DERIVE_EACH $config:tt [$($known:tt)*] $attrs:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = pack($($pack:tt)*); $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config [$($known)* $name [$($numeric_type)*]] [] $($t)*);
+    };
+    (@DERIVE_EACH $config:tt [$($known:tt)*] $attrs:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = $lit:literal $unit:ident; $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config [$($known)* $name [$($numeric_type)*]] [] $($t)*);
+    };
+    (@DERIVE_EACH $config:tt [$($known:tt)*] $attrs:tt $(pub $(($($v:tt)+))?)? const $name:ident : $( $numeric_type:ident )|* = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config [$($known)* $name [$($numeric_type)*]] [] $($t)*);
+    };
+    (@DERIVE_EACH $config:tt $known:tt $attrs:tt $(pub $(($($v:tt)+))?)? const $name:ident = $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_OPERANDS ($) ($config $known $attrs ($(pub $(($($v)+))?)?) $name) $($t)*);
+        $crate::__nz_impl!(@DERIVE_NEXT $config $known $($t)*);
+    };
+    (@DERIVE_EACH $config:tt $known:tt $attrs:tt $(pub $(($($v:tt)+))?)? static $name:ident : $( $numeric_type:ident )|* = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config $known [] $($t)*);
+    };
+    (@DERIVE_EACH $config:tt $known:tt $attrs:tt $(pub $(($($v:tt)+))?)? group $group:ident { $($body:tt)* } $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config $known [] $($body)* $($t)*);
+    };
+    (@DERIVE_EACH $config:tt $known:tt $attrs:tt) => {};
+    (@DERIVE_NEXT $config:tt $known:tt $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@DERIVE_EACH $config $known [] $($t)*);
+    };
+    // Find the known constants used by the initializer, up to its `;`, through a local macro matching any of them,
+    // and replace them by a call to `__derive_operand!`, defined once the variant to compute with is known
+    (@DERIVE_OPERANDS ($d:tt) ($config:tt [$($known:ident $types:tt)*] $attrs:tt $vis:tt $name:ident) $($t:tt)*) => {
+        macro_rules! __derive_operands {
+            // Fields and methods of a constant are used as is
+            $(([$d($d operand:tt)*] [$d($d out:tt)*] $d stack:tt $known . $d($d t:tt)*) => {
+                __derive_operands!([$d($d operand)*] [$d($d out)* $known .] $d stack $d($d t)*);
+            };)*
+            $(([$d($d operand:tt)*] [$d($d out:tt)*] $d stack:tt $known $d($d t:tt)*) => {
+                __derive_operands!([$d($d operand)* $known $types] [$d($d out)* __derive_operand!($known)] $d stack $d($d t)*);
+            };)*
+            // Keep the groups on a stack while replacing their content
+            ($d operand:tt $d out:tt [$d($d stack:tt)*] ($d($d group:tt)*) $d($d t:tt)*) => {
+                __derive_operands!($d operand [] [(paren $d out [$d($d t)*]) $d($d stack)*] $d($d group)*);
+            };
+            ($d operand:tt $d out:tt [$d($d stack:tt)*] [$d($d group:tt)*] $d($d t:tt)*) => {
+                __derive_operands!($d operand [] [(bracket $d out [$d($d t)*]) $d($d stack)*] $d($d group)*);
+            };
+            ($d operand:tt $d out:tt [$d($d stack:tt)*] {$d($d group:tt)*} $d($d t:tt)*) => {
+                __derive_operands!($d operand [] [(brace $d out [$d($d t)*]) $d($d stack)*] $d($d group)*);
+            };
+            ($d operand:tt [$d($d group:tt)*] [(paren [$d($d out:tt)*] [$d($d t:tt)*]) $d($d stack:tt)*]) => {
+                __derive_operands!($d operand [$d($d out)* ($d($d group)*)] [$d($d stack)*] $d($d t)*);
+            };
+            ($d operand:tt [$d($d group:tt)*] [(bracket [$d($d out:tt)*] [$d($d t:tt)*]) $d($d stack:tt)*]) => {
+                __derive_operands!($d operand [$d($d out)* [$d($d group)*]] [$d($d stack)*] $d($d t)*);
+            };
+            ($d operand:tt [$d($d group:tt)*] [(brace [$d($d out:tt)*] [$d($d t:tt)*]) $d($d stack:tt)*]) => {
+                __derive_operands!($d operand [$d($d out)* {$d($d group)*}] [$d($d stack)*] $d($d t)*);
+            };
+            ($d operand:tt $d out:tt [] ; $d($d t:tt)*) => {
+                $crate::__nz_impl!(@DERIVE_TYPES ($config $attrs $vis $name) $d out $d operand);
+            };
+            ($d operand:tt [$d($d out:tt)*] $d stack:tt $d next:tt $d($d t:tt)*) => {
+                __derive_operands!($d operand [$d($d out)* $d next] $d stack $d($d t)*);
+            };
+        }
+        __derive_operands!([] [] [] $($t)*);
+    };
+    // Keep the variants common to every operand
+    (@DERIVE_TYPES ($config:tt $attrs:tt $vis:tt $name:ident) $init:tt []) => {
+        ::core::compile_error!(::core::concat!("`", ::core::stringify!($name), "` does not use any constant declared before it in the same block"));
+    };
+    // The variants of each operand are read with the aliases resolved
+    (@DERIVE_TYPES ($config:tt $($derived:tt)*) $init:tt [$operand:ident $types:tt $($operands:tt)*]) => {
+        $crate::__nz_impl!(@ALIAS $config (@DERIVE_COMMON ($config $($derived)*) $init [$($operands)*]) $types);
+    };
+    (@DERIVE_COMMON ($config:tt $($derived:tt)*) $init:tt [$operand:ident $types:tt $($operands:tt)*] $common:tt) => {
+        $crate::__nz_impl!(@ALIAS $config (@DERIVE_INTERSECT ($config $($derived)*) $init [$($operands)*] $common) $types);
+    };
+    (@DERIVE_INTERSECT $derived:tt $init:tt $operands:tt $common:tt $types:tt) => {
+        $crate::__nz_impl!(@INTERSECT (@DERIVE_COMMON $derived $init $operands) $common $types);
+    };
+    (@DERIVE_COMMON $derived:tt $init:tt [] $common:tt) => {
+        $crate::__nz_impl!(@INTERSECT (@DERIVE_SOURCE $derived $init $common) $common [f64]);
+    };
+    // Every derived constant is strict under a strict configuration
+    (@DERIVE_SOURCE ({ derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: [strict] customs: $customs:tt } [strict $($attrs:tt)*] $vis:tt $name:ident) $init:tt $common:tt $f64:tt) => {
+        $crate::__nz_impl!(@DERIVE_SOURCE ({ derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: [] customs: $customs } [strict $($attrs)*] $vis $name) $init $common $f64);
+    };
+    (@DERIVE_SOURCE ({ derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: [strict] customs: $customs:tt } [$($attrs:tt)*] $vis:tt $name:ident) $init:tt $common:tt $f64:tt) => {
+        $crate::__nz_impl!(@DERIVE_SOURCE ({ derive: $derive impls: $impls lints: $lints aliases: $aliases version: $version strict: [] customs: $customs } [strict $($attrs)*] $vis $name) $init $common $f64);
+    };
+    // Strict constants widen the operands to `i128` with `to_i128`, and compute with it,
+    // unless they have no integer variant in common
+    (@DERIVE_SOURCE ($config:tt [strict $($attrs:tt)*] $vis:tt $name:ident) $init:tt $common:tt $f64:tt) => {
+        $crate::__nz_impl!(@INTERSECT (@DERIVE_STRICT ($config [$($attrs)*] $vis $name) $init $common $f64) $common [
+            i128 nz_i128 nonmax_i128 bounded_i128 u128 nz_u128 nonmax_u128 bounded_u128
+            i64 u64 nz_i64 nz_u64 nonmax_i64 nonmax_u64 bounded_i64 bounded_u64 u40 u48 u56 c_longlong c_ulonglong
+            isize usize nz_isize nz_usize nonmax_isize nonmax_usize bounded_isize bounded_usize c_long c_ulong c_ssize_t c_size_t
+            i32 u32 nz_i32 nz_u32 nonmax_i32 nonmax_u32 bounded_i32 bounded_u32 u24 c_int c_uint
+            i16 u16 nz_i16 nz_u16 nonmax_i16 nonmax_u16 bounded_i16 bounded_u16 c_short c_ushort
+            i8 u8 nz_i8 nz_u8 nonmax_i8 nonmax_u8 bounded_i8 bounded_u8 c_char c_schar c_uchar
+        ]);
+    };
+    (@DERIVE_STRICT $derived:tt $init:tt $common:tt $f64:tt []) => {
+        $crate::__nz_impl!(@DERIVE_SOURCE $derived $init $common $f64);
+    };
+    (@DERIVE_STRICT $derived:tt $init:tt $common:tt $f64:tt [$($integers:ident)+]) => {
+        $crate::__nz_impl!(@DERIVE_STRICT_EMIT ($) $derived $init $common);
+    };
+    // Read the operands from `f64` if possible, as float variants are rounded, and from the first variant otherwise
+    (@DERIVE_SOURCE ($config:tt $attrs:tt $vis:tt $name:ident) $init:tt [] $f64:tt) => {
+        ::core::compile_error!(::core::concat!("the constants used by `", ::core::stringify!($name), "` have no variant in common"));
+    };
+    (@DERIVE_SOURCE $derived:tt $init:tt [$first:ident $($numeric_type:ident)*] [$($f64:ident)?]) => {
+        $crate::__nz_impl!(@DERIVE_EMIT ($) $derived $init [$first $($numeric_type)*] $($f64)? $first);
+    };
+    // Compute with the widest type of the same kind as the variants,
+    // then narrow to each variant like other computed initializers
+    (@DERIVE_EMIT ($d:tt) ($config:tt [$($attrs:tt)*] $vis:tt $name:ident) [$($init:tt)*] [$first:ident $($numeric_type:ident)*] $source:ident $($ignored:ident)?) => {
+        $crate::polymorphic_constant!(@ATTRS $config {} [] [] [] $($attrs)* $vis const $name : $first $(| $numeric_type)* = (@EXPR $name {
+            #[allow(unused_macros)]
+            macro_rules! __derive_operand {
+                ($d operand:ident) => {
+                    ($crate::__nz_impl!(@PLAIN $source, $d operand.$source) as $crate::__nz_impl!(@DERIVE_TYPE $source))
+                };
+            }
+            $($init)*
+        }););
+    };
+    // The widest variant of each operand is read, so saturated narrow variants are not used
+    (@DERIVE_STRICT_EMIT ($d:tt) ($config:tt [$($attrs:tt)*] $vis:tt $name:ident) [$($init:tt)*] [$first:ident $($numeric_type:ident)*]) => {
+        $crate::polymorphic_constant!(@ATTRS $config {} [] [] [] $($attrs)* $vis const $name : $first $(| $numeric_type)* = (@EXPR $name {
+            #[allow(unused_macros)]
+            macro_rules! __derive_operand {
+                ($d operand:ident) => { $d operand.to_i128() };
+            }
+            $($init)*
+        }););
+    };
+    (@DERIVE_TYPE f32          ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE f64          ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE real         ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE f16          ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE bf16         ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE c_f32        ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE c_f64        ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE uom_length) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE uom_mass) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE uom_time) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE uom_velocity) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE uom_acceleration) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE uom_frequency) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE of_f32       ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE of_f64       ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE nn_f32       ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE nn_f64       ) => { ::core::primitive::f64 };
+    (@DERIVE_TYPE f128         ) => { $crate::__nightly_impl!(@TYPE f128) };
+    (@DERIVE_TYPE u128         ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE nz_u128      ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE opt_nz_u128  ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE nonmax_u128  ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE bounded_u128 ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE ipv6         ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE duration     ) => { ::core::primitive::u128 };
+    (@DERIVE_TYPE $other:ident ) => { $crate::__fixed_kind! { $other (@DERIVE_FIXED) (@SIMD_KIND $other (@DERIVE_SIMD) (@DERIVE_OTHER)) } };
+    (@DERIVE_FIXED $numeric_type:ident $info:tt) => { ::core::primitive::f64 };
+    (@DERIVE_SIMD $numeric_type:ident ($lane:ident $lanes:literal)) => { $crate::__nz_impl!(@DERIVE_TYPE $lane) };
+    (@DERIVE_OTHER) => { ::core::primitive::i128 };
+
+    // Call back with the variants of the first list that are also in the second one, in order,
+    // through a local macro matching any variant of the second list
+    (@INTERSECT $callback:tt $types:tt $other:tt) => {
+        $crate::__nz_impl!(@INTERSECT_IN ($) $callback $types $other);
+    };
+    (@INTERSECT_IN ($d:tt) ($($callback:tt)*) [$($numeric_type:ident)*] [$($other:ident)*]) => {
+        macro_rules! __intersect {
+            $(([$d($d common:ident)*] $other $d($d rest:ident)*) => { __intersect!([$d($d common)* $other] $d($d rest)*); };)*
+            ([$d($d common:ident)*] $d skipped:ident $d($d rest:ident)*) => { __intersect!([$d($d common)*] $d($d rest)*); };
+            ([$d($d common:ident)*]) => { $crate::__nz_impl!($($callback)* [$d($d common)*]); };
+        }
+        __intersect!([] $($numeric_type)*);
+    };
+
+    // Generate the lookup function over the collected constants
+    (@LOOKUP ($($vis:tt)*) $lookup:ident [$($name:ident [$($numeric_type:ident)*] [$($cfg:tt)*] $constant_vis:tt $deprecated:tt $source:tt)*]) => {
+        /// Get the name and value of every variant of a constant from its name
+        #[allow(dead_code, deprecated)]
+        $($vis)* fn $lookup(name: &::core::primitive::str)
+            -> ::core::option::Option<&'static [(&'static ::core::primitive::str, $crate::ConstValue)]>
+        {
+            match name {
+                $($($cfg)* ::core::stringify!($name) => {
+                    const VARIANTS: &[(&::core::primitive::str, $crate::ConstValue)] = &[
+                        $((::core::stringify!($numeric_type), $crate::__nz_impl!(@CONST_VALUE $numeric_type, $name.$numeric_type)),)*
+                    ];
+                    ::core::option::Option::Some(VARIANTS)
+                })*
+                _ => ::core::option::Option::None,
+            }
+        }
+    };
+
+    // Register the descriptor of every collected constant, with `linkme`
+    (@REGISTER $slice:tt [$($name:ident $types:tt [$($cfg:tt)*] $constant_vis:tt $deprecated:tt $source:tt)*]) => {
+        $($($cfg)* $crate::__linkme_impl!($slice $name $types);)*
+    };
+
+    // Generate the module re-exporting every collected constant, without its type
+    (@PRELUDE ($($vis:tt)*) $prelude:ident [$($name:ident $types:tt $cfgs:tt $constant_vis:tt $deprecated:tt $source:tt)*]) => {
+        /// The constants of the invocation, without their types
+        #[allow(unused_imports)]
+        $($vis)* mod $prelude {
+            $($crate::__nz_impl!(@PRELUDE_CONST $constant_vis $name $cfgs $deprecated);)*
+        }
+    };
+    // Keep the visibility of the constant, relative to the module of the invocation
+    (@PRELUDE_CONST () $name:ident $cfgs:tt $deprecated:tt) => {
+        $crate::__nz_impl!(@PRELUDE_ITEM (pub (super)) $name $cfgs $deprecated);
+    };
+    (@PRELUDE_CONST (pub (self)) $name:ident $cfgs:tt $deprecated:tt) => {
+        $crate::__nz_impl!(@PRELUDE_ITEM (pub (super)) $name $cfgs $deprecated);
+    };
+    (@PRELUDE_CONST (pub (super)) $name:ident $cfgs:tt $deprecated:tt) => {
+        $crate::__nz_impl!(@PRELUDE_ITEM (pub (in super::super)) $name $cfgs $deprecated);
+    };
+    (@PRELUDE_CONST $vis:tt $name:ident $cfgs:tt $deprecated:tt) => {
+        $crate::__nz_impl!(@PRELUDE_ITEM $vis $name $cfgs $deprecated);
+    };
+    (@PRELUDE_ITEM ($($vis:tt)*) $name:ident [$($cfg:tt)*] [$($deprecated:tt)*]) => {
+        $($cfg)*
+        $($deprecated)*
+        #[allow(non_upper_case_globals, deprecated)]
+        #[doc = ::core::concat!("The constant `", ::core::stringify!($name), "`")]
+        $($vis)* const $name: super::$name = super::$name;
+    };
+
+    // Generate the function writing every collected constant as a table
+    (@DUMP ($($vis:tt)*) $dump:ident [$($name:ident [$($numeric_type:ident)*] [$($cfg:tt)*] $constant_vis:tt $deprecated:tt $source:tt)*]) => {
+        /// Write the name, variant and value of every constant as a table, one variant per line
+        #[allow(dead_code, deprecated)]
+        $($vis)* fn $dump<W: ::core::fmt::Write>(out: &mut W) -> ::core::fmt::Result {
+            const CONSTANTS: &[(&::core::primitive::str, &[(&::core::primitive::str, $crate::ConstValue)])] = &[
+                $($($cfg)* (::core::stringify!($name), &[
+                    $((::core::stringify!($numeric_type), $crate::__nz_impl!(@CONST_VALUE $numeric_type, $name.$numeric_type)),)*
+                ]),)*
+            ];
+
+            // Align the columns on the longest name and variant
+            let (mut name_width, mut variant_width) = ("constant".len(), "variant".len());
+            for (name, variants) in CONSTANTS {
+                name_width = name_width.max(name.len());
+                for (variant, _) in variants.iter() {
+                    variant_width = variant_width.max(variant.len());
+                }
+            }
+
+            ::core::writeln!(out, "{:<name_width$}  {:<variant_width$}  value", "constant", "variant")?;
+            for (name, variants) in CONSTANTS {
+                for (variant, value) in variants.iter() {
+                    ::core::writeln!(out, "{:<name_width$}  {:<variant_width$}  {}", name, variant, value)?;
+                }
+            }
+            ::core::result::Result::Ok(())
+        }
+    };
+
+    // Generate the function writing every collected constant along with the literal it was rounded from
+    (@REPORT ($($vis:tt)*) $report:ident [$($name:ident [$($numeric_type:ident)*] [$($cfg:tt)*] $constant_vis:tt $deprecated:tt $source:tt)*]) => {
+        /// Write the name, variant, value and rounding of every constant as a table, one variant per line
+        #[allow(dead_code, deprecated)]
+        $($vis)* fn $report<W: ::core::fmt::Write>(out: &mut W) -> ::core::fmt::Result {
+            type Variants = &'static [(&'static ::core::primitive::str, $crate::ConstValue)];
+            const CONSTANTS: &[(&::core::primitive::str, ::core::option::Option<&::core::primitive::str>, Variants)] = &[
+                $($($cfg)* (::core::stringify!($name), $crate::__nz_impl!(@REPORT_SOURCE $source), &[
+                    $((::core::stringify!($numeric_type), $crate::__nz_impl!(@CONST_VALUE $numeric_type, $name.$numeric_type)),)*
+                ]),)*
+            ];
+
+            // Align the columns on the longest name, variant and value
+            let (mut name_width, mut variant_width, mut value_width) = ("constant".len(), "variant".len(), "value".len());
+            for (name, _, variants) in CONSTANTS {
+                name_width = name_width.max(name.len());
+                for (variant, value) in variants.iter() {
+                    variant_width = variant_width.max(variant.len());
+                    value_width = value_width.max($crate::__display_width(value));
+                }
+            }
+
+            ::core::writeln!(out, "{:<name_width$}  {:<variant_width$}  {:<value_width$}  status", "constant", "variant", "value")?;
+            for (name, literal, variants) in CONSTANTS {
+                for (variant, value) in variants.iter() {
+                    match ($crate::__report_status(*literal, *value), literal) {
+                        (status @ ("rounded" | "saturated"), ::core::option::Option::Some(literal)) => {
+                            ::core::writeln!(out, "{:<name_width$}  {:<variant_width$}  {:<value_width$}  {} from {}", name, variant, value, status, literal)?
+                        }
+                        (status, _) => ::core::writeln!(out, "{:<name_width$}  {:<variant_width$}  {:<value_width$}  {}", name, variant, value, status)?,
+                    }
+                }
+            }
+            ::core::result::Result::Ok(())
+        }
+    };
+    (@REPORT_SOURCE (lit $lit:literal)) => {
+        ::core::option::Option::Some(::core::stringify!($lit))
+    };
+    (@REPORT_SOURCE (expr)) => {
+        ::core::option::Option::None
+    };
+
+    // Generate the group of every collected constant, one slice per variant
+    (@GROUP ($($vis:tt)*) $group:ident [$($numeric_type:ident)*] $found:tt) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug)]
+        #[doc = ::core::concat!("Every constant of the group `", ::core::stringify!($group), "`, for each variant")]
+        $($vis)* struct $group {
+            $(
+                #[doc = ::core::concat!("Every constant of `", ::core::stringify!($group), "` as `", ::core::stringify!($numeric_type), "`")]
+                $numeric_type: &'static [$crate::__nz_impl!(@GET_TYPE $numeric_type)],
+            )*
+        }
+
+        #[allow(non_upper_case_globals, deprecated)]
+        #[doc = ::core::concat!("Every constant of the group `", ::core::stringify!($group), "`, for each variant")]
+        $($vis)* const $group: $group = $group {
+            $($numeric_type: $crate::__nz_impl!(@GROUP_SLICE $numeric_type $found),)*
+        };
+    };
+    (@GROUP_SLICE $numeric_type:ident [$($name:ident $types:tt [$($cfg:tt)*] $constant_vis:tt $deprecated:tt $source:tt)*]) => {
+        &[$($($cfg)* $name.$numeric_type),*]
+    };
+
+    // Generate the struct of a group, with a field for each of its constants, under the conditions and visibility of the constant
+    (@NAMESPACE $group:tt $found:tt [$($cfgs:tt)*] #[cfg $($cfg:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@NAMESPACE $group $found [$($cfgs)* #[cfg $($cfg)*]] $($t)*);
+    };
+    (@NAMESPACE $group:tt $found:tt $cfgs:tt #[$($attr:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@NAMESPACE $group $found $cfgs $($t)*);
+    };
+    (@NAMESPACE $group:tt [$($found:tt)*] $cfgs:tt $(pub $(($($v:tt)+))?)? const $name:ident $(: $( $numeric_type:ident )|*)? = $lit:literal $unit:ident; $($t:tt)*) => {
+        $crate::__nz_impl!(@NAMESPACE $group [$($found)* ($cfgs ($(pub $(($($v)+))?)?) $name)] [] $($t)*);
+    };
+    (@NAMESPACE $group:tt [$($found:tt)*] $cfgs:tt $(pub $(($($v:tt)+))?)? const $name:ident $(: $( $numeric_type:ident )|*)? = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@NAMESPACE $group [$($found)* ($cfgs ($(pub $(($($v)+))?)?) $name)] [] $($t)*);
+    };
+    (@NAMESPACE $group:tt $found:tt $cfgs:tt $(pub $(($($v:tt)+))?)? static $name:ident : $( $numeric_type:ident )|* = $init:expr; $($t:tt)*) => {
+        $crate::__nz_impl!(@NAMESPACE $group $found [] $($t)*);
+    };
+    (@NAMESPACE $group:tt $found:tt $cfgs:tt #![$($option:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@NAMESPACE $group $found $cfgs $($t)*);
+    };
+    (@NAMESPACE ({ derive: [$($derive:path),*] impls: $impls:tt lints: [$($lints:tt)*] aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } [$($attrs:tt)*] ($($vis:tt)*) $group:ident) [$(([$($cfg:tt)*] ($($field_vis:tt)*) $name:ident))*] []) => {
+        #[allow(non_camel_case_types, non_snake_case, deprecated)]
+        $($lints)*
+        #[derive($($derive),*)]
+        $($attrs)*
+        $($vis)* struct $group {
+            $(
+                $($cfg)*
+                #[doc = ::core::concat!("The constant `", ::core::stringify!($name), "`")]
+                $($field_vis)* $name: $name,
+            )*
+        }
+
+        #[allow(non_upper_case_globals, deprecated)]
+        $($lints)*
+        $($attrs)*
+        $($vis)* const $group: $group = $group {
+            $($($cfg)* $name: $name,)*
+        };
+    };
+
+    // Generate the struct of a static and the static itself, documentation and conditions applying to both
+    (@STATIC $config:tt [$($docs:tt)*] $attrs:tt #[doc $($doc:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@STATIC $config [$($docs)* #[doc $($doc)*]] $attrs $($t)*);
+    };
+    (@STATIC $config:tt [$($docs:tt)*] $attrs:tt #[deprecated $($deprecated:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@STATIC $config [$($docs)* #[deprecated $($deprecated)*]] $attrs $($t)*);
+    };
+    (@STATIC $config:tt [$($docs:tt)*] $attrs:tt #[cfg $($cfg:tt)*] $($t:tt)*) => {
+        $crate::__nz_impl!(@STATIC $config [$($docs)* #[cfg $($cfg)*]] $attrs $($t)*);
+    };
+    (@STATIC $config:tt $docs:tt [$($attrs:tt)*] #[$attr:meta] $($t:tt)*) => {
+        $crate::__nz_impl!(@STATIC $config $docs [$($attrs)* #[$attr]] $($t)*);
+    };
+    // Atomics are neither `Clone` nor `Copy`, so only `Debug` is derived
+    (@STATIC { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: $version:tt strict: $strict:tt customs: $customs:tt } $docs:tt $attrs:tt $vis:tt $name:ident $types:tt $lit:tt) => {
+        $crate::__nz_impl!(@RESOLVE ($) $aliases (@STATIC_EMIT $lints $docs $attrs $vis $name $lit) $types);
+    };
+    (@STATIC_EMIT [$($lints:tt)*] [$($docs:tt)*] [$($attrs:tt)*] ($($vis:tt)*) $name:ident $lit:tt [$($numeric_type:ident)*]) => {
+        #[allow(non_camel_case_types, deprecated)]
+        $($lints)*
+        #[derive(::core::fmt::Debug)]
+        $($docs)*
+        $($attrs)*
+        $($vis)* struct $name {
+            $(
+                #[doc = ::core::concat!("The value of `", ::core::stringify!($name), "` as `", ::core::stringify!($numeric_type), "`")]
+                $numeric_type: $crate::__nz_impl!(@FIELD_TYPE $name $numeric_type),
+            )*
+        }
+
+        $crate::__nz_impl!(@LIMBS $name $lit [$($numeric_type)*]);
+
+        #[allow(non_upper_case_globals, deprecated)]
+        $($lints)*
+        $($docs)*
+        $($vis)* static $name: $name = $name {
+            $($numeric_type: $crate::__nz_impl!(@MAKE_VAL $lit, $numeric_type),)*
+        };
+    };
+
+    // Fail to compile if a constant has atomic variants, which require a `static`, or generate it
+    (@NOT_STATIC $name:ident [atomic_bool $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_bool); };
+    (@NOT_STATIC $name:ident [atomic_i8 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_i8); };
+    (@NOT_STATIC $name:ident [atomic_i16 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_i16); };
+    (@NOT_STATIC $name:ident [atomic_i32 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_i32); };
+    (@NOT_STATIC $name:ident [atomic_i64 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_i64); };
+    (@NOT_STATIC $name:ident [atomic_isize $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_isize); };
+    (@NOT_STATIC $name:ident [atomic_u8 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_u8); };
+    (@NOT_STATIC $name:ident [atomic_u16 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_u16); };
+    (@NOT_STATIC $name:ident [atomic_u32 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_u32); };
+    (@NOT_STATIC $name:ident [atomic_u64 $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_u64); };
+    (@NOT_STATIC $name:ident [atomic_usize $($rest:ident)*] $callback:tt) => { $crate::__nz_impl!(@NOT_STATIC_ERROR $name atomic_usize); };
+    (@NOT_STATIC $name:ident [$numeric_type:ident $($rest:ident)*] $callback:tt) => {
+        $crate::__nz_impl!(@NOT_STATIC $name [$($rest)*] $callback);
+    };
+    (@NOT_STATIC $name:ident [] ($($callback:tt)*)) => { $crate::__nz_impl!($($callback)*); };
+    (@NOT_STATIC_ERROR $name:ident $numeric_type:ident) => {
+        ::core::compile_error!(::core::concat!("`", ::core::stringify!($numeric_type), "` variants require a `static`, but `", ::core::stringify!($name), "` is a `const`"));
+    };
+
+    // Wrap a variant in a `ConstValue`, in const contexts
+    (@CONST_VALUE i8      , $value:expr) => { $crate::ConstValue::I8($value) };
+    (@CONST_VALUE i16     , $value:expr) => { $crate::ConstValue::I16($value) };
+    (@CONST_VALUE i32     , $value:expr) => { $crate::ConstValue::I32($value) };
+    (@CONST_VALUE i64     , $value:expr) => { $crate::ConstValue::I64($value) };
+    (@CONST_VALUE i128    , $value:expr) => { $crate::ConstValue::I128($value) };
+    (@CONST_VALUE isize   , $value:expr) => { $crate::ConstValue::Isize($value) };
+    (@CONST_VALUE u8      , $value:expr) => { $crate::ConstValue::U8($value) };
+    (@CONST_VALUE u16     , $value:expr) => { $crate::ConstValue::U16($value) };
+    (@CONST_VALUE u32     , $value:expr) => { $crate::ConstValue::U32($value) };
+    (@CONST_VALUE u64     , $value:expr) => { $crate::ConstValue::U64($value) };
+    (@CONST_VALUE u128    , $value:expr) => { $crate::ConstValue::U128($value) };
+    (@CONST_VALUE usize   , $value:expr) => { $crate::ConstValue::Usize($value) };
+    (@CONST_VALUE f32     , $value:expr) => { $crate::ConstValue::F32($value) };
+    (@CONST_VALUE f64     , $value:expr) => { $crate::ConstValue::F64($value) };
+    (@CONST_VALUE real    , $value:expr) => { $crate::ConstValue::from($value) };
+    // `f16` and `bf16` widen exactly to `f32`, while `f128` is rounded to `f64`
+    (@CONST_VALUE f16     , $value:expr) => { $crate::__f16_impl!((@CONST_VALUE) , $value) };
+    (@CONST_VALUE nightly_f16, $value:expr) => { $crate::ConstValue::F32($value as ::core::primitive::f32) };
+    (@CONST_VALUE half_f16, $value:expr) => { $crate::ConstValue::F32($value.to_f32_const()) };
+    (@CONST_VALUE bf16    , $value:expr) => { $crate::ConstValue::F32($value.to_f32_const()) };
+    (@CONST_VALUE f128    , $value:expr) => { $crate::ConstValue::F64($value as ::core::primitive::f64) };
+    (@CONST_VALUE nz_i8   , $value:expr) => { $crate::ConstValue::NonZeroI8($value) };
+    (@CONST_VALUE nz_i16  , $value:expr) => { $crate::ConstValue::NonZeroI16($value) };
+    (@CONST_VALUE nz_i32  , $value:expr) => { $crate::ConstValue::NonZeroI32($value) };
+    (@CONST_VALUE nz_i64  , $value:expr) => { $crate::ConstValue::NonZeroI64($value) };
+    (@CONST_VALUE nz_i128 , $value:expr) => { $crate::ConstValue::NonZeroI128($value) };
+    (@CONST_VALUE nz_isize, $value:expr) => { $crate::ConstValue::NonZeroIsize($value) };
+    (@CONST_VALUE nz_u8   , $value:expr) => { $crate::ConstValue::NonZeroU8($value) };
+    (@CONST_VALUE nz_u16  , $value:expr) => { $crate::ConstValue::NonZeroU16($value) };
+    (@CONST_VALUE nz_u32  , $value:expr) => { $crate::ConstValue::NonZeroU32($value) };
+    (@CONST_VALUE nz_u64  , $value:expr) => { $crate::ConstValue::NonZeroU64($value) };
+    (@CONST_VALUE nz_u128 , $value:expr) => { $crate::ConstValue::NonZeroU128($value) };
+    (@CONST_VALUE nz_usize, $value:expr) => { $crate::ConstValue::NonZeroUsize($value) };
+    // `None` is stored as zero
+    (@CONST_VALUE opt_nz_i8   , $value:expr) => { $crate::ConstValue::I8($crate::__nz_impl!(@PLAIN opt_nz_i8   , $value)) };
+    (@CONST_VALUE opt_nz_i16  , $value:expr) => { $crate::ConstValue::I16($crate::__nz_impl!(@PLAIN opt_nz_i16  , $value)) };
+    (@CONST_VALUE opt_nz_i32  , $value:expr) => { $crate::ConstValue::I32($crate::__nz_impl!(@PLAIN opt_nz_i32  , $value)) };
+    (@CONST_VALUE opt_nz_i64  , $value:expr) => { $crate::ConstValue::I64($crate::__nz_impl!(@PLAIN opt_nz_i64  , $value)) };
+    (@CONST_VALUE opt_nz_i128 , $value:expr) => { $crate::ConstValue::I128($crate::__nz_impl!(@PLAIN opt_nz_i128 , $value)) };
+    (@CONST_VALUE opt_nz_isize, $value:expr) => { $crate::ConstValue::Isize($crate::__nz_impl!(@PLAIN opt_nz_isize, $value)) };
+    (@CONST_VALUE opt_nz_u8   , $value:expr) => { $crate::ConstValue::U8($crate::__nz_impl!(@PLAIN opt_nz_u8   , $value)) };
+    (@CONST_VALUE opt_nz_u16  , $value:expr) => { $crate::ConstValue::U16($crate::__nz_impl!(@PLAIN opt_nz_u16  , $value)) };
+    (@CONST_VALUE opt_nz_u32  , $value:expr) => { $crate::ConstValue::U32($crate::__nz_impl!(@PLAIN opt_nz_u32  , $value)) };
+    (@CONST_VALUE opt_nz_u64  , $value:expr) => { $crate::ConstValue::U64($crate::__nz_impl!(@PLAIN opt_nz_u64  , $value)) };
+    (@CONST_VALUE opt_nz_u128 , $value:expr) => { $crate::ConstValue::U128($crate::__nz_impl!(@PLAIN opt_nz_u128 , $value)) };
+    (@CONST_VALUE opt_nz_usize, $value:expr) => { $crate::ConstValue::Usize($crate::__nz_impl!(@PLAIN opt_nz_usize, $value)) };
+    // Types without a maximum and bounded types hold the variant of their primitive
+    (@CONST_VALUE nonmax_i8    , $value:expr) => { $crate::ConstValue::I8($value.get()) };
+    (@CONST_VALUE nonmax_i16   , $value:expr) => { $crate::ConstValue::I16($value.get()) };
+    (@CONST_VALUE nonmax_i32   , $value:expr) => { $crate::ConstValue::I32($value.get()) };
+    (@CONST_VALUE nonmax_i64   , $value:expr) => { $crate::ConstValue::I64($value.get()) };
+    (@CONST_VALUE nonmax_i128  , $value:expr) => { $crate::ConstValue::I128($value.get()) };
+    (@CONST_VALUE nonmax_isize , $value:expr) => { $crate::ConstValue::Isize($value.get()) };
+    (@CONST_VALUE nonmax_u8    , $value:expr) => { $crate::ConstValue::U8($value.get()) };
+    (@CONST_VALUE nonmax_u16   , $value:expr) => { $crate::ConstValue::U16($value.get()) };
+    (@CONST_VALUE nonmax_u32   , $value:expr) => { $crate::ConstValue::U32($value.get()) };
+    (@CONST_VALUE nonmax_u64   , $value:expr) => { $crate::ConstValue::U64($value.get()) };
+    (@CONST_VALUE nonmax_u128  , $value:expr) => { $crate::ConstValue::U128($value.get()) };
+    (@CONST_VALUE nonmax_usize , $value:expr) => { $crate::ConstValue::Usize($value.get()) };
+    (@CONST_VALUE bounded_i8   , $value:expr) => { $crate::ConstValue::I8($value.get()) };
+    (@CONST_VALUE bounded_i16  , $value:expr) => { $crate::ConstValue::I16($value.get()) };
+    (@CONST_VALUE bounded_i32  , $value:expr) => { $crate::ConstValue::I32($value.get()) };
+    (@CONST_VALUE bounded_i64  , $value:expr) => { $crate::ConstValue::I64($value.get()) };
+    (@CONST_VALUE bounded_i128 , $value:expr) => { $crate::ConstValue::I128($value.get()) };
+    (@CONST_VALUE bounded_isize, $value:expr) => { $crate::ConstValue::Isize($value.get()) };
+    (@CONST_VALUE bounded_u8   , $value:expr) => { $crate::ConstValue::U8($value.get()) };
+    (@CONST_VALUE bounded_u16  , $value:expr) => { $crate::ConstValue::U16($value.get()) };
+    (@CONST_VALUE bounded_u32  , $value:expr) => { $crate::ConstValue::U32($value.get()) };
+    (@CONST_VALUE bounded_u64  , $value:expr) => { $crate::ConstValue::U64($value.get()) };
+    (@CONST_VALUE bounded_u128 , $value:expr) => { $crate::ConstValue::U128($value.get()) };
+    (@CONST_VALUE bounded_usize, $value:expr) => { $crate::ConstValue::Usize($value.get()) };
+    (@CONST_VALUE u24          , $value:expr) => { $crate::ConstValue::U32($value.value()) };
+    (@CONST_VALUE u40          , $value:expr) => { $crate::ConstValue::U64($value.value()) };
+    (@CONST_VALUE u48          , $value:expr) => { $crate::ConstValue::U64($value.value()) };
+    (@CONST_VALUE u56          , $value:expr) => { $crate::ConstValue::U64($value.value()) };
+    // The narrowest types hold the variant of the primitive selected for the value
+    (@CONST_VALUE bool, $value:expr) => { ::core::compile_error!("`bool` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE char, $value:expr) => { ::core::compile_error!("`char` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE duration, $value:expr) => { ::core::compile_error!("`duration` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE decimal, $value:expr) => { ::core::compile_error!("`decimal` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE c_f32, $value:expr) => { ::core::compile_error!("`c_f32` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE c_f64, $value:expr) => { ::core::compile_error!("`c_f64` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE uom_length, $value:expr) => { ::core::compile_error!("`uom_length` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE uom_mass, $value:expr) => { ::core::compile_error!("`uom_mass` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE uom_time, $value:expr) => { ::core::compile_error!("`uom_time` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE uom_velocity, $value:expr) => { ::core::compile_error!("`uom_velocity` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE uom_acceleration, $value:expr) => { ::core::compile_error!("`uom_acceleration` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE uom_frequency, $value:expr) => { ::core::compile_error!("`uom_frequency` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE of_f32, $value:expr) => { ::core::compile_error!("`of_f32` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE of_f64, $value:expr) => { ::core::compile_error!("`of_f64` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE nn_f32, $value:expr) => { ::core::compile_error!("`nn_f32` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE nn_f64, $value:expr) => { ::core::compile_error!("`nn_f64` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ratio_i32, $value:expr) => { ::core::compile_error!("`ratio_i32` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ratio_i64, $value:expr) => { ::core::compile_error!("`ratio_i64` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ratio_i128, $value:expr) => { ::core::compile_error!("`ratio_i128` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ratio_u32, $value:expr) => { ::core::compile_error!("`ratio_u32` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ratio_u64, $value:expr) => { ::core::compile_error!("`ratio_u64` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ratio_u128, $value:expr) => { ::core::compile_error!("`ratio_u128` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ipv4, $value:expr) => { ::core::compile_error!("`ipv4` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ipv6, $value:expr) => { ::core::compile_error!("`ipv6` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE bigint, $value:expr) => { ::core::compile_error!("`bigint` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE biguint, $value:expr) => { ::core::compile_error!("`biguint` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE limbs, $value:expr) => { ::core::compile_error!("`limbs` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE str, $value:expr) => { ::core::compile_error!("`str` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ptr_u8   , $value:expr) => { ::core::compile_error!("`ptr_u8` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ptr_u16  , $value:expr) => { ::core::compile_error!("`ptr_u16` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ptr_u32  , $value:expr) => { ::core::compile_error!("`ptr_u32` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ptr_u64  , $value:expr) => { ::core::compile_error!("`ptr_u64` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ptr_usize, $value:expr) => { ::core::compile_error!("`ptr_usize` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE u256, $value:expr) => { ::core::compile_error!("`u256` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE i256, $value:expr) => { ::core::compile_error!("`i256` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE le_bytes, $value:expr) => { ::core::compile_error!("`le_bytes` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE be_bytes, $value:expr) => { ::core::compile_error!("`be_bytes` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE ne_bytes, $value:expr) => { ::core::compile_error!("`ne_bytes` variants cannot be stored in a `ConstValue`") };
+    (@CONST_VALUE smallest_uint, $value:expr) => { $crate::ConstValue::__from_ffi($value as ::core::primitive::i128, ::core::mem::size_of_val(&$value), false) };
+    (@CONST_VALUE smallest_int , $value:expr) => { $crate::ConstValue::__from_ffi($value as ::core::primitive::i128, ::core::mem::size_of_val(&$value), true) };
+    // Fixed-point types of up to 32 bits are exactly held in `f64`
+    (@CONST_VALUE_FIXED ($value:expr) $numeric_type:ident $info:tt) => { $crate::ConstValue::F64($crate::__nz_impl!(@PLAIN_FIXED ($value) $numeric_type $info)) };
+    // C types hold the variant of the primitive they are an alias of
+    (@CONST_VALUE c_char     , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_char, $value) };
+    (@CONST_VALUE c_schar    , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_schar, $value) };
+    (@CONST_VALUE c_uchar    , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_uchar, $value) };
+    (@CONST_VALUE c_short    , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_short, $value) };
+    (@CONST_VALUE c_ushort   , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_ushort, $value) };
+    (@CONST_VALUE c_int      , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_int, $value) };
+    (@CONST_VALUE c_uint     , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_uint, $value) };
+    (@CONST_VALUE c_long     , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_long, $value) };
+    (@CONST_VALUE c_ulong    , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_ulong, $value) };
+    (@CONST_VALUE c_longlong , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_longlong, $value) };
+    (@CONST_VALUE c_ulonglong, $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_ulonglong, $value) };
+    (@CONST_VALUE c_ssize_t  , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_ssize_t, $value) };
+    (@CONST_VALUE c_size_t   , $value:expr) => { $crate::__nz_impl!(@CONST_VALUE_FFI c_size_t, $value) };
+    (@CONST_VALUE $numeric_type:ident, $value:expr) => {
+        $crate::__fixed_kind! { $numeric_type (@CONST_VALUE_FIXED ($value)) (@SIMD_KIND $numeric_type (@CONST_VALUE_SIMD) (@SORT_UNKNOWN $numeric_type)) }
+    };
+    (@CONST_VALUE_SIMD $numeric_type:ident $info:tt) => {
+        ::core::compile_error!("SIMD variants cannot be stored in a `ConstValue`")
+    };
+    (@CONST_VALUE_FFI $numeric_type:ident, $value:expr) => {
+        $crate::ConstValue::__from_ffi(
+            $value as ::core::primitive::i128,
+            ::core::mem::size_of::<$crate::__nz_impl!(@GET_TYPE $numeric_type)>(),
+            <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::MIN != 0,
+        )
+    };
+
+    // Read a number at an offset of a file, failing to compile if the file is too short
+    (@FROM_FILE $name:ident, $path:literal, $offset:expr, $format:ident $(,)?) => {{
+        let bytes: &[::core::primitive::u8] = ::core::include_bytes!($path);
+        let offset: ::core::primitive::usize = $offset;
+        $crate::__nz_impl!(@FILE_READ $name, bytes, offset, $format)
+    }};
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u8     ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u8  , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i8     ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i8  , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u16_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u16 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u16_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u16 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i16_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i16 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i16_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i16 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u32_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u32 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u32_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u32 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i32_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i32 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i32_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i32 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u64_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u64 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u64_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u64 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i64_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i64 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i64_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i64 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u128_le) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u128, from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, u128_be) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, u128, from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i128_le) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i128, from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, i128_be) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, i128, from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, f32_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, f32 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, f32_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, f32 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, f64_le ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, f64 , from_le_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, f64_be ) => { $crate::__nz_impl!(@FILE_BYTES $name, $bytes, $offset, f64 , from_be_bytes) };
+    (@FILE_READ $name:ident, $bytes:ident, $offset:ident, $format:ident) => {
+        ::core::compile_error!(::core::concat!("unknown format `", ::core::stringify!($format), "`, expected a type like `u8` or `u32_le`"))
+    };
+    (@FILE_BYTES $name:ident, $bytes:ident, $offset:ident, $numeric_type:ident, $from_bytes:ident) => {{
+        const SIZE: ::core::primitive::usize = ::core::mem::size_of::<::core::primitive::$numeric_type>();
+        if $offset + SIZE > $bytes.len() {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` is read past the end of its file"));
+        }
+        let mut array = [0; SIZE];
+        let mut i = 0;
+        while i < SIZE {
+            array[i] = $bytes[$offset + i];
+            i += 1;
+        }
+        <::core::primitive::$numeric_type>::$from_bytes(array)
+    }};
+
+    // Aggregate the elements of a const array, sums being compensated to keep the precision of f64
+    (@AGGREGATE sum($table:expr)) => { $crate::__nz_impl!(@SUM $table).0 };
+    // Integer means are rounded once from the exact sum
+    (@AGGREGATE mean($table:expr)) => {{
+        let (sum, len) = $crate::__nz_impl!(@SUM $table);
+        if len == 0 {
+            ::core::panic!("cannot compute the mean of an empty table");
+        }
+        match $crate::__element_kind(&$table) {
+            0 => sum as ::core::primitive::f64 / len as ::core::primitive::f64,
+            1 => $crate::__ratio_float((sum as ::core::primitive::i128) < 0, (sum as ::core::primitive::i128).unsigned_abs(), len as ::core::primitive::u128, 53, -1022),
+            _ => $crate::__ratio_float(false, sum as ::core::primitive::u128, len as ::core::primitive::u128, 53, -1022),
+        }
+    }};
+    (@AGGREGATE max_elem($table:expr)) => { $crate::__nz_impl!(@EXTREMUM $table, >) };
+    (@AGGREGATE min_elem($table:expr)) => { $crate::__nz_impl!(@EXTREMUM $table, <) };
+    // Measure the widest variant of another constant, as the number of decimal digits or significant bits of its magnitude
+    (@AGGREGATE digits($constant:expr)) => {{
+        let mut magnitude = $crate::__nz_impl!(@MAGNITUDE digits $constant);
+        let mut digits: ::core::primitive::u32 = 1;
+        while magnitude >= 10 {
+            magnitude /= 10;
+            digits += 1;
+        }
+        digits
+    }};
+    (@AGGREGATE bits($constant:expr)) => { 128 - $crate::__nz_impl!(@MAGNITUDE bits $constant).leading_zeros() };
+    (@AGGREGATE $function:ident($argument:expr)) => { $function($argument) };
+    (@MAGNITUDE $function:ident $constant:expr) => {{
+        let value = $constant.widest();
+        let negative = (value as ::core::primitive::f64) < 0.0;
+        let magnitude = if negative {
+            (value as ::core::primitive::i128).unsigned_abs()
+        } else {
+            value as ::core::primitive::u128
+        };
+        // Integers of any width round to the same float as their magnitude, unlike floats with a fraction
+        if magnitude as ::core::primitive::f64 != if negative { -(value as ::core::primitive::f64) } else { value as ::core::primitive::f64 } {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($function), "` requires a constant holding an integer"));
+        }
+        magnitude
+    }};
+    // Sum integer tables exactly in `i128` or `u128`, and float tables in `f64` with the compensation of Neumaier
+    (@SUM $table:expr) => {{
+        type Total = <$crate::__Accumulator<{ $crate::__element_kind(&$table) }> as $crate::__Pick>::Type;
+        let table = &$table;
+        let sum = match $crate::__element_kind(table) {
+            0 => {
+                let (mut sum, mut compensation, mut i) = (0.0, 0.0, 0);
+                while i < table.len() {
+                    let value = table[i] as ::core::primitive::f64;
+                    let total = sum + value;
+                    let (larger, smaller) = if (if sum < 0.0 { -sum } else { sum }) >= (if value < 0.0 { -value } else { value }) {
+                        (sum, value)
+                    } else {
+                        (value, sum)
+                    };
+                    compensation += (larger - total) + smaller;
+                    sum = total;
+                    i += 1;
+                }
+                (sum + compensation) as Total
+            }
+            1 => {
+                let (mut sum, mut i): (::core::primitive::i128, _) = (0, 0);
+                while i < table.len() {
+                    sum = match sum.checked_add(table[i] as ::core::primitive::i128) {
+                        ::core::option::Option::Some(sum) => sum,
+                        ::core::option::Option::None => ::core::panic!("the sum of the table overflows `i128`"),
+                    };
+                    i += 1;
+                }
+                sum as Total
+            }
+            _ => {
+                let (mut sum, mut i): (::core::primitive::u128, _) = (0, 0);
+                while i < table.len() {
+                    sum = match sum.checked_add(table[i] as ::core::primitive::u128) {
+                        ::core::option::Option::Some(sum) => sum,
+                        ::core::option::Option::None => ::core::panic!("the sum of the table overflows `u128`"),
+                    };
+                    i += 1;
+                }
+                sum as Total
+            }
+        };
+        (sum, table.len())
+    }};
+    (@EXTREMUM $table:expr, $op:tt) => {{
+        let table = &$table;
+        if table.is_empty() {
+            ::core::panic!("cannot find the extremum of an empty table");
+        }
+        let (mut extremum, mut i) = (table[0], 1);
+        while i < table.len() {
+            if table[i] $op extremum {
+                extremum = table[i];
+            }
+            i += 1;
+        }
+        extremum
+    }};
+
+    // Divide the widest variants of two constants, rounded once from their exact values for float variants,
+    // and exactly for the others, failing if there is a remainder
+    (@RATIO $name:ident f16 $numerator:ident $denominator:ident) => { $crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022) };
+    (@RATIO $name:ident bf16 $numerator:ident $denominator:ident) => { $crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022) };
+    (@RATIO $name:ident f32 $numerator:ident $denominator:ident) => { $crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022) };
+    (@RATIO $name:ident f64 $numerator:ident $denominator:ident) => { $crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022) };
+    (@RATIO $name:ident f128 $numerator:ident $denominator:ident) => { $crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022) };
+    (@RATIO $name:ident real $numerator:ident $denominator:ident) => { $crate::__nz_impl!(@RATIO_FLOAT $name $numerator $denominator, 53, -1022) };
+    // The quotient is computed on the magnitudes, in `i128` if negative and in `u128` otherwise
+    (@RATIO $name:ident $numeric_type:ident $numerator:ident $denominator:ident) => {{
+        let (numerator, denominator) = ($crate::__nz_impl!(@RATIO_OPERAND $numerator), $crate::__nz_impl!(@RATIO_OPERAND $denominator));
+        type Value = <$crate::__Quotient<{
+            let (numerator, denominator) = ($crate::__nz_impl!(@RATIO_OPERAND $numerator), $crate::__nz_impl!(@RATIO_OPERAND $denominator));
+            numerator.0 != denominator.0 && numerator.1 >= denominator.1
+        }> as $crate::__Pick>::Type;
+        if !numerator.2 || !denominator.2 {
+            ::core::panic!(::core::concat!(
+                "`", ::core::stringify!($name), "` is not an integer, as `", ::core::stringify!($numerator),
+                "` or `", ::core::stringify!($denominator), "` is not an integer"
+            ));
+        }
+        match numerator.1.checked_rem(denominator.1) {
+            ::core::option::Option::None => ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` divides by zero")),
+            ::core::option::Option::Some(0) => {}
+            ::core::option::Option::Some(_) => ::core::panic!(::core::concat!(
+                "`", ::core::stringify!($name), "` is not an integer, as `", ::core::stringify!($numerator),
+                "` is not a multiple of `", ::core::stringify!($denominator), "`"
+            )),
+        }
+        let quotient = numerator.1 / denominator.1;
+        if numerator.0 != denominator.0 && quotient != 0 {
+            if quotient > ::core::primitive::i128::MIN.unsigned_abs() {
+                ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` overflows `i128`"));
+            }
+            (quotient as ::core::primitive::i128).wrapping_neg() as Value
+        } else {
+            quotient as Value
+        }
+    }};
+    // Round the exact quotient to a float of `digits` significant bits, or divide in `f64` if an operand has a fraction
+    (@RATIO_FLOAT $name:ident $numerator:ident $denominator:ident, $digits:literal, $min_exp:literal) => {{
+        let (numerator, denominator) = ($crate::__nz_impl!(@RATIO_OPERAND $numerator), $crate::__nz_impl!(@RATIO_OPERAND $denominator));
+        if denominator.3 == 0.0 {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` divides by zero"));
+        }
+        if numerator.2 && denominator.2 {
+            $crate::__ratio_float(numerator.0 != denominator.0, numerator.1, denominator.1, $digits, $min_exp)
+        } else {
+            numerator.3 / denominator.3
+        }
+    }};
+    // The sign and magnitude of the widest variant of a constant, whether it is an integer, and its value in `f64`
+    (@RATIO_OPERAND $constant:ident) => {{
+        let value = $constant.widest();
+        let zero = if true { 0 as _ } else { value };
+        let negative = value < zero;
+        let magnitude = if negative { (value as ::core::primitive::i128).unsigned_abs() } else { value as ::core::primitive::u128 };
+        let float = value as ::core::primitive::f64;
+        let whole = if negative { -(magnitude as ::core::primitive::f64) } else { magnitude as ::core::primitive::f64 };
+        (negative, magnitude, float == whole, float)
+    }};
+
+    // Concatenate the fields from the most significant, checking that each fits in its width
+    (@PACK $($field:ident : $constant:expr, $width:literal);+ $(;)?) => {{
+        let mut packed: ::core::primitive::u128 = 0;
+        let mut total: ::core::primitive::u32 = 0;
+        $(
+            let value = $crate::__nz_impl!(@CHECKED $field, ($constant.widest()), u128);
+            total += $width;
+            if total > 128 {
+                ::core::panic!("packed fields are wider than 128 bits");
+            }
+            if $width < 128 && value >> $width != 0 {
+                ::core::panic!(::core::concat!("packed field `", ::core::stringify!($field), "` does not fit in ", ::core::stringify!($width), " bits"));
+            }
+            packed = match packed.checked_shl($width) {
+                ::core::option::Option::Some(shifted) => shifted,
+                ::core::option::Option::None => 0,
+            } | value;
+        )+
+        packed
+    }};
+
+    // Find the unit of the initializer, then the `units` options, and generate each conversion in turn
+    (@UNITS $config:tt $vis:tt $name:ident $types:tt $lit:tt, [(unit $from:ident) $($options:tt)*]) => {
+        $crate::__nz_impl!(@UNITS_FIND $from $config $vis $name $types $lit, [$($options)*]);
+    };
+    (@UNITS $config:tt $vis:tt $name:ident $types:tt $lit:tt, [(units $units:tt) $($options:tt)*]) => {
+        ::core::compile_error!("`units` conversions require an initializer with a unit, such as `= 2.5 m`");
+    };
+    (@UNITS $config:tt $vis:tt $name:ident $types:tt $lit:tt, [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@UNITS $config $vis $name $types $lit, [$($options)*]);
+    };
+    (@UNITS $config:tt $vis:tt $name:ident $types:tt $lit:tt, []) => {};
+    (@UNITS_FIND $from:ident $config:tt $vis:tt $name:ident $types:tt $lit:tt, [(units ($($to:ident = $companion:ident),* $(,)?)) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@UNITS_EACH $from $to $companion $config $vis $name $types $lit);)*
+        $crate::__nz_impl!(@UNITS_FIND $from $config $vis $name $types $lit, [$($options)*]);
+    };
+    (@UNITS_FIND $from:ident $config:tt $vis:tt $name:ident $types:tt $lit:tt, [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@UNITS_FIND $from $config $vis $name $types $lit, [$($options)*]);
+    };
+    (@UNITS_FIND $from:ident $config:tt $vis:tt $name:ident $types:tt $lit:tt, []) => {};
+    (@UNITS_EACH $from:ident $to:ident $companion:ident $config:tt $vis:tt $name:ident [$($numeric_type:ident)*] $lit:tt) => {
+        $crate::polymorphic_constant!(@EMIT $config [
+            #[doc = ::core::concat!("`", ::core::stringify!($name), "` in `", ::core::stringify!($to), "`")]
+        ] [] $vis $companion [$($numeric_type)*] {
+            $($numeric_type: $crate::__nz_impl!(@MAKE_VAL (@EXPR $companion $crate::__nz_impl!(@UNIT_VALUE $lit, $from, $to)), $numeric_type),)*
+        } []);
+        $crate::__nz_impl!(@LABEL_IMPL $vis $companion (::core::stringify!($to)));
+        $crate::__nz_impl!(@DURATION_EACH $companion (::core::stringify!($to)) [$($numeric_type)*]);
+    };
+
+    // Convert the exact value of the literal through integer scales, failing on conversions between different quantities
+    // Integer results are computed in `i128`, and the others rounded once to `f64`
+    (@UNIT_VALUE $lit:tt, $from:ident, $to:ident) => {{
+        let (from_quantity, from_scale): (&::core::primitive::str, ::core::primitive::u128) = $crate::__nz_impl!(@UNIT $from);
+        let (to_quantity, to_scale): (&::core::primitive::str, ::core::primitive::u128) = $crate::__nz_impl!(@UNIT $to);
+        if !$crate::__str_eq(from_quantity, to_quantity) {
+            ::core::panic!(::core::concat!("cannot convert `", ::core::stringify!($from), "` to `", ::core::stringify!($to), "`"));
+        }
+        type Value = <$crate::__Exact<{
+            $crate::__unit_ratio(::core::stringify!($lit), $crate::__nz_impl!(@UNIT $from).1, $crate::__nz_impl!(@UNIT $to).1).2 == 1
+        }> as $crate::__Pick>::Type;
+        let (negative, numerator, denominator) = $crate::__unit_ratio(::core::stringify!($lit), from_scale, to_scale);
+        if denominator == 1 {
+            if numerator > ::core::primitive::i128::MAX as ::core::primitive::u128 {
+                ::core::panic!(::core::concat!("`", ::core::stringify!($lit), " ", ::core::stringify!($from), "` overflows `i128` in `", ::core::stringify!($to), "`"));
+            }
+            (if negative { -(numerator as ::core::primitive::i128) } else { numerator as ::core::primitive::i128 }) as Value
+        } else {
+            $crate::__ratio_float(negative, numerator, denominator, 53, -1022) as Value
+        }
+    }};
+
+    // The quantity and scale of each unit, in micrometers, micrograms and nanoseconds
+    (@UNIT um  ) => { ("length", 1) };
+    (@UNIT mm  ) => { ("length", 1_000) };
+    (@UNIT cm  ) => { ("length", 10_000) };
+    (@UNIT m   ) => { ("length", 1_000_000) };
+    (@UNIT km  ) => { ("length", 1_000_000_000) };
+    (@UNIT inch) => { ("length", 25_400) };
+    (@UNIT ft  ) => { ("length", 304_800) };
+    (@UNIT yd  ) => { ("length", 914_400) };
+    (@UNIT mi  ) => { ("length", 1_609_344_000) };
+    (@UNIT ug  ) => { ("mass", 1) };
+    (@UNIT mg  ) => { ("mass", 1_000) };
+    (@UNIT g   ) => { ("mass", 1_000_000) };
+    (@UNIT kg  ) => { ("mass", 1_000_000_000) };
+    (@UNIT oz  ) => { ("mass", 28_349_523_125) };
+    (@UNIT lb  ) => { ("mass", 453_592_370_000) };
+    (@UNIT ns  ) => { ("time", 1) };
+    (@UNIT us  ) => { ("time", 1_000) };
+    (@UNIT ms  ) => { ("time", 1_000_000) };
+    (@UNIT s   ) => { ("time", 1_000_000_000) };
+    (@UNIT min ) => { ("time", 60_000_000_000) };
+    (@UNIT h   ) => { ("time", 3_600_000_000_000) };
+    (@UNIT $unit:ident) => {
+        ::core::compile_error!(::core::concat!("unknown unit `", ::core::stringify!($unit), "`"))
+    };
+
+    // Find the `ord` options, and compare with each listed constant in turn
+    (@ORD $name:ident $types:tt [(ord ($($other:ident),* $(,)?)) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@ORD_CHECK $name $other $types);)*
+        $($crate::__nz_impl!(@ORD_IMPL $name $other $types);)*
+        $($crate::__nz_impl!(@ORD_IMPL $other $name $types);)*
+        $crate::__nz_impl!(@ORD $name $types [$($options)*]);
+    };
+    (@ORD $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@ORD $name $types [$($options)*]);
+    };
+    (@ORD $name:ident $types:tt []) => {};
+    // Find the `narrow_from` options, and build the constant from the variants of each listed constant
+    (@NARROW $name:ident $types:tt [(narrow_from ($($wide:ident),* $(,)?)) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@NARROW_IMPL $name $wide $types);)*
+        $crate::__nz_impl!(@NARROW $name $types [$($options)*]);
+    };
+    (@NARROW $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@NARROW $name $types [$($options)*]);
+    };
+    (@NARROW $name:ident $types:tt []) => {};
+    // A variant missing from the wider constant, or of another type, fails to compile
+    (@NARROW_IMPL $name:ident $wide:ident [$($numeric_type:ident)*]) => {
+        #[allow(deprecated)]
+        impl $crate::NarrowFrom<$wide> for $name {
+            const NARROWED: Self = $name { $($numeric_type: $wide.$numeric_type),* };
+        }
+    };
+    // Find the `total_ord` option, ordering float constants by the `total_cmp` of their widest variant
+    (@TOTAL_ORD $name:ident [$($numeric_type:ident)*] [(total_ord) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@TOTAL_ORD_FLOAT $numeric_type);)*
+        $crate::__nz_impl!(@FIND (@TOTAL_ORD_IMPL $name) [$($numeric_type)*] [f64 real f32]);
+    };
+    (@TOTAL_ORD $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@TOTAL_ORD $name $types [$($options)*]);
+    };
+    (@TOTAL_ORD $name:ident $types:tt []) => {};
+    (@TOTAL_ORD_FLOAT f32) => {};
+    (@TOTAL_ORD_FLOAT f64) => {};
+    (@TOTAL_ORD_FLOAT real) => {};
+    (@TOTAL_ORD_FLOAT $numeric_type:ident) => {
+        ::core::compile_error!(::core::concat!("`total_ord` requires every variant to be a float, not `", ::core::stringify!($numeric_type), "`"));
+    };
+    // Every comparison goes through `total_cmp`, so that equality is consistent with the order
+    (@TOTAL_ORD_IMPL $name:ident $numeric_type:ident) => {
+        impl ::core::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> ::core::primitive::bool {
+                ::core::cmp::Ord::cmp(self, other) == ::core::cmp::Ordering::Equal
+            }
+        }
+
+        impl ::core::cmp::Eq for $name {}
+
+        impl ::core::cmp::PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::core::cmp::Ord for $name {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.$numeric_type.total_cmp(&other.$numeric_type)
+            }
+        }
+    };
+
+    // Check that every variant orders both constants like the first one
+    (@ORD_CHECK $name:ident $other:ident [$first:ident $($numeric_type:ident)*]) => {
+        #[allow(deprecated)]
+        const _: () = {
+            let expected = $crate::__nz_impl!(@ORD_CONST $name $other $first);
+            $(if $crate::__nz_impl!(@ORD_CONST $name $other $numeric_type) != expected {
+                ::core::panic!(::core::concat!(
+                    "the variants of `", ::core::stringify!($name), "` and `", ::core::stringify!($other), "` are not ordered the same way"
+                ));
+            })*
+        };
+    };
+    (@ORD_CONST $name:ident $other:ident $numeric_type:ident) => {{
+        let a = $crate::__nz_impl!(@PLAIN $numeric_type, $name.$numeric_type);
+        let b = $crate::__nz_impl!(@PLAIN $numeric_type, $other.$numeric_type);
+        if a < b { -1 } else if a > b { 1 } else if a == b { 0 } else { 2 }
+    }};
+    // The order being the same for every variant, compare the first one
+    (@ORD_IMPL $name:ident $other:ident [$first:ident $($numeric_type:ident)*]) => {
+        #[allow(deprecated)]
+        impl ::core::cmp::PartialEq<$other> for $name {
+            fn eq(&self, other: &$other) -> ::core::primitive::bool {
+                self.$first == other.$first
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::cmp::PartialOrd<$other> for $name {
+            fn partial_cmp(&self, other: &$other) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::cmp::PartialOrd::partial_cmp(&self.$first, &other.$first)
+            }
+        }
+    };
+    // Compute the size of the narrowest types holding the value
+    (@SMALLEST $name:ident $lit:tt [smallest_uint $($rest:ident)*]) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __SMALLEST_UINT: ::core::primitive::usize = $crate::__nz_impl!(@SMALLEST_BYTES $name, false, $crate::__nz_impl!(@SMALLEST_VALUE $lit));
+        }
+        $crate::__nz_impl!(@SMALLEST $name $lit [$($rest)*]);
+    };
+    (@SMALLEST $name:ident $lit:tt [smallest_int $($rest:ident)*]) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __SMALLEST_INT: ::core::primitive::usize = $crate::__nz_impl!(@SMALLEST_BYTES $name, true, $crate::__nz_impl!(@SMALLEST_VALUE $lit));
+        }
+        $crate::__nz_impl!(@SMALLEST $name $lit [$($rest)*]);
+    };
+    (@SMALLEST $name:ident $lit:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@SMALLEST $name $lit [$($rest)*]);
+    };
+    (@SMALLEST $name:ident $lit:tt []) => {};
+    (@LIMBS $name:ident $lit:tt [limbs $($rest:ident)*]) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __LIMBS: ::core::primitive::usize = $crate::__nz_impl!(@LIMB_COUNT $lit);
+        }
+    };
+    (@LIMBS $name:ident $lit:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@LIMBS $name $lit [$($rest)*]);
+    };
+    (@LIMBS $name:ident $lit:tt []) => {};
+    (@LIMB_COUNT (@EXPR $name:ident $init:expr)) => {
+        if $crate::__nz_impl!(@CHECKED $name, ($init), u128) >> 64 == 0 { 1 } else { 2 }
+    };
+    (@LIMB_COUNT $lit:literal) => { $crate::__limb_count(::core::stringify!($lit)) };
+    // Look for a byte variant, then for the type holding their value
+    (@BYTES $name:ident $lit:tt $types:tt $options:tt) => {
+        $crate::__nz_impl!(@FIND (@BYTES_FOUND $name $lit $types $options) $types [le_bytes be_bytes ne_bytes]);
+    };
+    (@BYTES_FOUND $name:ident $lit:tt $types:tt [(bytes $int:ident) $($options:tt)*] $found:ident) => {
+        $crate::__nz_impl!(@BYTES_VALUE $name $lit $int);
+    };
+    (@BYTES_FOUND $name:ident $lit:tt $types:tt [$other:tt $($options:tt)*] $found:ident) => {
+        $crate::__nz_impl!(@BYTES_FOUND $name $lit $types [$($options)*] $found);
+    };
+    // The byte variant found ends the candidates, so that it is called back if there is no integer variant
+    (@BYTES_FOUND $name:ident $lit:tt $types:tt [] $found:ident) => {
+        $crate::__nz_impl!(@FIND (@BYTES_VALUE $name $lit) $types [i128 u128 i64 u64 isize usize i32 u32 i16 u16 i8 u8 $found]);
+    };
+    (@BYTES_VALUE $name:ident $lit:tt le_bytes) => { $crate::__nz_impl!(@BYTES_MISSING le_bytes); };
+    (@BYTES_VALUE $name:ident $lit:tt be_bytes) => { $crate::__nz_impl!(@BYTES_MISSING be_bytes); };
+    (@BYTES_VALUE $name:ident $lit:tt ne_bytes) => { $crate::__nz_impl!(@BYTES_MISSING ne_bytes); };
+    (@BYTES_VALUE $name:ident $lit:tt $int:ident) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __BYTES: $crate::__nz_impl!(@GET_TYPE $int) = $crate::__nz_impl!(@MAKE_VAL $lit, $int);
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __BYTE_COUNT: ::core::primitive::usize = ::core::mem::size_of::<$crate::__nz_impl!(@GET_TYPE $int)>();
+        }
+    };
+    (@BYTES_MISSING $found:ident) => {
+        ::core::compile_error!(::core::concat!("`", ::core::stringify!($found), "` variants require a primitive integer variant, or `#[bytes(type)]`"));
+    };
+    (@SMALLEST_VALUE (@EXPR $name:ident $init:expr)) => { ($init) };
+    (@SMALLEST_VALUE $lit:literal) => {{
+        let value: ::core::primitive::i128 = $lit;
+        value
+    }};
+    (@SMALLEST_BYTES $name:ident, $signed:tt, $value:expr) => {{
+        let value = $value;
+        let wide = value as ::core::primitive::i128;
+        // Cast back to the type of the computed value, whatever it is
+        let back = if true { wide as _ } else { value };
+        if back != value {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` is not an integer"));
+        }
+        if !$signed && wide < 0 {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` is negative, and does not fit in `smallest_uint`"));
+        }
+        $crate::__smallest_bytes(wide, $signed)
+    }};
+
+    // Find the `warn_precision_loss` option, comparing the `f32` variants with the initializer in `f64`
+    (@PRECISION $name:ident [$($numeric_type:ident)*] $lit:tt [(warn_precision_loss) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@PRECISION_EACH $name $lit $numeric_type $numeric_type);)*
+    };
+    (@PRECISION $name:ident $types:tt $lit:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@PRECISION $name $types $lit [$($options)*]);
+    };
+    (@PRECISION $name:ident $types:tt $lit:tt []) => {};
+    // The warning comes from a deprecated function, selected by the comparison of the values,
+    // called with the span of the variant, and whose note tells how the variant rounds the initializer
+    // Only the `f32` variants, and the `real` and `f16` variants going through them, are checked
+    (@PRECISION_EACH $name:ident $lit:tt f32 $variant:ident) => { $crate::__nz_impl!(@PRECISION_CHECK $name $lit f32 $variant); };
+    (@PRECISION_EACH $name:ident $lit:tt real $variant:ident) => { $crate::__real_impl!((@PRECISION_EACH $name $lit) $variant); };
+    (@PRECISION_EACH $name:ident $lit:tt f16 $variant:ident) => { $crate::__f16_impl!((@PRECISION_EACH $name $lit) $variant); };
+    (@PRECISION_EACH $name:ident $lit:tt nightly_f16 $variant:ident) => { $crate::__nz_impl!(@PRECISION_CHECK $name $lit f16 $variant); };
+    (@PRECISION_EACH $name:ident $lit:tt $numeric_type:ident $variant:ident) => {};
+    (@PRECISION_CHECK $name:ident $lit:tt $float:ident $variant:ident) => {
+        const _: () = {
+            struct Precision<const LOSSY: ::core::primitive::bool>;
+            #[allow(dead_code)]
+            impl Precision<true> {
+                $crate::__lossy_variant!($variant $float $lit);
+            }
+            #[allow(dead_code)]
+            impl Precision<false> {
+                const fn $variant() {}
+            }
+            Precision::<{
+                #[allow(deprecated)]
+                let value = $name.$variant as ::core::primitive::f64;
+                value != $crate::__nz_impl!(@PRECISION_VALUE $lit)
+            }>::$variant()
+        };
+    };
+    (@PRECISION_VALUE (@EXPR $name:ident $init:expr)) => { ($init) as ::core::primitive::f64 };
+    (@PRECISION_VALUE $lit:literal) => {{
+        #[allow(clippy::excessive_precision)]
+        let value: ::core::primitive::f64 = $lit;
+        value
+    }};
+
+    // Find the `proof` option, stating the precondition of each checked constructor as a named constant,
+    // on the value given to the constructor, which is clamped first by `#[saturate]`
+    (@PROOF $name:ident $types:tt $lit:tt [(proof) $($options:tt)*] $all:tt) => {
+        $crate::__nz_impl!(@PROOF_SATURATE $name $types $lit $all);
+    };
+    (@PROOF $name:ident $types:tt $lit:tt [$other:tt $($options:tt)*] $all:tt) => {
+        $crate::__nz_impl!(@PROOF $name $types $lit [$($options)*] $all);
+    };
+    (@PROOF $name:ident $types:tt $lit:tt [] $all:tt) => {};
+    (@PROOF_SATURATE $name:ident [$($numeric_type:ident)*] $lit:tt [(saturate) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@PROOF_EACH $name $lit [saturate] $numeric_type);)*
+    };
+    (@PROOF_SATURATE $name:ident $types:tt $lit:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@PROOF_SATURATE $name $types $lit [$($options)*]);
+    };
+    (@PROOF_SATURATE $name:ident [$($numeric_type:ident)*] $lit:tt []) => {
+        $($crate::__nz_impl!(@PROOF_EACH $name $lit [] $numeric_type);)*
+    };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_i8   ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_i8, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, i8   ) $lit, i8   ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_i16  ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_i16, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, i16  ) $lit, i16  ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_i32  ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_i32, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, i32  ) $lit, i32  ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_i64  ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_i64, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, i64  ) $lit, i64  ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_i128 ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_i128, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, i128 ) $lit, i128 ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_isize) => { $crate::__nz_impl!(@PROOF_CONST $name nz_isize, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, isize) $lit, isize) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_u8   ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_u8, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, u8   ) $lit, u8   ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_u16  ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_u16, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, u16  ) $lit, u16  ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_u32  ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_u32, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, u32  ) $lit, u32  ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_u64  ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_u64, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, u64  ) $lit, u64  ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_u128 ) => { $crate::__nz_impl!(@PROOF_CONST $name nz_u128, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, u128 ) $lit, u128 ) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nz_usize) => { $crate::__nz_impl!(@PROOF_CONST $name nz_usize, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NZ $lit, usize) $lit, usize) != 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_i8   ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_i8, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, i8   ) $lit, i8   ) != ::core::primitive::i8::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_i16  ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_i16, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, i16  ) $lit, i16  ) != ::core::primitive::i16::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_i32  ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_i32, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, i32  ) $lit, i32  ) != ::core::primitive::i32::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_i64  ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_i64, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, i64  ) $lit, i64  ) != ::core::primitive::i64::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_i128 ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_i128, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, i128 ) $lit, i128 ) != ::core::primitive::i128::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_isize) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_isize, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, isize) $lit, isize) != ::core::primitive::isize::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_u8   ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_u8, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, u8   ) $lit, u8   ) != ::core::primitive::u8::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_u16  ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_u16, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, u16  ) $lit, u16  ) != ::core::primitive::u16::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_u32  ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_u32, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, u32  ) $lit, u32  ) != ::core::primitive::u32::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_u64  ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_u64, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, u64  ) $lit, u64  ) != ::core::primitive::u64::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_u128 ) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_u128, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, u128 ) $lit, u128 ) != ::core::primitive::u128::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nonmax_usize) => { $crate::__nz_impl!(@PROOF_CONST $name nonmax_usize, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_NONMAX $lit, usize) $lit, usize) != ::core::primitive::usize::MAX); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt u24) => { $crate::__nz_impl!(@PROOF_CONST $name u24, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_UINT $lit, u32, 24) $lit, u32) >> 24 == 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt u40) => { $crate::__nz_impl!(@PROOF_CONST $name u40, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_UINT $lit, u64, 40) $lit, u64) >> 40 == 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt u48) => { $crate::__nz_impl!(@PROOF_CONST $name u48, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_UINT $lit, u64, 48) $lit, u64) >> 48 == 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt u56) => { $crate::__nz_impl!(@PROOF_CONST $name u56, $crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_UINT $lit, u64, 56) $lit, u64) >> 56 == 0); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nn_f32) => { $crate::__nz_impl!(@PROOF_CONST $name nn_f32, !::core::primitive::f32::is_nan($crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_FLOAT nearest $lit, f32) $lit, f32))); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt nn_f64) => { $crate::__nz_impl!(@PROOF_CONST $name nn_f64, !::core::primitive::f64::is_nan($crate::__nz_impl!(@PROOF_VALUE $saturate (@SATURATE_FLOAT nearest $lit, f64) $lit, f64))); };
+    (@PROOF_EACH $name:ident $lit:tt $saturate:tt $numeric_type:ident) => {};
+    (@PROOF_VALUE [] $saturated:tt $lit:tt, $plain:ident) => { $crate::__nz_impl!(@MAKE_VAL $lit, $plain) };
+    (@PROOF_VALUE [saturate] ($($saturated:tt)*) $lit:tt, $plain:ident) => { $crate::__nz_impl!($($saturated)*) };
+    (@PROOF_CONST $name:ident $variant:ident, $proof:expr) => {
+        $crate::__paste! {
+            #[allow(non_upper_case_globals)]
+            const [<_PROOF_ $name _ $variant>]: () = ::core::assert!(
+                $proof,
+                ::core::concat!("the precondition of `", ::core::stringify!($name), ".", ::core::stringify!($variant), "` does not hold")
+            );
+        }
+    };
+
+    // Find the `reference_test` option, generating a test comparing every listed variant
+    // with the initializer computed at runtime in `f64`, within a relative tolerance
+    (@REFERENCE_TEST $name:ident $lit:tt [(reference_test $test:ident [$($tolerance:literal)?]) $($options:tt)*]) => {
+        #[cfg(test)]
+        #[test]
+        #[allow(deprecated)]
+        fn $test() {
+            let tolerance: ::core::primitive::f64 = $crate::__nz_impl!(@REFERENCE_TOLERANCE $($tolerance)?);
+            let reference = $crate::__nz_impl!(@PRECISION_VALUE $lit);
+            for (variant, value) in $name {
+                // Saturating casts always give a value
+                let value: ::core::primitive::f64 = value.cast($crate::CastMode::Saturating).unwrap();
+                ::core::assert!(
+                    (value - reference).abs() <= tolerance * reference.abs(),
+                    "`{}` is {} as `{}`, expected {} within a relative tolerance of {}",
+                    ::core::stringify!($name), value, variant, reference, tolerance,
+                );
+            }
+        }
+    };
+    (@REFERENCE_TEST $name:ident $lit:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@REFERENCE_TEST $name $lit [$($options)*]);
+    };
+    (@REFERENCE_TEST $name:ident $lit:tt []) => {};
+    (@REFERENCE_TOLERANCE $tolerance:literal) => { $tolerance };
+    (@REFERENCE_TOLERANCE) => { 0.0 };
+
+    // Find the `bounds` option, required by bounded variants, and give them to the constant and its companions
+    (@BOUNDS $name:ident $types:tt [(bounds $min:literal $max:literal) $($options:tt)*] $all:tt) => {
+        $crate::__nz_impl!(@BOUNDS_EACH ($min, $max) [$name] $all);
+    };
+    (@BOUNDS $name:ident $types:tt [$other:tt $($options:tt)*] $all:tt) => {
+        $crate::__nz_impl!(@BOUNDS $name $types [$($options)*] $all);
+    };
+    (@BOUNDS $name:ident [$($numeric_type:ident)*] [] $all:tt) => {
+        $($crate::__nz_impl!(@UNBOUNDED $numeric_type);)*
+    };
+    (@BOUNDS_EACH $bounds:tt [$name:ident $($names:ident)*] [(also ($($op:ident $(= $companion:ident)?),* $(,)?)) $($options:tt)*]) => {
+        $($crate::__nz_impl!(@ALSO_NAME (@BOUNDS_COMPANION $bounds) $name $op $($companion)?);)*
+        $crate::__nz_impl!(@BOUNDS_EACH $bounds [$name $($names)*] [$($options)*]);
+    };
+    (@BOUNDS_EACH $bounds:tt [$($names:ident)*] [(units ($($to:ident = $companion:ident),* $(,)?)) $($options:tt)*]) => {
+        $crate::__nz_impl!(@BOUNDS_EACH $bounds [$($names)* $($companion)*] [$($options)*]);
+    };
+    (@BOUNDS_EACH $bounds:tt $names:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@BOUNDS_EACH $bounds $names [$($options)*]);
+    };
+    (@BOUNDS_COMPANION $bounds:tt $companion:ident) => {
+        $crate::__nz_impl!(@BOUNDS_EACH $bounds [$companion] []);
+    };
+    (@BOUNDS_EACH $bounds:tt [$($name:ident)*] []) => {
+        $(
+            #[allow(deprecated)]
+            impl $name {
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                pub const __BOUNDS: (::core::primitive::i128, ::core::primitive::i128) = $bounds;
+            }
+        )*
+    };
+    // Keep a deprecated alias under the previous name of a renamed constant
+    (@MIGRATION $config:tt ($($vis:tt)*) $name:ident [(renamed_from $old:ident $since:literal) $($options:tt)*]) => {
+        #[doc = ::core::concat!("Renamed to [`", ::core::stringify!($name), "`] in version ", ::core::stringify!($since))]
+        #[deprecated(note = "this constant is renamed, and will be deleted in the next version")]
+        #[allow(non_camel_case_types, deprecated)]
+        $($vis)* type $old = $name;
+
+        #[doc = ::core::concat!("Renamed to [`", ::core::stringify!($name), "`] in version ", ::core::stringify!($since))]
+        #[deprecated(note = "this constant is renamed, and will be deleted in the next version")]
+        #[allow(non_upper_case_globals, deprecated)]
+        $($vis)* const $old: $name = $name;
+
+        $crate::__nz_impl!(@MIGRATION_CHECK $config $old "renamed" $since);
+        $crate::__nz_impl!(@MIGRATION $config ($($vis)*) $name [$($options)*]);
+    };
+    (@MIGRATION $config:tt $vis:tt $name:ident [(removed $since:literal) $($options:tt)*]) => {
+        $crate::__nz_impl!(@MIGRATION_CHECK $config $name "removed" $since);
+        $crate::__nz_impl!(@MIGRATION $config $vis $name [$($options)*]);
+    };
+    (@MIGRATION $config:tt $vis:tt $name:ident [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@MIGRATION $config $vis $name [$($options)*]);
+    };
+    (@MIGRATION $config:tt $vis:tt $name:ident []) => {};
+    // The previous name resolves during the version of the migration and the next one, then fails to compile
+    (@MIGRATION_CHECK { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: [] strict: $strict:tt customs: $customs:tt } $name:ident $what:literal $since:literal) => {
+        ::core::compile_error!("renamed and removed constants require the version of their set, given by `#![version(...)]`");
+    };
+    (@MIGRATION_CHECK { derive: $derive:tt impls: $impls:tt lints: $lints:tt aliases: $aliases:tt version: [$version:literal] strict: $strict:tt customs: $customs:tt } $name:ident $what:literal $since:literal) => {
+        const _: () = {
+            if $since > $version {
+                ::core::panic!(::core::concat!(
+                    "`", ::core::stringify!($name), "` is ", $what, " in version ", ::core::stringify!($since), ", after the current version ", ::core::stringify!($version)
+                ));
+            }
+            if $version > $since + 1 {
+                ::core::panic!(::core::concat!(
+                    "`", ::core::stringify!($name), "` was ", $what, " in version ", ::core::stringify!($since), ", and must be deleted from version ", ::core::stringify!($version)
+                ));
+            }
+        };
+    };
+    // Find the `zero_is_none` option, read by the optional non-zero variants of the constant
+    (@ZERO_IS_NONE $name:ident $types:tt [(zero_is_none) $($options:tt)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EACH $name true $types);
+    };
+    (@ZERO_IS_NONE $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE $name $types [$($options)*]);
+    };
+    (@ZERO_IS_NONE $name:ident $types:tt []) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EACH $name false $types);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_i8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_i16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_i32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_i64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_i128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_isize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_u128 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [opt_nz_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EMIT $name $none);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@ZERO_IS_NONE_EACH $name $none [$($rest)*]);
+    };
+    (@ZERO_IS_NONE_EACH $name:ident $none:tt []) => {};
+    (@ZERO_IS_NONE_EMIT $name:ident $none:tt) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __ZERO_IS_NONE: ::core::primitive::bool = $none;
+        }
+    };
+    // Find the `const_ptr` option, read by the field types of the pointer variants of the constant
+    (@POINTER $name:ident $types:tt [(const_ptr) $($options:tt)*]) => {
+        $crate::__nz_impl!(@POINTER_EACH $name true $types);
+    };
+    (@POINTER $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@POINTER $name $types [$($options)*]);
+    };
+    (@POINTER $name:ident $types:tt []) => {
+        $crate::__nz_impl!(@POINTER_EACH $name false $types);
+    };
+    (@POINTER_EACH $name:ident $const:tt [ptr_u8 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@POINTER_EMIT $name $const);
+    };
+    (@POINTER_EACH $name:ident $const:tt [ptr_u16 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@POINTER_EMIT $name $const);
+    };
+    (@POINTER_EACH $name:ident $const:tt [ptr_u32 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@POINTER_EMIT $name $const);
+    };
+    (@POINTER_EACH $name:ident $const:tt [ptr_u64 $($rest:ident)*]) => {
+        $crate::__nz_impl!(@POINTER_EMIT $name $const);
+    };
+    (@POINTER_EACH $name:ident $const:tt [ptr_usize $($rest:ident)*]) => {
+        $crate::__nz_impl!(@POINTER_EMIT $name $const);
+    };
+    (@POINTER_EACH $name:ident $const:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@POINTER_EACH $name $const [$($rest)*]);
+    };
+    (@POINTER_EACH $name:ident $const:tt []) => {};
+    (@POINTER_EMIT $name:ident $const:tt) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __CONST_PTR: ::core::primitive::bool = $const;
+        }
+    };
+    // Find the `uom_unit` option, read by the quantity variants of the constant
+    (@UOM_UNIT $name:ident $types:tt [(uom_unit $unit:ident) $($options:tt)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EACH $name (::core::stringify!($unit)) $types);
+    };
+    (@UOM_UNIT $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT $name $types [$($options)*]);
+    };
+    (@UOM_UNIT $name:ident $types:tt []) => {
+        $crate::__nz_impl!(@UOM_UNIT_EACH $name ("") $types);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [uom_length $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EMIT $name $unit);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [uom_mass $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EMIT $name $unit);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [uom_time $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EMIT $name $unit);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [uom_velocity $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EMIT $name $unit);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [uom_acceleration $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EMIT $name $unit);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [uom_frequency $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EMIT $name $unit);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@UOM_UNIT_EACH $name $unit [$($rest)*]);
+    };
+    (@UOM_UNIT_EACH $name:ident $unit:tt []) => {};
+    (@UOM_UNIT_EMIT $name:ident ($unit:expr)) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __UOM_UNIT: &'static ::core::primitive::str = $unit;
+        }
+    };
+    // Find the unit of duration variants, the `unit` attribute taking precedence over the unit of the initializer
+    (@DURATION_UNIT $name:ident $types:tt [(label $unit:literal) $($options:tt)*]) => {
+        $crate::__nz_impl!(@DURATION_EACH $name ($unit) $types);
+    };
+    (@DURATION_UNIT $name:ident $types:tt [(unit $unit:ident) $($options:tt)*]) => {
+        $crate::__nz_impl!(@DURATION_UNIT_OR $name (::core::stringify!($unit)) $types [$($options)*]);
+    };
+    (@DURATION_UNIT $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@DURATION_UNIT $name $types [$($options)*]);
+    };
+    (@DURATION_UNIT $name:ident $types:tt []) => {
+        $crate::__nz_impl!(@DURATION_EACH $name () $types);
+    };
+    (@DURATION_UNIT_OR $name:ident $fallback:tt $types:tt [(label $unit:literal) $($options:tt)*]) => {
+        $crate::__nz_impl!(@DURATION_EACH $name ($unit) $types);
+    };
+    (@DURATION_UNIT_OR $name:ident $fallback:tt $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@DURATION_UNIT_OR $name $fallback $types [$($options)*]);
+    };
+    (@DURATION_UNIT_OR $name:ident $fallback:tt $types:tt []) => {
+        $crate::__nz_impl!(@DURATION_EACH $name $fallback $types);
+    };
+    (@DURATION_EACH $name:ident () [duration $($rest:ident)*]) => {
+        ::core::compile_error!("`duration` variants require a unit, such as `#[unit(\"ms\")]` or `= 250 ms`");
+    };
+    (@DURATION_EACH $name:ident ($unit:expr) [duration $($rest:ident)*]) => {
+        #[allow(deprecated)]
+        impl $name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub const __DURATION_NANOS: ::core::primitive::u64 = $crate::__duration_nanos($unit);
+        }
+    };
+    (@DURATION_EACH $name:ident $unit:tt [$other:ident $($rest:ident)*]) => {
+        $crate::__nz_impl!(@DURATION_EACH $name $unit [$($rest)*]);
+    };
+    (@DURATION_EACH $name:ident $unit:tt []) => {};
+    // Scale the count of a duration variant by its unit, failing beyond the range of `Duration`
+    (@DURATION $name:ident, $count:expr) => {{
+        let nanos = $count as ::core::primitive::u128 * $name::__DURATION_NANOS as ::core::primitive::u128;
+        if nanos / 1_000_000_000 > ::core::primitive::u64::MAX as ::core::primitive::u128 {
+            ::core::panic!(::core::concat!("`", ::core::stringify!($name), "` does not fit in `duration`"));
+        }
+        ::core::time::Duration::new((nanos / 1_000_000_000) as ::core::primitive::u64, (nanos % 1_000_000_000) as ::core::primitive::u32)
+    }};
+    (@UNBOUNDED bounded_i8   ) => { ::core::compile_error!("`bounded_i8` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_i16  ) => { ::core::compile_error!("`bounded_i16` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_i32  ) => { ::core::compile_error!("`bounded_i32` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_i64  ) => { ::core::compile_error!("`bounded_i64` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_i128 ) => { ::core::compile_error!("`bounded_i128` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_isize) => { ::core::compile_error!("`bounded_isize` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_u8   ) => { ::core::compile_error!("`bounded_u8` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_u16  ) => { ::core::compile_error!("`bounded_u16` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_u32  ) => { ::core::compile_error!("`bounded_u32` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_u64  ) => { ::core::compile_error!("`bounded_u64` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_u128 ) => { ::core::compile_error!("`bounded_u128` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED bounded_usize) => { ::core::compile_error!("`bounded_usize` variants require `#[bounds(min, max)]`"); };
+    (@UNBOUNDED $numeric_type:ident) => {};
+
+    // Get the primitive value of a variant, to compare it in const contexts
+    (@PLAIN nz_i8   , $value:expr) => { $value.get() };
+    (@PLAIN nz_i16  , $value:expr) => { $value.get() };
+    (@PLAIN nz_i32  , $value:expr) => { $value.get() };
+    (@PLAIN nz_i64  , $value:expr) => { $value.get() };
+    (@PLAIN nz_i128 , $value:expr) => { $value.get() };
+    (@PLAIN nz_isize, $value:expr) => { $value.get() };
+    (@PLAIN nz_u8   , $value:expr) => { $value.get() };
+    (@PLAIN nz_u16  , $value:expr) => { $value.get() };
+    (@PLAIN nz_u32  , $value:expr) => { $value.get() };
+    (@PLAIN nz_u64  , $value:expr) => { $value.get() };
+    (@PLAIN nz_u128 , $value:expr) => { $value.get() };
+    (@PLAIN nz_usize, $value:expr) => { $value.get() };
+    (@PLAIN opt_nz_i8   , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_i16  , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_i32  , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_i64  , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_i128 , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_isize, $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_u8   , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_u16  , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_u32  , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_u64  , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_u128 , $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN opt_nz_usize, $value:expr) => { $crate::__nz_impl!(@PLAIN_OPT_NZ $value) };
+    (@PLAIN_OPT_NZ $value:expr) => {
+        match $value {
+            ::core::option::Option::Some(value) => value.get(),
+            ::core::option::Option::None => 0,
+        }
+    };
+    (@PLAIN nonmax_i8   , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_i16  , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_i32  , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_i64  , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_i128 , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_isize, $value:expr) => { $value.get() };
+    (@PLAIN nonmax_u8   , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_u16  , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_u32  , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_u64  , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_u128 , $value:expr) => { $value.get() };
+    (@PLAIN nonmax_usize, $value:expr) => { $value.get() };
+    (@PLAIN bounded_i8   , $value:expr) => { $value.get() };
+    (@PLAIN bounded_i16  , $value:expr) => { $value.get() };
+    (@PLAIN bounded_i32  , $value:expr) => { $value.get() };
+    (@PLAIN bounded_i64  , $value:expr) => { $value.get() };
+    (@PLAIN bounded_i128 , $value:expr) => { $value.get() };
+    (@PLAIN bounded_isize, $value:expr) => { $value.get() };
+    (@PLAIN bounded_u8   , $value:expr) => { $value.get() };
+    (@PLAIN bounded_u16  , $value:expr) => { $value.get() };
+    (@PLAIN bounded_u32  , $value:expr) => { $value.get() };
+    (@PLAIN bounded_u64  , $value:expr) => { $value.get() };
+    (@PLAIN bounded_u128 , $value:expr) => { $value.get() };
+    (@PLAIN bounded_usize, $value:expr) => { $value.get() };
+    (@PLAIN u24          , $value:expr) => { $value.value() };
+    (@PLAIN u40          , $value:expr) => { $value.value() };
+    (@PLAIN u48          , $value:expr) => { $value.value() };
+    (@PLAIN u56          , $value:expr) => { $value.value() };
+    (@PLAIN bool, $value:expr) => { ($value as ::core::primitive::u8) };
+    (@PLAIN char, $value:expr) => { ($value as ::core::primitive::u32) };
+    (@PLAIN duration, $value:expr) => { $value.as_nanos() };
+    (@PLAIN ipv4, $value:expr) => { $value.to_bits() };
+    (@PLAIN ipv6, $value:expr) => { $value.to_bits() };
+    (@PLAIN f16, $value:expr) => { $crate::__f16_impl!((@PLAIN) , $value) };
+    (@PLAIN half_f16, $value:expr) => { $value.to_f64_const() };
+    (@PLAIN bf16, $value:expr) => { $value.to_f64_const() };
+    (@PLAIN c_f32, $value:expr) => { $value.re };
+    (@PLAIN c_f64, $value:expr) => { $value.re };
+    (@PLAIN uom_length, $value:expr) => { $value.value };
+    (@PLAIN uom_mass, $value:expr) => { $value.value };
+    (@PLAIN uom_time, $value:expr) => { $value.value };
+    (@PLAIN uom_velocity, $value:expr) => { $value.value };
+    (@PLAIN uom_acceleration, $value:expr) => { $value.value };
+    (@PLAIN uom_frequency, $value:expr) => { $value.value };
+    (@PLAIN of_f32, $value:expr) => { $value.0 };
+    (@PLAIN of_f64, $value:expr) => { $value.0 };
+    (@PLAIN nn_f32, $value:expr) => { $value.into_inner() };
+    (@PLAIN nn_f64, $value:expr) => { $value.into_inner() };
+    (@PLAIN $numeric_type:ident, $value:expr) => {
+        $crate::__fixed_kind! { $numeric_type (@PLAIN_FIXED ($value)) (@SIMD_KIND $numeric_type (@PLAIN_SIMD ($value)) (@PLAIN_OTHER ($value))) }
+    };
+    (@PLAIN_FIXED ($value:expr) $numeric_type:ident ($fixed:ident $bits:ident $frac:literal)) => {
+        ($value.to_bits() as ::core::primitive::f64 / (1u64 << $frac) as ::core::primitive::f64)
+    };
+    (@PLAIN_SIMD ($value:expr) $numeric_type:ident ($lane:ident $lanes:literal)) => { $value.to_array()[0] };
+    (@PLAIN_OTHER ($value:expr)) => { $value };
+
+    // Find the `tunable` option
+    (@TUNABLE $vis:tt $name:ident $types:tt [(tunable) $($options:tt)*]) => {
+        $crate::__nz_impl!(@TUNABLE_IMPL $vis $name $types);
+    };
+    (@TUNABLE $vis:tt $name:ident $types:tt [$other:tt $($options:tt)*]) => {
+        $crate::__nz_impl!(@TUNABLE $vis $name $types [$($options)*]);
+    };
+    (@TUNABLE $vis:tt $name:ident $types:tt []) => {};
+
+    // Store each variant in an atomic, initialized with the constant value
+    // The variants are guarded by a sequence lock, odd while a write is in progress,
+    // so that `get` never observes an override half-applied
+    (@TUNABLE_IMPL ($($vis:tt)*) $name:ident [$($numeric_type:ident)*]) => {
+        const _: () = {
+            #[cfg(debug_assertions)]
+            #[allow(non_snake_case)]
+            struct Overrides {
+                sequence: ::core::sync::atomic::AtomicUsize,
+                $($numeric_type: $crate::__nz_impl!(@ATOMIC $numeric_type),)*
+            }
+
+            #[allow(deprecated)]
+            impl $name {
+                #[cfg(debug_assertions)]
+                fn overrides() -> &'static Overrides {
+                    static OVERRIDES: Overrides = Overrides {
+                        sequence: ::core::sync::atomic::AtomicUsize::new(0),
+                        $($numeric_type: <$crate::__nz_impl!(@ATOMIC $numeric_type)>::new(
+                            $crate::__nz_impl!(@TO_BITS $numeric_type, $name.$numeric_type)
+                        ),)*
+                    };
+                    &OVERRIDES
+                }
+
+                /// Get the current value of the constant, including the overrides in debug builds
+                ///
+                /// All the variants come from the same call to `set_override`, even when it runs concurrently
+                #[allow(dead_code)]
+                $($vis)* fn get(&self) -> Self {
+                    #[cfg(debug_assertions)]
+                    {
+                        use ::core::sync::atomic::{fence, Ordering};
+                        let overrides = Self::overrides();
+                        loop {
+                            let sequence = overrides.sequence.load(Ordering::Acquire);
+                            if sequence % 2 == 1 {
+                                ::core::hint::spin_loop();
+                                continue;
+                            }
+                            let value = $name {
+                                $($numeric_type: $crate::__nz_impl!(@FROM_BITS $numeric_type,
+                                    overrides.$numeric_type.load(Ordering::Relaxed)
+                                ),)*
+                            };
+                            fence(Ordering::Acquire);
+                            if overrides.sequence.load(Ordering::Relaxed) == sequence {
+                                return value;
+                            }
+                        }
+                    }
+                    #[cfg(not(debug_assertions))]
+                    {
+                        *self
+                    }
+                }
+
+                /// Override the value returned by `get`, only available in debug builds
+                ///
+                /// Concurrent calls are serialized, and readers see either the previous value or the new one
+                #[cfg(debug_assertions)]
+                #[allow(dead_code)]
+                $($vis)* fn set_override(&self, value: Self) {
+                    use ::core::sync::atomic::{fence, Ordering};
+                    let overrides = Self::overrides();
+                    let mut sequence = overrides.sequence.load(Ordering::Relaxed);
+                    loop {
+                        if sequence % 2 == 1 {
+                            ::core::hint::spin_loop();
+                            sequence = overrides.sequence.load(Ordering::Relaxed);
+                            continue;
+                        }
+                        match overrides.sequence.compare_exchange_weak(
+                            sequence, sequence.wrapping_add(1), Ordering::Acquire, Ordering::Relaxed,
+                        ) {
+                            ::core::result::Result::Ok(_) => break,
+                            ::core::result::Result::Err(current) => sequence = current,
+                        }
+                    }
+                    fence(Ordering::Release);
+                    $(overrides.$numeric_type.store(
+                        $crate::__nz_impl!(@TO_BITS $numeric_type, value.$numeric_type),
+                        Ordering::Relaxed,
+                    );)*
+                    overrides.sequence.store(sequence.wrapping_add(2), Ordering::Release);
+                }
+
+                /// Restore the compiled value, only available in debug builds
+                #[cfg(debug_assertions)]
+                #[allow(dead_code)]
+                $($vis)* fn reset_override(&self) {
+                    self.set_override(*self);
+                }
+
+                /// Override the value from its text representation, parsed for every variant,
+                /// only available in debug builds
+                #[cfg(debug_assertions)]
+                #[allow(dead_code)]
+                $($vis)* fn set_override_str(&self, value: &::core::primitive::str)
+                    -> ::core::result::Result<(), $crate::OverrideError>
+                {
+                    self.set_override($name {
+                        $($numeric_type: match <$crate::__nz_impl!(@GET_TYPE $numeric_type) as ::core::str::FromStr>::from_str(value) {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(_) => return ::core::result::Result::Err($crate::OverrideError),
+                        },)*
+                    });
+                    ::core::result::Result::Ok(())
+                }
+            }
+        };
+    };
+
+    // Get the atomic storing each variant, floats being stored as bits
+    (@ATOMIC i8   ) => { ::core::sync::atomic::AtomicI8 };
+    (@ATOMIC i16  ) => { ::core::sync::atomic::AtomicI16 };
+    (@ATOMIC i32  ) => { ::core::sync::atomic::AtomicI32 };
+    (@ATOMIC i64  ) => { ::core::sync::atomic::AtomicI64 };
+    (@ATOMIC isize) => { ::core::sync::atomic::AtomicIsize };
+    (@ATOMIC u8   ) => { ::core::sync::atomic::AtomicU8 };
+    (@ATOMIC u16  ) => { ::core::sync::atomic::AtomicU16 };
+    (@ATOMIC u32  ) => { ::core::sync::atomic::AtomicU32 };
+    (@ATOMIC u64  ) => { ::core::sync::atomic::AtomicU64 };
+    (@ATOMIC usize) => { ::core::sync::atomic::AtomicUsize };
+    (@ATOMIC f32  ) => { ::core::sync::atomic::AtomicU32 };
+    (@ATOMIC f64  ) => { ::core::sync::atomic::AtomicU64 };
+    (@ATOMIC real ) => { $crate::__real_impl!((@ATOMIC)) };
+    (@ATOMIC f16  ) => { ::core::sync::atomic::AtomicU16 };
+    (@ATOMIC bf16 ) => { ::core::sync::atomic::AtomicU16 };
+    (@ATOMIC nz_i8   ) => { ::core::sync::atomic::AtomicI8 };
+    (@ATOMIC nz_i16  ) => { ::core::sync::atomic::AtomicI16 };
+    (@ATOMIC nz_i32  ) => { ::core::sync::atomic::AtomicI32 };
+    (@ATOMIC nz_i64  ) => { ::core::sync::atomic::AtomicI64 };
+    (@ATOMIC nz_isize) => { ::core::sync::atomic::AtomicIsize };
+    (@ATOMIC nz_u8   ) => { ::core::sync::atomic::AtomicU8 };
+    (@ATOMIC nz_u16  ) => { ::core::sync::atomic::AtomicU16 };
+    (@ATOMIC nz_u32  ) => { ::core::sync::atomic::AtomicU32 };
+    (@ATOMIC nz_u64  ) => { ::core::sync::atomic::AtomicU64 };
+    (@ATOMIC nz_usize) => { ::core::sync::atomic::AtomicUsize };
+    (@ATOMIC $numeric_type:ident) => {
+        ::core::compile_error!(::core::concat!("`", ::core::stringify!($numeric_type), "` variants cannot be tunable"))
+    };
+
+    // Convert between a variant and the content of its atomic
+    (@TO_BITS f32, $value:expr) => { ::core::primitive::f32::to_bits($value) };
+    (@TO_BITS f64, $value:expr) => { ::core::primitive::f64::to_bits($value) };
+    (@TO_BITS real, $value:expr) => { $crate::Real::to_bits($value) };
+    (@TO_BITS f16, $value:expr) => { <$crate::__nz_impl!(@GET_TYPE f16)>::to_bits($value) };
+    (@TO_BITS bf16, $value:expr) => { <$crate::__nz_impl!(@GET_TYPE bf16)>::to_bits($value) };
+    (@TO_BITS nz_i8   , $value:expr) => { $value.get() };
+    (@TO_BITS nz_i16  , $value:expr) => { $value.get() };
+    (@TO_BITS nz_i32  , $value:expr) => { $value.get() };
+    (@TO_BITS nz_i64  , $value:expr) => { $value.get() };
+    (@TO_BITS nz_isize, $value:expr) => { $value.get() };
+    (@TO_BITS nz_u8   , $value:expr) => { $value.get() };
+    (@TO_BITS nz_u16  , $value:expr) => { $value.get() };
+    (@TO_BITS nz_u32  , $value:expr) => { $value.get() };
+    (@TO_BITS nz_u64  , $value:expr) => { $value.get() };
+    (@TO_BITS nz_usize, $value:expr) => { $value.get() };
+    (@TO_BITS $numeric_type:ident, $value:expr) => { $value };
+    (@FROM_BITS f32, $bits:expr) => { ::core::primitive::f32::from_bits($bits) };
+    (@FROM_BITS f64, $bits:expr) => { ::core::primitive::f64::from_bits($bits) };
+    (@FROM_BITS real, $bits:expr) => { $crate::Real::from_bits($bits) };
+    (@FROM_BITS f16, $bits:expr) => { <$crate::__nz_impl!(@GET_TYPE f16)>::from_bits($bits) };
+    (@FROM_BITS bf16, $bits:expr) => { <$crate::__nz_impl!(@GET_TYPE bf16)>::from_bits($bits) };
+    (@FROM_BITS nz_i8   , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_i8   , $bits) };
+    (@FROM_BITS nz_i16  , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_i16  , $bits) };
+    (@FROM_BITS nz_i32  , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_i32  , $bits) };
+    (@FROM_BITS nz_i64  , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_i64  , $bits) };
+    (@FROM_BITS nz_isize, $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_isize, $bits) };
+    (@FROM_BITS nz_u8   , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_u8   , $bits) };
+    (@FROM_BITS nz_u16  , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_u16  , $bits) };
+    (@FROM_BITS nz_u32  , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_u32  , $bits) };
+    (@FROM_BITS nz_u64  , $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_u64  , $bits) };
+    (@FROM_BITS nz_usize, $bits:expr) => { $crate::__nz_impl!(@FROM_BITS_NZ nz_usize, $bits) };
+    (@FROM_BITS $numeric_type:ident, $bits:expr) => { $bits };
+    // Only non-zero values can be stored by `set_override`
+    (@FROM_BITS_NZ $numeric_type:ident, $bits:expr) => {
+        match <$crate::__nz_impl!(@GET_TYPE $numeric_type)>::new($bits) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::unreachable!(),
+        }
+    };
+
+    // Keep the third-party derives from the structs with custom variants, whose types may not implement them,
+    // and from deprecated structs, as the derived impls would warn about using the struct
+    (@DERIVE_BLOCKERS [#[deprecated $($deprecated:tt)*] $($docs:tt)*] $types:tt [$($blockers:tt)*] $item:tt) => {
+        $crate::__nz_impl!(@DERIVE_BLOCKERS [$($docs)*] $types [$($blockers)* deprecated] $item);
+    };
+    (@DERIVE_BLOCKERS [#[$($doc:tt)*] $($docs:tt)*] $types:tt $blockers:tt $item:tt) => {
+        $crate::__nz_impl!(@DERIVE_BLOCKERS [$($docs)*] $types $blockers $item);
+    };
+    (@DERIVE_BLOCKERS [] $types:tt $blockers:tt ($($item:tt)*)) => {
+        $crate::__rkyv_impl! {
+            $types $blockers
+            $($item)*
+        }
+    };
+
+    // Describe the constant by the value of its widest variant
+    (@SCHEMARS $name:ident $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl ::schemars::JsonSchema for $name {
+            fn schema_name() -> $crate::__Cow<'static, ::core::primitive::str> {
+                $crate::__Cow::Borrowed(::core::stringify!($name))
+            }
+
+            fn schema_id() -> $crate::__Cow<'static, ::core::primitive::str> {
+                $crate::__Cow::Borrowed(::core::concat!(::core::module_path!(), "::", ::core::stringify!($name)))
+            }
+
+            fn json_schema(_: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+                // The `json!` macro of serde_json relies on the prelude
+                #[allow(unused_imports)]
+                use ::core::convert::Into as _;
+                ::schemars::json_schema!({ "const": $name.$numeric_type })
+            }
+        }
+    };
+
+    (@UFMT $name:ident $numeric_type:ident) => {
+        #[allow(deprecated)]
+        impl ::ufmt::uDisplay for $name {
+            fn fmt<W>(&self, f: &mut ::ufmt::Formatter<'_, W>) -> ::core::result::Result<(), W::Error>
+            where
+                W: ::ufmt::uWrite + ?::core::marker::Sized,
+            {
+                ::ufmt::uDisplay::fmt(&self.$numeric_type, f)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::ufmt::uDebug for $name {
+            fn fmt<W>(&self, f: &mut ::ufmt::Formatter<'_, W>) -> ::core::result::Result<(), W::Error>
+            where
+                W: ::ufmt::uWrite + ?::core::marker::Sized,
+            {
+                ::ufmt::uDebug::fmt(&self.$numeric_type, f)
+            }
+        }
+    };
+
+    // Get the type of the field of a variant, bounded and narrowest types depending on their constant
+    (@FIELD_TYPE $name:ident bounded_i8   ) => { ::bounded_integer::BoundedI8<{ $name::__BOUNDS.0 as ::core::primitive::i8 }, { $name::__BOUNDS.1 as ::core::primitive::i8 }> };
+    (@FIELD_TYPE $name:ident bounded_i16  ) => { ::bounded_integer::BoundedI16<{ $name::__BOUNDS.0 as ::core::primitive::i16 }, { $name::__BOUNDS.1 as ::core::primitive::i16 }> };
+    (@FIELD_TYPE $name:ident bounded_i32  ) => { ::bounded_integer::BoundedI32<{ $name::__BOUNDS.0 as ::core::primitive::i32 }, { $name::__BOUNDS.1 as ::core::primitive::i32 }> };
+    (@FIELD_TYPE $name:ident bounded_i64  ) => { ::bounded_integer::BoundedI64<{ $name::__BOUNDS.0 as ::core::primitive::i64 }, { $name::__BOUNDS.1 as ::core::primitive::i64 }> };
+    (@FIELD_TYPE $name:ident bounded_i128 ) => { ::bounded_integer::BoundedI128<{ $name::__BOUNDS.0 as ::core::primitive::i128 }, { $name::__BOUNDS.1 as ::core::primitive::i128 }> };
+    (@FIELD_TYPE $name:ident bounded_isize) => { ::bounded_integer::BoundedIsize<{ $name::__BOUNDS.0 as ::core::primitive::isize }, { $name::__BOUNDS.1 as ::core::primitive::isize }> };
+    (@FIELD_TYPE $name:ident bounded_u8   ) => { ::bounded_integer::BoundedU8<{ $name::__BOUNDS.0 as ::core::primitive::u8 }, { $name::__BOUNDS.1 as ::core::primitive::u8 }> };
+    (@FIELD_TYPE $name:ident bounded_u16  ) => { ::bounded_integer::BoundedU16<{ $name::__BOUNDS.0 as ::core::primitive::u16 }, { $name::__BOUNDS.1 as ::core::primitive::u16 }> };
+    (@FIELD_TYPE $name:ident bounded_u32  ) => { ::bounded_integer::BoundedU32<{ $name::__BOUNDS.0 as ::core::primitive::u32 }, { $name::__BOUNDS.1 as ::core::primitive::u32 }> };
+    (@FIELD_TYPE $name:ident bounded_u64  ) => { ::bounded_integer::BoundedU64<{ $name::__BOUNDS.0 as ::core::primitive::u64 }, { $name::__BOUNDS.1 as ::core::primitive::u64 }> };
+    (@FIELD_TYPE $name:ident bounded_u128 ) => { ::bounded_integer::BoundedU128<{ $name::__BOUNDS.0 as ::core::primitive::u128 }, { $name::__BOUNDS.1 as ::core::primitive::u128 }> };
+    (@FIELD_TYPE $name:ident bounded_usize) => { ::bounded_integer::BoundedUsize<{ $name::__BOUNDS.0 as ::core::primitive::usize }, { $name::__BOUNDS.1 as ::core::primitive::usize }> };
+    (@FIELD_TYPE $name:ident limbs) => { [::core::primitive::u64; $name::__LIMBS] };
+    (@FIELD_TYPE $name:ident le_bytes) => { [::core::primitive::u8; $name::__BYTE_COUNT] };
+    (@FIELD_TYPE $name:ident be_bytes) => { [::core::primitive::u8; $name::__BYTE_COUNT] };
+    (@FIELD_TYPE $name:ident ne_bytes) => { [::core::primitive::u8; $name::__BYTE_COUNT] };
+    (@FIELD_TYPE $name:ident smallest_uint) => { <$crate::__Smallest<false, { $name::__SMALLEST_UINT }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident smallest_int ) => { <$crate::__Smallest<true, { $name::__SMALLEST_INT }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident ptr_u8   ) => { <$crate::__Pointer<::core::primitive::u8   , { $name::__CONST_PTR }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident ptr_u16  ) => { <$crate::__Pointer<::core::primitive::u16  , { $name::__CONST_PTR }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident ptr_u32  ) => { <$crate::__Pointer<::core::primitive::u32  , { $name::__CONST_PTR }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident ptr_u64  ) => { <$crate::__Pointer<::core::primitive::u64  , { $name::__CONST_PTR }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident ptr_usize) => { <$crate::__Pointer<::core::primitive::usize, { $name::__CONST_PTR }> as $crate::__Pick>::Type };
+    (@FIELD_TYPE $name:ident $numeric_type:ident) => { $crate::__nz_impl!(@GET_TYPE $numeric_type) };
+
+    // Get the full nonzero type from shorthand
+    // Fails in nonstd
+    (@GET_TYPE nz_i8   ) => { ::core::num::NonZeroI8 };
+    (@GET_TYPE nz_i16  ) => { ::core::num::NonZeroI16 };
+    (@GET_TYPE nz_i32  ) => { ::core::num::NonZeroI32 };
+    (@GET_TYPE nz_i64  ) => { ::core::num::NonZeroI64 };
+    (@GET_TYPE nz_i128 ) => { ::core::num::NonZeroI128 };
+    (@GET_TYPE nz_isize) => { ::core::num::NonZeroIsize };
+    (@GET_TYPE nz_u8   ) => { ::core::num::NonZeroU8 };
+    (@GET_TYPE nz_u16  ) => { ::core::num::NonZeroU16 };
+    (@GET_TYPE nz_u32  ) => { ::core::num::NonZeroU32 };
+    (@GET_TYPE nz_u64  ) => { ::core::num::NonZeroU64 };
+    (@GET_TYPE nz_u128 ) => { ::core::num::NonZeroU128 };
+    (@GET_TYPE nz_usize) => { ::core::num::NonZeroUsize };
+    (@GET_TYPE opt_nz_i8   ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_i8   )> };
+    (@GET_TYPE opt_nz_i16  ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_i16  )> };
+    (@GET_TYPE opt_nz_i32  ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_i32  )> };
+    (@GET_TYPE opt_nz_i64  ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_i64  )> };
+    (@GET_TYPE opt_nz_i128 ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_i128 )> };
+    (@GET_TYPE opt_nz_isize) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_isize)> };
+    (@GET_TYPE opt_nz_u8   ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_u8   )> };
+    (@GET_TYPE opt_nz_u16  ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_u16  )> };
+    (@GET_TYPE opt_nz_u32  ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_u32  )> };
+    (@GET_TYPE opt_nz_u64  ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_u64  )> };
+    (@GET_TYPE opt_nz_u128 ) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_u128 )> };
+    (@GET_TYPE opt_nz_usize) => { ::core::option::Option<$crate::__nz_impl!(@GET_TYPE nz_usize)> };
+    // The types of the `bounded-integer` crate, their bounds being inferred from the field
+    (@GET_TYPE bounded_i8   ) => { ::bounded_integer::BoundedI8<_, _> };
+    (@GET_TYPE bounded_i16  ) => { ::bounded_integer::BoundedI16<_, _> };
+    (@GET_TYPE bounded_i32  ) => { ::bounded_integer::BoundedI32<_, _> };
+    (@GET_TYPE bounded_i64  ) => { ::bounded_integer::BoundedI64<_, _> };
+    (@GET_TYPE bounded_i128 ) => { ::bounded_integer::BoundedI128<_, _> };
+    (@GET_TYPE bounded_isize) => { ::bounded_integer::BoundedIsize<_, _> };
+    (@GET_TYPE bounded_u8   ) => { ::bounded_integer::BoundedU8<_, _> };
+    (@GET_TYPE bounded_u16  ) => { ::bounded_integer::BoundedU16<_, _> };
+    (@GET_TYPE bounded_u32  ) => { ::bounded_integer::BoundedU32<_, _> };
+    (@GET_TYPE bounded_u64  ) => { ::bounded_integer::BoundedU64<_, _> };
+    (@GET_TYPE bounded_u128 ) => { ::bounded_integer::BoundedU128<_, _> };
+    (@GET_TYPE bounded_usize) => { ::bounded_integer::BoundedUsize<_, _> };
+    // The types of the `nonmax` crate, which the crate using the macro must depend on
+    (@GET_TYPE nonmax_i8   ) => { ::nonmax::NonMaxI8 };
+    (@GET_TYPE nonmax_i16  ) => { ::nonmax::NonMaxI16 };
+    (@GET_TYPE nonmax_i32  ) => { ::nonmax::NonMaxI32 };
+    (@GET_TYPE nonmax_i64  ) => { ::nonmax::NonMaxI64 };
+    (@GET_TYPE nonmax_i128 ) => { ::nonmax::NonMaxI128 };
+    (@GET_TYPE nonmax_isize) => { ::nonmax::NonMaxIsize };
+    (@GET_TYPE nonmax_u8   ) => { ::nonmax::NonMaxU8 };
+    (@GET_TYPE nonmax_u16  ) => { ::nonmax::NonMaxU16 };
+    (@GET_TYPE nonmax_u32  ) => { ::nonmax::NonMaxU32 };
+    (@GET_TYPE nonmax_u64  ) => { ::nonmax::NonMaxU64 };
+    (@GET_TYPE nonmax_u128 ) => { ::nonmax::NonMaxU128 };
+    (@GET_TYPE nonmax_usize) => { ::nonmax::NonMaxUsize };
+    (@GET_TYPE u24         ) => { $crate::__uint_impl!(@TYPE u24) };
+    (@GET_TYPE u40         ) => { $crate::__uint_impl!(@TYPE u40) };
+    (@GET_TYPE u48         ) => { $crate::__uint_impl!(@TYPE u48) };
+    (@GET_TYPE u56         ) => { $crate::__uint_impl!(@TYPE u56) };
+    // Fully qualify the primitives, in case they are shadowed
+    (@GET_TYPE i8      ) => { ::core::primitive::i8 };
+    (@GET_TYPE i16     ) => { ::core::primitive::i16 };
+    (@GET_TYPE i32     ) => { ::core::primitive::i32 };
+    (@GET_TYPE i64     ) => { ::core::primitive::i64 };
+    (@GET_TYPE i128    ) => { ::core::primitive::i128 };
+    (@GET_TYPE isize   ) => { ::core::primitive::isize };
+    (@GET_TYPE u8      ) => { ::core::primitive::u8 };
+    (@GET_TYPE u16     ) => { ::core::primitive::u16 };
+    (@GET_TYPE u32     ) => { ::core::primitive::u32 };
+    (@GET_TYPE u64     ) => { ::core::primitive::u64 };
+    (@GET_TYPE u128    ) => { ::core::primitive::u128 };
+    (@GET_TYPE usize   ) => { ::core::primitive::usize };
+    (@GET_TYPE f32     ) => { ::core::primitive::f32 };
+    (@GET_TYPE f64     ) => { ::core::primitive::f64 };
+    (@GET_TYPE bool    ) => { ::core::primitive::bool };
+    (@GET_TYPE str     ) => { &'static ::core::primitive::str };
+    (@GET_TYPE ptr_u8   ) => { *mut ::core::primitive::u8 };
+    (@GET_TYPE ptr_u16  ) => { *mut ::core::primitive::u16 };
+    (@GET_TYPE ptr_u32  ) => { *mut ::core::primitive::u32 };
+    (@GET_TYPE ptr_u64  ) => { *mut ::core::primitive::u64 };
+    (@GET_TYPE ptr_usize) => { *mut ::core::primitive::usize };
+    (@GET_TYPE char    ) => { ::core::primitive::char };
+    (@GET_TYPE real    ) => { $crate::Real };
+    (@GET_TYPE f16     ) => { $crate::__f16_impl!((@GET_TYPE)) };
+    (@GET_TYPE nightly_f16) => { $crate::__nightly_impl!(@TYPE f16) };
+    (@GET_TYPE half_f16) => { $crate::__half_impl!(@TYPE f16) };
+    (@GET_TYPE bf16    ) => { $crate::__half_impl!(@TYPE bf16) };
+    (@GET_TYPE f128    ) => { $crate::__nightly_impl!(@TYPE f128) };
+    // C types, whose width depends on the target, `size_t` and `ssize_t` being unstable in `core::ffi`
+    (@GET_TYPE c_char     ) => { ::core::ffi::c_char };
+    (@GET_TYPE c_schar    ) => { ::core::ffi::c_schar };
+    (@GET_TYPE c_uchar    ) => { ::core::ffi::c_uchar };
+    (@GET_TYPE c_short    ) => { ::core::ffi::c_short };
+    (@GET_TYPE c_ushort   ) => { ::core::ffi::c_ushort };
+    (@GET_TYPE c_int      ) => { ::core::ffi::c_int };
+    (@GET_TYPE c_uint     ) => { ::core::ffi::c_uint };
+    (@GET_TYPE c_long     ) => { ::core::ffi::c_long };
+    (@GET_TYPE c_ulong    ) => { ::core::ffi::c_ulong };
+    (@GET_TYPE c_longlong ) => { ::core::ffi::c_longlong };
+    (@GET_TYPE c_ulonglong) => { ::core::ffi::c_ulonglong };
+    (@GET_TYPE c_ssize_t  ) => { ::core::primitive::isize };
+    (@GET_TYPE c_size_t   ) => { ::core::primitive::usize };
+    (@GET_TYPE atomic_bool ) => { ::core::sync::atomic::AtomicBool };
+    (@GET_TYPE atomic_i8   ) => { ::core::sync::atomic::AtomicI8 };
+    (@GET_TYPE atomic_i16  ) => { ::core::sync::atomic::AtomicI16 };
+    (@GET_TYPE atomic_i32  ) => { ::core::sync::atomic::AtomicI32 };
+    (@GET_TYPE atomic_i64  ) => { ::core::sync::atomic::AtomicI64 };
+    (@GET_TYPE atomic_isize) => { ::core::sync::atomic::AtomicIsize };
+    (@GET_TYPE atomic_u8   ) => { ::core::sync::atomic::AtomicU8 };
+    (@GET_TYPE atomic_u16  ) => { ::core::sync::atomic::AtomicU16 };
+    (@GET_TYPE atomic_u32  ) => { ::core::sync::atomic::AtomicU32 };
+    (@GET_TYPE atomic_u64  ) => { ::core::sync::atomic::AtomicU64 };
+    (@GET_TYPE atomic_usize) => { ::core::sync::atomic::AtomicUsize };
+    (@GET_TYPE ipv4       ) => { ::core::net::Ipv4Addr };
+    (@GET_TYPE decimal    ) => { $crate::__decimal_impl!(@TYPE) };
+    (@GET_TYPE c_f32      ) => { $crate::__complex_impl!(@TYPE f32) };
+    (@GET_TYPE c_f64      ) => { $crate::__complex_impl!(@TYPE f64) };
+    (@GET_TYPE of_f32     ) => { $crate::__ordered_impl!(@ORDERED_TYPE f32) };
+    (@GET_TYPE of_f64     ) => { $crate::__ordered_impl!(@ORDERED_TYPE f64) };
+    (@GET_TYPE nn_f32     ) => { $crate::__ordered_impl!(@NOT_NAN_TYPE f32) };
+    (@GET_TYPE nn_f64     ) => { $crate::__ordered_impl!(@NOT_NAN_TYPE f64) };
+    (@GET_TYPE uom_length) => { $crate::__uom_impl!(@TYPE Length) };
+    (@GET_TYPE uom_mass) => { $crate::__uom_impl!(@TYPE Mass) };
+    (@GET_TYPE uom_time) => { $crate::__uom_impl!(@TYPE Time) };
+    (@GET_TYPE uom_velocity) => { $crate::__uom_impl!(@TYPE Velocity) };
+    (@GET_TYPE uom_acceleration) => { $crate::__uom_impl!(@TYPE Acceleration) };
+    (@GET_TYPE uom_frequency) => { $crate::__uom_impl!(@TYPE Frequency) };
+    (@GET_TYPE ratio_i32  ) => { $crate::__rational_impl!(@TYPE i32) };
+    (@GET_TYPE ratio_i64  ) => { $crate::__rational_impl!(@TYPE i64) };
+    (@GET_TYPE ratio_i128 ) => { $crate::__rational_impl!(@TYPE i128) };
+    (@GET_TYPE ratio_u32  ) => { $crate::__rational_impl!(@TYPE u32) };
+    (@GET_TYPE ratio_u64  ) => { $crate::__rational_impl!(@TYPE u64) };
+    (@GET_TYPE ratio_u128 ) => { $crate::__rational_impl!(@TYPE u128) };
+    (@GET_TYPE ipv6       ) => { ::core::net::Ipv6Addr };
+    (@GET_TYPE duration   ) => { ::core::time::Duration };
+    (@GET_TYPE bigint     ) => { $crate::Lazy<$crate::__bigint_impl!(@TYPE BigInt)> };
+    (@GET_TYPE u256       ) => { $crate::__bnum_impl!(@TYPE U256) };
+    (@GET_TYPE i256       ) => { $crate::__bnum_impl!(@TYPE I256) };
+    (@GET_TYPE biguint    ) => { $crate::Lazy<$crate::__bigint_impl!(@TYPE BigUint)> };
+    (@GET_TYPE $numeric_type:ident) => {
+        $crate::__fixed_kind! { $numeric_type (@FIXED_TYPE) (@SIMD_KIND $numeric_type (@SIMD_TYPE) (@OTHER_TYPE $numeric_type)) }
+    };
+    (@SIMD_TYPE $numeric_type:ident ($lane:ident $lanes:literal)) => { $crate::__simd_impl!(@TYPE $lane $lanes) };
+    (@FIXED_TYPE $numeric_type:ident ($fixed:ident $bits:ident $frac:literal)) => { $crate::__fixed_impl!(@TYPE $fixed) };
+    (@OTHER_TYPE $numeric_type:ident) => { $numeric_type };
+    // Look for a SIMD variant in the variants that are not fixed-point
+    (@SIMD_KIND $numeric_type:ident $simd:tt $other:tt) => { $crate::__simd_kind! { $numeric_type $simd $other } };
+}
+
+/// Implement `ufmt::uDisplay` and `ufmt::uDebug` through the widest integer variant
+#[cfg(feature = "ufmt")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __ufmt_impl {
+    ($name:ident $types:tt) => {
+        $crate::__nz_impl!(@FIND (@UFMT $name) $types [
+            i128 u128 i64 u64 c_longlong c_ulonglong isize usize c_long c_ulong c_ssize_t c_size_t
+            i32 u32 c_int c_uint i16 u16 c_short c_ushort i8 u8 c_char c_schar c_uchar
+        ]);
+    };
+}
+
+#[cfg(not(feature = "ufmt"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __ufmt_impl {
+    ($name:ident $types:tt) => {};
+}
+
+/**
+Check whether a polymorphic constant provides a variant, as a `bool` usable in const contexts.
+
+This lets downstream macros and generic code adapt to the variants of a constant:
+```
+# use polymorphic_constant::{polymorphic_constant, has_variant};
+polymorphic_constant! {
+    const PI: f32 | f64 = 3.141592653589793;
+}
+
+const HAS_F32: bool = has_variant!(PI, f32);
+
+assert!(HAS_F32);
+assert!(!has_variant!(PI, u8));
+```
+*/
+#[macro_export]
+macro_rules! has_variant {
+    ($constant:ty, $numeric_type:ident) => {
+        $crate::__has_variant(<$constant>::__VARIANTS, ::core::stringify!($numeric_type))
+    };
+}
+
+/// Implement `schemars::JsonSchema` as a fixed value, the one of the widest variant
+#[cfg(feature = "schemars")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __schemars_impl {
+    ($name:ident $types:tt) => {
+        $crate::__nz_impl!(@FIND_WIDEST (@SCHEMARS $name) $types);
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __schemars_impl {
+    ($name:ident $types:tt) => {};
+}
+
+/// Compare a constant with its float variants, exactly with `PartialEq` and approximately with `approx`, both ways
+#[cfg(feature = "approx")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __approx_impl {
+    ($name:ident [$($numeric_type:ident)*]) => {
+        $($crate::__approx_impl!(@EACH $name $numeric_type);)*
+    };
+    (@EACH $name:ident f32) => { $crate::__approx_impl!(@FLOAT $name f32); };
+    (@EACH $name:ident f64) => { $crate::__approx_impl!(@FLOAT $name f64); };
+    (@EACH $name:ident $other:ident) => {};
+    (@FLOAT $name:ident $float:ident) => {
+        #[allow(deprecated)]
+        const _: () = {
+            impl ::core::cmp::PartialEq<::core::primitive::$float> for $name {
+                fn eq(&self, other: &::core::primitive::$float) -> ::core::primitive::bool {
+                    self.$float == *other
+                }
+            }
+
+            impl ::core::cmp::PartialEq<$name> for ::core::primitive::$float {
+                fn eq(&self, other: &$name) -> ::core::primitive::bool {
+                    *self == other.$float
+                }
+            }
+
+            $crate::__approx_impl!(@TRAITS $float ($name, ::core::primitive::$float) [$float] []);
+            $crate::__approx_impl!(@TRAITS $float (::core::primitive::$float, $name) [] [$float]);
+        };
+    };
+    // Compare the variant with the float, the field being read on the side of the constant
+    (@TRAITS $float:ident ($lhs:ty, $rhs:ty) [$($lhs_field:ident)?] [$($rhs_field:ident)?]) => {
+        impl ::approx::AbsDiffEq<$rhs> for $lhs {
+            type Epsilon = ::core::primitive::$float;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <::core::primitive::$float as ::approx::AbsDiffEq>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &$rhs, epsilon: Self::Epsilon) -> ::core::primitive::bool {
+                let (lhs, rhs): (&::core::primitive::$float, &::core::primitive::$float) = (&self$(.$lhs_field)?, &other$(.$rhs_field)?);
+                ::approx::AbsDiffEq::abs_diff_eq(lhs, rhs, epsilon)
+            }
+        }
+
+        impl ::approx::RelativeEq<$rhs> for $lhs {
+            fn default_max_relative() -> Self::Epsilon {
+                <::core::primitive::$float as ::approx::RelativeEq>::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &$rhs, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> ::core::primitive::bool {
+                let (lhs, rhs): (&::core::primitive::$float, &::core::primitive::$float) = (&self$(.$lhs_field)?, &other$(.$rhs_field)?);
+                ::approx::RelativeEq::relative_eq(lhs, rhs, epsilon, max_relative)
+            }
+        }
+
+        impl ::approx::UlpsEq<$rhs> for $lhs {
+            fn default_max_ulps() -> ::core::primitive::u32 {
+                <::core::primitive::$float as ::approx::UlpsEq>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &$rhs, epsilon: Self::Epsilon, max_ulps: ::core::primitive::u32) -> ::core::primitive::bool {
+                let (lhs, rhs): (&::core::primitive::$float, &::core::primitive::$float) = (&self$(.$lhs_field)?, &other$(.$rhs_field)?);
+                ::approx::UlpsEq::ulps_eq(lhs, rhs, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "approx"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __approx_impl {
+    ($name:ident $types:tt) => {};
+}
+
+/// Derive `bevy_reflect::Reflect` for the struct of a constant when every variant is reflectable,
+/// whose derive also submits it to `TypeRegistry::register_derived_types`
+#[cfg(feature = "bevy")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bevy_impl {
+    // Every variant has been checked, and nothing keeps the struct from being reflected
+    (@CHECK [] [] ($($item:tt)*)) => {
+        #[derive(::bevy_reflect::Reflect)]
+        $($item)*
+    };
+    (@CHECK [i8           $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [i16          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [i32          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [i64          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [i128         $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [isize        $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [u8           $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [u16          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [u32          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [u64          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [u128         $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [usize        $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [f32          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [f64          $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [bool         $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [char         $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [real         $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [duration     $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_i8        $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_i16       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_i32       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_i64       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_i128      $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_isize     $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_u8        $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_u16       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_u32       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_u64       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_u128      $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [nz_usize     $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_i8    $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_i16   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_i32   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_i64   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_i128  $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_isize $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_u8    $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_u16   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_u32   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_u64   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_u128  $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [opt_nz_usize $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_char       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_schar      $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_uchar      $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_short      $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_ushort     $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_int        $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_uint       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_long       $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_ulong      $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_longlong   $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_ulonglong  $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_ssize_t    $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    (@CHECK [c_size_t     $($types:ident)*] $blockers:tt $item:tt) => { $crate::__bevy_impl!(@CHECK [$($types)*] $blockers $item); };
+    // Leave the struct without the derive if a variant is not reflectable
+    (@CHECK $types:tt $blockers:tt ($($item:tt)*)) => {
+        $($item)*
+    };
+    ([$($numeric_type:ident)*] [$($blocker:ident)*] $($item:tt)*) => {
+        $crate::__bevy_impl!(@CHECK [$($numeric_type)*] [$($blocker)*] ($($item)*));
+    };
+}
+
+#[cfg(not(feature = "bevy"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bevy_impl {
+    ($types:tt $blockers:tt $($item:tt)*) => {
+        $($item)*
+    };
+}
+
+/// Derive the `rkyv` traits for the struct of a constant when every variant is archivable, then pass it to `bevy`
+#[cfg(feature = "rkyv")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __rkyv_impl {
+    // Every variant has been checked, and nothing keeps the struct from being archived
+    (@CHECK [] $all:tt [] ($($item:tt)*)) => {
+        $crate::__bevy_impl! {
+            $all []
+            #[derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)]
+            #[repr(C)]
+            $($item)*
+        }
+    };
+    // Leave the struct without the derives if it has custom variants or is deprecated
+    (@CHECK [] $all:tt $blockers:tt ($($item:tt)*)) => {
+        $crate::__bevy_impl! {
+            $all $blockers
+            $($item)*
+        }
+    };
+    (@CHECK [i8           $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [i16          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [i32          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [i64          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [i128         $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [isize        $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [u8           $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [u16          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [u32          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [u64          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [u128         $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [usize        $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [f32          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [f64          $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [bool         $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [char         $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [real         $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [duration     $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_i8        $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_i16       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_i32       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_i64       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_i128      $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_isize     $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_u8        $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_u16       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_u32       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_u64       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_u128      $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [nz_usize     $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_i8    $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_i16   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_i32   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_i64   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_i128  $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_isize $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_u8    $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_u16   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_u32   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_u64   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_u128  $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [opt_nz_usize $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_char       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_schar      $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_uchar      $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_short      $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_ushort     $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_int        $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_uint       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_long       $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_ulong      $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_longlong   $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_ulonglong  $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_ssize_t    $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    (@CHECK [c_size_t     $($types:ident)*] $all:tt $blockers:tt $item:tt) => { $crate::__rkyv_impl!(@CHECK [$($types)*] $all $blockers $item); };
+    // Leave the struct without the derives if a variant is not archivable
+    (@CHECK [$other:ident $($types:ident)*] $all:tt $blockers:tt ($($item:tt)*)) => {
+        $crate::__bevy_impl! {
+            $all $blockers
+            $($item)*
+        }
+    };
+    ([$($numeric_type:ident)*] $blockers:tt $($item:tt)*) => {
+        $crate::__rkyv_impl!(@CHECK [$($numeric_type)*] [$($numeric_type)*] $blockers ($($item)*));
+    };
+}
+
+#[cfg(not(feature = "rkyv"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __rkyv_impl {
+    ($types:tt $blockers:tt $($item:tt)*) => {
+        $crate::__bevy_impl! {
+            $types $blockers
+            $($item)*
+        }
+    };
+}
+
+/// Name the `num-bigint` types, and build them from the text of a literal checked at compile-time
+#[cfg(feature = "bigint")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bigint_impl {
+    (@TYPE $big:ident) => { ::num_bigint::$big };
+    (@VALUE BigInt $lit:literal) => {{
+        const LITERAL: (::core::primitive::bool, ::core::primitive::usize, ::core::primitive::u32) =
+            $crate::__int_literal(::core::stringify!($lit), false);
+        fn build() -> ::num_bigint::BigInt {
+            let magnitude = $crate::__bigint_impl!(@MAGNITUDE $lit, LITERAL);
+            let value = ::num_bigint::BigInt::from(magnitude);
+            if LITERAL.0 { -value } else { value }
+        }
+        $crate::Lazy::new(build)
+    }};
+    (@VALUE BigUint $lit:literal) => {{
+        const LITERAL: (::core::primitive::bool, ::core::primitive::usize, ::core::primitive::u32) =
+            $crate::__int_literal(::core::stringify!($lit), true);
+        fn build() -> ::num_bigint::BigUint {
+            $crate::__bigint_impl!(@MAGNITUDE $lit, LITERAL)
+        }
+        $crate::Lazy::new(build)
+    }};
+    (@MAGNITUDE $lit:literal, $literal:ident) => {
+        match ::num_bigint::BigUint::parse_bytes(::core::stringify!($lit)[$literal.1..].as_bytes(), $literal.2) {
+            ::core::option::Option::Some(magnitude) => magnitude,
+            ::core::option::Option::None => ::core::unreachable!(),
+        }
+    };
+}
+
+#[cfg(not(feature = "bigint"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bigint_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `bigint` and `biguint` variants require the `bigint` feature")
+    };
+}
+
+/// Name the unstable `f16` and `f128` primitives, with the `nightly` feature, `core::primitive` not listing them yet
+#[cfg(feature = "nightly")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __nightly_impl {
+    (@TYPE f16) => { f16 };
+    (@TYPE f128) => { f128 };
+}
+
+#[cfg(not(feature = "nightly"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __nightly_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `f128` variants require the `nightly` feature")
+    };
+}
+
+/// Name the `half` types
+#[cfg(feature = "half")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __half_impl {
+    (@TYPE $half:ident) => { ::half::$half };
+}
+
+#[cfg(not(feature = "half"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __half_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `bf16` variants require the `half` feature")
+    };
+}
+
+/// Call back the main macro with the kind of `f16` variants, the primitive with the `nightly` feature,
+/// and the `half` type with the `half` feature
+#[cfg(feature = "nightly")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __f16_impl {
+    (($($before:tt)*) $($after:tt)*) => {
+        $crate::__nz_impl! { $($before)* nightly_f16 $($after)* }
+    };
+}
+
+#[cfg(all(not(feature = "nightly"), feature = "half"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __f16_impl {
+    (($($before:tt)*) $($after:tt)*) => {
+        $crate::__nz_impl! { $($before)* half_f16 $($after)* }
+    };
+}
+
+#[cfg(not(any(feature = "nightly", feature = "half")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __f16_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `f16` variants require the `nightly` or the `half` feature")
+    };
+}
+
+/// Call back the main macro with the float type of `real` variants, `f64` with the `real-f64` feature
+#[cfg(feature = "real-f64")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __real_impl {
+    (($($before:tt)*) $($after:tt)*) => {
+        $crate::__nz_impl! { $($before)* f64 $($after)* }
+    };
+}
+
+#[cfg(not(feature = "real-f64"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __real_impl {
+    (($($before:tt)*) $($after:tt)*) => {
+        $crate::__nz_impl! { $($before)* f32 $($after)* }
+    };
+}
+
+/// Call back the main macro with the `fixed` type, the type of the bits and the fractional bits of
+/// fixed-point variants, or with the other arguments for any other variant
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __fixed_kind {
+    (i8f0    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i8f0 (I8F0 i8 0) $fixed) };
+    (i7f1    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i7f1 (I7F1 i8 1) $fixed) };
+    (i6f2    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i6f2 (I6F2 i8 2) $fixed) };
+    (i5f3    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i5f3 (I5F3 i8 3) $fixed) };
+    (i4f4    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i4f4 (I4F4 i8 4) $fixed) };
+    (i3f5    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i3f5 (I3F5 i8 5) $fixed) };
+    (i2f6    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i2f6 (I2F6 i8 6) $fixed) };
+    (i1f7    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i1f7 (I1F7 i8 7) $fixed) };
+    (i0f8    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i0f8 (I0F8 i8 8) $fixed) };
+    (i16f0   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i16f0 (I16F0 i16 0) $fixed) };
+    (i15f1   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i15f1 (I15F1 i16 1) $fixed) };
+    (i14f2   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i14f2 (I14F2 i16 2) $fixed) };
+    (i13f3   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i13f3 (I13F3 i16 3) $fixed) };
+    (i12f4   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i12f4 (I12F4 i16 4) $fixed) };
+    (i11f5   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i11f5 (I11F5 i16 5) $fixed) };
+    (i10f6   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i10f6 (I10F6 i16 6) $fixed) };
+    (i9f7    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i9f7 (I9F7 i16 7) $fixed) };
+    (i8f8    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i8f8 (I8F8 i16 8) $fixed) };
+    (i7f9    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i7f9 (I7F9 i16 9) $fixed) };
+    (i6f10   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i6f10 (I6F10 i16 10) $fixed) };
+    (i5f11   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i5f11 (I5F11 i16 11) $fixed) };
+    (i4f12   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i4f12 (I4F12 i16 12) $fixed) };
+    (i3f13   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i3f13 (I3F13 i16 13) $fixed) };
+    (i2f14   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i2f14 (I2F14 i16 14) $fixed) };
+    (i1f15   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i1f15 (I1F15 i16 15) $fixed) };
+    (i0f16   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i0f16 (I0F16 i16 16) $fixed) };
+    (i32f0   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i32f0 (I32F0 i32 0) $fixed) };
+    (i31f1   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i31f1 (I31F1 i32 1) $fixed) };
+    (i30f2   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i30f2 (I30F2 i32 2) $fixed) };
+    (i29f3   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i29f3 (I29F3 i32 3) $fixed) };
+    (i28f4   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i28f4 (I28F4 i32 4) $fixed) };
+    (i27f5   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i27f5 (I27F5 i32 5) $fixed) };
+    (i26f6   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i26f6 (I26F6 i32 6) $fixed) };
+    (i25f7   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i25f7 (I25F7 i32 7) $fixed) };
+    (i24f8   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i24f8 (I24F8 i32 8) $fixed) };
+    (i23f9   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i23f9 (I23F9 i32 9) $fixed) };
+    (i22f10  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i22f10 (I22F10 i32 10) $fixed) };
+    (i21f11  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i21f11 (I21F11 i32 11) $fixed) };
+    (i20f12  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i20f12 (I20F12 i32 12) $fixed) };
+    (i19f13  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i19f13 (I19F13 i32 13) $fixed) };
+    (i18f14  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i18f14 (I18F14 i32 14) $fixed) };
+    (i17f15  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i17f15 (I17F15 i32 15) $fixed) };
+    (i16f16  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i16f16 (I16F16 i32 16) $fixed) };
+    (i15f17  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i15f17 (I15F17 i32 17) $fixed) };
+    (i14f18  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i14f18 (I14F18 i32 18) $fixed) };
+    (i13f19  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i13f19 (I13F19 i32 19) $fixed) };
+    (i12f20  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i12f20 (I12F20 i32 20) $fixed) };
+    (i11f21  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i11f21 (I11F21 i32 21) $fixed) };
+    (i10f22  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i10f22 (I10F22 i32 22) $fixed) };
+    (i9f23   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i9f23 (I9F23 i32 23) $fixed) };
+    (i8f24   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i8f24 (I8F24 i32 24) $fixed) };
+    (i7f25   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i7f25 (I7F25 i32 25) $fixed) };
+    (i6f26   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i6f26 (I6F26 i32 26) $fixed) };
+    (i5f27   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i5f27 (I5F27 i32 27) $fixed) };
+    (i4f28   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i4f28 (I4F28 i32 28) $fixed) };
+    (i3f29   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i3f29 (I3F29 i32 29) $fixed) };
+    (i2f30   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i2f30 (I2F30 i32 30) $fixed) };
+    (i1f31   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i1f31 (I1F31 i32 31) $fixed) };
+    (i0f32   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED i0f32 (I0F32 i32 32) $fixed) };
+    (u8f0    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u8f0 (U8F0 u8 0) $fixed) };
+    (u7f1    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u7f1 (U7F1 u8 1) $fixed) };
+    (u6f2    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u6f2 (U6F2 u8 2) $fixed) };
+    (u5f3    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u5f3 (U5F3 u8 3) $fixed) };
+    (u4f4    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u4f4 (U4F4 u8 4) $fixed) };
+    (u3f5    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u3f5 (U3F5 u8 5) $fixed) };
+    (u2f6    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u2f6 (U2F6 u8 6) $fixed) };
+    (u1f7    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u1f7 (U1F7 u8 7) $fixed) };
+    (u0f8    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u0f8 (U0F8 u8 8) $fixed) };
+    (u16f0   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u16f0 (U16F0 u16 0) $fixed) };
+    (u15f1   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u15f1 (U15F1 u16 1) $fixed) };
+    (u14f2   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u14f2 (U14F2 u16 2) $fixed) };
+    (u13f3   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u13f3 (U13F3 u16 3) $fixed) };
+    (u12f4   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u12f4 (U12F4 u16 4) $fixed) };
+    (u11f5   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u11f5 (U11F5 u16 5) $fixed) };
+    (u10f6   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u10f6 (U10F6 u16 6) $fixed) };
+    (u9f7    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u9f7 (U9F7 u16 7) $fixed) };
+    (u8f8    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u8f8 (U8F8 u16 8) $fixed) };
+    (u7f9    $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u7f9 (U7F9 u16 9) $fixed) };
+    (u6f10   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u6f10 (U6F10 u16 10) $fixed) };
+    (u5f11   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u5f11 (U5F11 u16 11) $fixed) };
+    (u4f12   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u4f12 (U4F12 u16 12) $fixed) };
+    (u3f13   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u3f13 (U3F13 u16 13) $fixed) };
+    (u2f14   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u2f14 (U2F14 u16 14) $fixed) };
+    (u1f15   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u1f15 (U1F15 u16 15) $fixed) };
+    (u0f16   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u0f16 (U0F16 u16 16) $fixed) };
+    (u32f0   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u32f0 (U32F0 u32 0) $fixed) };
+    (u31f1   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u31f1 (U31F1 u32 1) $fixed) };
+    (u30f2   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u30f2 (U30F2 u32 2) $fixed) };
+    (u29f3   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u29f3 (U29F3 u32 3) $fixed) };
+    (u28f4   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u28f4 (U28F4 u32 4) $fixed) };
+    (u27f5   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u27f5 (U27F5 u32 5) $fixed) };
+    (u26f6   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u26f6 (U26F6 u32 6) $fixed) };
+    (u25f7   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u25f7 (U25F7 u32 7) $fixed) };
+    (u24f8   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u24f8 (U24F8 u32 8) $fixed) };
+    (u23f9   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u23f9 (U23F9 u32 9) $fixed) };
+    (u22f10  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u22f10 (U22F10 u32 10) $fixed) };
+    (u21f11  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u21f11 (U21F11 u32 11) $fixed) };
+    (u20f12  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u20f12 (U20F12 u32 12) $fixed) };
+    (u19f13  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u19f13 (U19F13 u32 13) $fixed) };
+    (u18f14  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u18f14 (U18F14 u32 14) $fixed) };
+    (u17f15  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u17f15 (U17F15 u32 15) $fixed) };
+    (u16f16  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u16f16 (U16F16 u32 16) $fixed) };
+    (u15f17  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u15f17 (U15F17 u32 17) $fixed) };
+    (u14f18  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u14f18 (U14F18 u32 18) $fixed) };
+    (u13f19  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u13f19 (U13F19 u32 19) $fixed) };
+    (u12f20  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u12f20 (U12F20 u32 20) $fixed) };
+    (u11f21  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u11f21 (U11F21 u32 21) $fixed) };
+    (u10f22  $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u10f22 (U10F22 u32 22) $fixed) };
+    (u9f23   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u9f23 (U9F23 u32 23) $fixed) };
+    (u8f24   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u8f24 (U8F24 u32 24) $fixed) };
+    (u7f25   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u7f25 (U7F25 u32 25) $fixed) };
+    (u6f26   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u6f26 (U6F26 u32 26) $fixed) };
+    (u5f27   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u5f27 (U5F27 u32 27) $fixed) };
+    (u4f28   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u4f28 (U4F28 u32 28) $fixed) };
+    (u3f29   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u3f29 (U3F29 u32 29) $fixed) };
+    (u2f30   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u2f30 (U2F30 u32 30) $fixed) };
+    (u1f31   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u1f31 (U1F31 u32 31) $fixed) };
+    (u0f32   $fixed:tt $other:tt) => { $crate::__fixed_kind!(@FIXED u0f32 (U0F32 u32 32) $fixed) };
+    (@FIXED $numeric_type:ident $info:tt ($($before:tt)*)) => { $crate::__nz_impl! { $($before)* $numeric_type $info } };
+    ($numeric_type:ident $fixed:tt ($($other:tt)*)) => { $crate::__nz_impl! { $($other)* } };
+}
+
+/// Name the `fixed` types
+#[cfg(feature = "fixed")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __fixed_impl {
+    (@TYPE $fixed:ident) => { ::fixed::types::$fixed };
+}
+
+#[cfg(not(feature = "fixed"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __fixed_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the fixed-point variants require the `fixed` feature")
+    };
+}
+
+/// Call back the main macro with the lane type and the number of lanes of SIMD variants,
+/// or with the other arguments for any other variant
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __simd_kind {
+    (f32x2  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f32x2 (f32 2) $simd } };
+    (f32x4  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f32x4 (f32 4) $simd } };
+    (f32x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f32x8 (f32 8) $simd } };
+    (f32x16 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f32x16 (f32 16) $simd } };
+    (f64x2  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f64x2 (f64 2) $simd } };
+    (f64x4  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f64x4 (f64 4) $simd } };
+    (f64x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD f64x8 (f64 8) $simd } };
+    (i8x16  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i8x16 (i8 16) $simd } };
+    (i8x32  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i8x32 (i8 32) $simd } };
+    (i8x64  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i8x64 (i8 64) $simd } };
+    (u8x16  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u8x16 (u8 16) $simd } };
+    (u8x32  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u8x32 (u8 32) $simd } };
+    (u8x64  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u8x64 (u8 64) $simd } };
+    (i16x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i16x8 (i16 8) $simd } };
+    (i16x16 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i16x16 (i16 16) $simd } };
+    (i16x32 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i16x32 (i16 32) $simd } };
+    (u16x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u16x8 (u16 8) $simd } };
+    (u16x16 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u16x16 (u16 16) $simd } };
+    (u16x32 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u16x32 (u16 32) $simd } };
+    (i32x4  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i32x4 (i32 4) $simd } };
+    (i32x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i32x8 (i32 8) $simd } };
+    (i32x16 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i32x16 (i32 16) $simd } };
+    (u32x4  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u32x4 (u32 4) $simd } };
+    (u32x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u32x8 (u32 8) $simd } };
+    (u32x16 $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u32x16 (u32 16) $simd } };
+    (i64x2  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i64x2 (i64 2) $simd } };
+    (i64x4  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i64x4 (i64 4) $simd } };
+    (i64x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD i64x8 (i64 8) $simd } };
+    (u64x2  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u64x2 (u64 2) $simd } };
+    (u64x4  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u64x4 (u64 4) $simd } };
+    (u64x8  $simd:tt $other:tt) => { $crate::__simd_kind! { @SIMD u64x8 (u64 8) $simd } };
+    (@SIMD $numeric_type:ident $info:tt ($($before:tt)*)) => { $crate::__nz_impl! { $($before)* $numeric_type $info } };
+    ($numeric_type:ident $simd:tt ($($other:tt)*)) => { $crate::__nz_impl! { $($other)* } };
+}
+
+/// Name the `core::simd` vectors, and splat a value in all their lanes
+#[cfg(feature = "portable_simd")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __simd_impl {
+    (@TYPE $lane:ident $lanes:literal) => { ::core::simd::Simd<::core::primitive::$lane, $lanes> };
+    (@SPLAT $lanes:literal, $value:expr) => { ::core::simd::Simd::from_array([$value; $lanes]) };
+}
+
+#[cfg(not(feature = "portable_simd"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __simd_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the SIMD variants require the `portable_simd` feature")
+    };
+}
+
+/// Name the `rust_decimal` type, and build it from its parts
+#[cfg(feature = "decimal")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __decimal_impl {
+    (@TYPE) => { ::rust_decimal::Decimal };
+    (@PARTS $negative:expr, $mantissa:expr, $scale:expr) => {{
+        let mantissa: ::core::primitive::u128 = $mantissa;
+        ::rust_decimal::Decimal::from_parts(
+            mantissa as ::core::primitive::u32,
+            (mantissa >> 32) as ::core::primitive::u32,
+            (mantissa >> 64) as ::core::primitive::u32,
+            $negative && mantissa != 0,
+            $scale,
+        )
+    }};
+}
+
+#[cfg(not(feature = "decimal"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __decimal_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `decimal` variants require the `decimal` feature")
+    };
+}
+
+/// Name the `num-complex` type, and build it from its real part
+#[cfg(feature = "complex")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __complex_impl {
+    (@TYPE $float:ident) => { ::num_complex::Complex<::core::primitive::$float> };
+    (@NEW $re:expr) => { ::num_complex::Complex::new($re, 0.0) };
+}
+
+#[cfg(not(feature = "complex"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __complex_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `c_f32` and `c_f64` variants require the `complex` feature")
+    };
+}
+
+/// Name the `ordered-float` types, and build them from a float that is never NaN
+#[cfg(feature = "ordered-float")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __ordered_impl {
+    (@ORDERED_TYPE $float:ident) => { ::ordered_float::OrderedFloat<::core::primitive::$float> };
+    (@NOT_NAN_TYPE $float:ident) => { ::ordered_float::NotNan<::core::primitive::$float> };
+    (@ORDERED $value:expr) => { ::ordered_float::OrderedFloat($value) };
+    // `NotNan` has no safe const constructor, its only precondition is proven by a hidden const assertion just before
+    // This is the only `unsafe` code generated by the macro
+    (@NOT_NAN $float:ident $value:expr) => {{
+        const VALUE: ::core::primitive::$float = $value;
+        const _: () = ::core::assert!(!VALUE.is_nan(), "`NotNan` variants cannot hold NaN");
+        // SAFETY: `VALUE` is a constant, checked not to be NaN by the assertion above during constant evaluation
+        #[allow(unsafe_code)]
+        let value = unsafe { ::ordered_float::NotNan::new_unchecked(VALUE) };
+        value
+    }};
+}
+
+#[cfg(not(feature = "ordered-float"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __ordered_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `of_f32`, `of_f64`, `nn_f32` and `nn_f64` variants require the `ordered-float` feature")
+    };
+}
+
+/// Name the unsigned types of `arbitrary-int`
+#[cfg(feature = "arbitrary-int")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __uint_impl {
+    (@TYPE $numeric_type:ident) => { ::arbitrary_int::$numeric_type };
+}
+
+#[cfg(not(feature = "arbitrary-int"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __uint_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `u24`, `u40`, `u48` and `u56` variants require the `arbitrary-int` feature")
+    };
+}
+
+/// Name the 256 bits integers of `bnum`, and build them from their digits
+#[cfg(feature = "bnum")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bnum_impl {
+    (@TYPE $type:ident) => { ::bnum::types::$type };
+    (@UNSIGNED $digits:expr) => { ::bnum::types::U256::from_digits($digits) };
+    (@SIGNED $digits:expr) => { ::bnum::types::I256::from_bits(::bnum::types::U256::from_digits($digits)) };
+}
+
+#[cfg(not(feature = "bnum"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __bnum_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `u256` and `i256` variants require the `bnum` feature")
+    };
+}
+
+/// Name the `f64` quantities of `uom`, and build them from a value in their base unit
+#[cfg(feature = "uom")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __uom_impl {
+    (@TYPE $quantity:ident) => { ::uom::si::f64::$quantity };
+    // Quantities have no const constructor, but their fields are public
+    (@NEW $quantity:ident, $value:expr) => {
+        ::uom::si::f64::$quantity {
+            dimension: ::core::marker::PhantomData,
+            units: ::core::marker::PhantomData,
+            value: $value,
+        }
+    };
+}
+
+#[cfg(not(feature = "uom"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __uom_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the quantity variants such as `uom_length` require the `uom` feature")
+    };
+}
+
+/// Name the `num-rational` type, and build it from its reduced parts
+#[cfg(feature = "rational")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __rational_impl {
+    (@TYPE $int:ident) => { ::num_rational::Ratio<::core::primitive::$int> };
+    (@NEW $numerator:expr, $denominator:expr) => { ::num_rational::Ratio::new_raw($numerator, $denominator) };
+}
+
+#[cfg(not(feature = "rational"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __rational_impl {
+    ($($t:tt)*) => {
+        ::core::compile_error!("the `ratio` variants require the `rational` feature")
+    };
+}
+
+/// Add the descriptor of a constant to a `linkme` distributed slice
+#[cfg(feature = "linkme")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __linkme_impl {
+    (($($slice:tt)+) $name:ident [$($numeric_type:ident)*]) => {
+        #[allow(deprecated)]
+        const _: () = {
+            #[::linkme::distributed_slice($($slice)+)]
+            static DESCRIPTOR: $crate::Descriptor = $crate::Descriptor {
+                name: ::core::stringify!($name),
+                module: ::core::module_path!(),
+                variants: &[
+                    $((::core::stringify!($numeric_type), $crate::__nz_impl!(@CONST_VALUE $numeric_type, $name.$numeric_type)),)*
+                ],
+            };
+        };
+    };
+}
+
+#[cfg(not(feature = "linkme"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __linkme_impl {
+    ($slice:tt $name:ident $types:tt) => {
+        ::core::compile_error!("the `register` option requires the `linkme` feature");
+    };
+}
+
+/**
+Apply the `NAME = value` entries of a source to the listed tunable constants, in debug builds only.
+
+Entries naming other constants are ignored, and entries that cannot be parsed
+into every variant of their constant are skipped. See [`hot_reload`].
+*/
+#[cfg(feature = "hot-reload")]
+#[macro_export]
+macro_rules! reload_overrides {
+    ($source:expr, [$($name:ident),* $(,)?]) => {
+        #[cfg(debug_assertions)]
+        for (name, value) in $crate::hot_reload::parse($source) {
+            $(if name == ::core::stringify!($name) {
+                let _ = $name.set_override_str(value);
+            })*
+        }
+    };
 }
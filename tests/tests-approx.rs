@@ -0,0 +1,31 @@
+#![cfg(all(test, feature = "approx"))]
+
+use approx::{abs_diff_eq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_ne};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const THIRD: f32 | f64 = 0.333_333_333_333_333_333;
+    const HALF: f64 = 0.5;
+}
+
+#[test]
+fn test_approx_traits() {
+    // Compared with the variant of the same type, on either side
+    assert_abs_diff_eq!(THIRD, 1.0f64 / 3.0);
+    assert_abs_diff_eq!(1.0f32 / 3.0, THIRD);
+    assert_relative_eq!(THIRD, 1.0f32 / 3.0);
+    assert_relative_eq!(1.0f64 / 3.0, THIRD, max_relative = 1e-15);
+    assert_ulps_eq!(THIRD, 1.0f64 / 3.0, max_ulps = 1);
+    assert_ulps_eq!(1.0f32 / 3.0, THIRD);
+
+    assert!(abs_diff_eq!(HALF, 0.6, epsilon = 0.2));
+    assert!(!abs_diff_eq!(HALF, 0.6, epsilon = 0.05));
+    assert!(relative_ne!(HALF, 0.5001));
+}
+
+#[test]
+fn test_approx_eq() {
+    assert!(THIRD == 0.333_333_34f32);
+    assert!(0.333_333_333_333_333_3f64 == THIRD);
+    assert!(HALF != 0.25);
+}
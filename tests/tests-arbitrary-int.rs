@@ -0,0 +1,56 @@
+#![cfg(all(test, feature = "arbitrary-int"))]
+
+use arbitrary_int::{u24, u40, u48, u56, Number};
+use polymorphic_constant::polymorphic_constant;
+
+const fn widest_address() -> u64 {
+    (1 << 40) - 1
+}
+
+polymorphic_constant! {
+    const FRAME_LENGTH: u32 | u24 = 0x12_3456;
+    const U24_MAX: u32 | u24 = 0xFF_FFFF;
+    const U40_MAX: u64 | u40 = 0xFF_FFFF_FFFF;
+    const U48_MAX: u64 | u48 = 0xFFFF_FFFF_FFFF;
+    const U56_MAX: u64 | u56 = 0xFF_FFFF_FFFF_FFFF;
+    const ZERO: u8 | u24 | u40 | u48 | u56 = 0;
+    const ADDRESS: u64 | u40 = widest_address();
+    #[saturate]
+    const CLAMPED: u64 | u24 | u40 | u48 | u56 = 0xFFFF_FFFF_FFFF_FFFF;
+    #[proof]
+    const PROVEN: u32 | u24 | u48 = 0x80_0000;
+}
+
+#[test]
+fn test_arbitrary_int() {
+    assert_eq!(FRAME_LENGTH.u24, u24::new(0x12_3456));
+    assert_eq!(FRAME_LENGTH.u24.value(), FRAME_LENGTH.u32);
+    assert_eq!(ADDRESS.u40, u40::MAX);
+}
+
+#[test]
+fn test_arbitrary_int_range() {
+    // The largest value of each width is accepted, one more fails to compile
+    assert_eq!(U24_MAX.u24, u24::MAX);
+    assert_eq!(U40_MAX.u40, u40::MAX);
+    assert_eq!(U48_MAX.u48, u48::MAX);
+    assert_eq!(U56_MAX.u56, u56::MAX);
+    assert_eq!(ZERO.u24, u24::new(0));
+    assert_eq!(ZERO.u56, u56::new(0));
+}
+
+#[test]
+fn test_arbitrary_int_saturate() {
+    assert_eq!(CLAMPED.u64, u64::MAX);
+    assert_eq!(CLAMPED.u24, u24::MAX);
+    assert_eq!(CLAMPED.u40, u40::MAX);
+    assert_eq!(CLAMPED.u48, u48::MAX);
+    assert_eq!(CLAMPED.u56, u56::MAX);
+}
+
+#[test]
+fn test_arbitrary_int_proof() {
+    let () = _PROOF_PROVEN_u24;
+    let () = _PROOF_PROVEN_u48;
+    assert_eq!(PROVEN.u48.value(), 0x80_0000);
+}
@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+use polymorphic_constant::polymorphic_assoc_const;
+
+struct Circle;
+
+polymorphic_assoc_const! {
+    impl Circle {
+        /// Half a turn
+        pub const HALF_TURN: f32 | f64 = 0.5;
+        pub (crate) const SIDES: u8 | nz_u16 = 1;
+        const POINTS: u32 | i64 = 360;
+    }
+}
+
+struct Square;
+
+// The same names can be reused for another type in the same module
+polymorphic_assoc_const! {
+    impl Square {
+        const SIDES: u8 | u32 = 4;
+    }
+}
+
+#[test]
+fn test_assoc() {
+    assert_eq!(Circle::HALF_TURN.f32, 0.5);
+    assert_eq!(Circle::HALF_TURN.f64, 0.5);
+    assert_eq!(Circle::SIDES.nz_u16.get(), 1);
+    assert_eq!(Circle::POINTS.i64, 360);
+    assert_eq!(Square::SIDES.u32, 4);
+}
+
+#[test]
+fn test_assoc_into() {
+    let sides: u8 = Square::SIDES.into();
+
+    assert_eq!(sides, 4);
+}
+
+polymorphic_assoc_const! {
+    /// Constants shared by every float type
+    pub trait FloatConsts {
+        /// Half a turn
+        const HALF_TURN: f32 | f64 = 0.5;
+        const TWO: f32 | f64 = 2.0;
+    }
+}
+
+polymorphic_assoc_const! {
+    trait NonZeroConsts {
+        const ONE: nz_u8 | nz_u32 = 1;
+    }
+}
+
+#[test]
+fn test_trait() {
+    fn turn<T: FloatConsts + core::ops::Mul<Output = T>>() -> T {
+        T::HALF_TURN * T::TWO
+    }
+
+    assert_eq!(turn::<f32>(), 1.0);
+    assert_eq!(turn::<f64>(), 1.0);
+    assert_eq!(<core::num::NonZeroU32 as NonZeroConsts>::ONE.get(), 1);
+}
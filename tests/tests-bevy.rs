@@ -0,0 +1,55 @@
+#![cfg(all(test, feature = "bevy"))]
+
+use bevy_reflect::{FromReflect, ReflectFromReflect, Struct, TypeRegistry};
+use core::any::TypeId;
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    pub const MAX_SPEED: u8 | u32 = 120;
+}
+
+#[test]
+fn test_registration() {
+    let mut registry = TypeRegistry::empty();
+    registry.register_derived_types();
+
+    assert!(registry.contains(TypeId::of::<MAX_SPEED>()));
+    assert!(registry.get_type_data::<ReflectFromReflect>(TypeId::of::<MAX_SPEED>()).is_some());
+    assert!(registry.get_with_short_type_path("MAX_SPEED").is_some());
+}
+
+#[test]
+fn test_reflect() {
+    assert_eq!(MAX_SPEED.field("u8").and_then(|field| field.try_downcast_ref::<u8>()), Some(&120));
+    assert_eq!(MAX_SPEED.field("u32").and_then(|field| field.try_downcast_ref::<u32>()), Some(&120));
+
+    let copy = MAX_SPEED::from_reflect(&MAX_SPEED).unwrap();
+    assert_eq!(copy.u8, 120);
+}
+
+#[test]
+fn test_unreflectable_variant() {
+    polymorphic_constant! {
+        const SMALL: u8 | smallest_uint = 3;
+    }
+
+    let mut registry = TypeRegistry::empty();
+    registry.register_derived_types();
+
+    assert!(!registry.contains(TypeId::of::<SMALL>()));
+    assert_eq!(SMALL.smallest_uint, 3);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_unreflected() {
+    polymorphic_constant! {
+        #[deprecated]
+        const OLD_SPEED: u8 = 90;
+    }
+
+    let mut registry = TypeRegistry::empty();
+    registry.register_derived_types();
+
+    assert!(!registry.contains(TypeId::of::<OLD_SPEED>()));
+}
@@ -0,0 +1,33 @@
+#![cfg(all(test, feature = "bigint"))]
+
+use num_bigint::{BigInt, BigUint};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    // Wider than `u128`, only the variants of any size can hold it
+    const MODULUS: limbs | biguint = 0x1_0000_0000_0000_0000_0000_0000_0000_0001;
+    const DEBT: bigint = -0x1_0000_0000_0000_0000_0000_0000_0000_0000;
+    // The limits of the fixed-width variants, held the same way by the variants of any size
+    const UNSIGNED_MAX: u128 | biguint = 340_282_366_920_938_463_463_374_607_431_768_211_455;
+    const SIGNED_MIN: i128 | bigint = -170_141_183_460_469_231_731_687_303_715_884_105_728;
+    const SEED: u64 | bigint = 0x1234_5678;
+}
+
+#[test]
+fn test_bigint_wide() {
+    let modulus: BigUint = MODULUS.into();
+    assert_eq!(modulus, (BigUint::from(1u8) << 128u32) + 1u8);
+    assert_eq!(MODULUS.limbs, [1, 0, 1]);
+    assert_eq!(modulus.to_u64_digits(), MODULUS.limbs);
+
+    assert_eq!(DEBT.bigint.get(), -(BigInt::from(1u8) << 128u32));
+}
+
+#[test]
+fn test_bigint_fixed_width() {
+    assert_eq!(UNSIGNED_MAX.biguint.get(), BigUint::from(UNSIGNED_MAX.u128));
+    assert_eq!(UNSIGNED_MAX.u128, u128::MAX);
+    assert_eq!(SIGNED_MIN.bigint.get(), BigInt::from(SIGNED_MIN.i128));
+    assert_eq!(SIGNED_MIN.i128, i128::MIN);
+    assert_eq!(SEED.bigint.get(), BigInt::from(SEED.u64));
+}
@@ -0,0 +1,42 @@
+#![cfg(all(test, feature = "bnum"))]
+
+use bnum::types::{I256, U256};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const FIELD_PRIME: u256 = 0xffff_ffff_0000_0001_0000_0000_0000_0000_0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
+    // One more than `u128::MAX`
+    const ABOVE_U128: u256 | i256 = 340_282_366_920_938_463_463_374_607_431_768_211_456;
+    // One less than `i128::MIN`
+    const BELOW_I128: i256 = -170_141_183_460_469_231_731_687_303_715_884_105_729;
+    const U256_MAX: u256 = 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+    const I256_MAX: i256 = 0x7fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+    const I256_MIN: i256 = -0x8000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+    const CHAIN_ID: u64 | u256 = 137;
+    const OFFSET: i64 | i256 = -42;
+}
+
+#[test]
+fn test_bnum() {
+    assert_eq!(CHAIN_ID.u256, U256::from(137u64));
+    assert_eq!(OFFSET.i256, I256::from(-42i64));
+    assert_eq!(
+        FIELD_PRIME.u256,
+        U256::parse_str_radix("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff", 16)
+    );
+}
+
+#[test]
+fn test_bnum_above_u128() {
+    assert_eq!(ABOVE_U128.u256, U256::from(u128::MAX) + U256::ONE);
+    assert_eq!(ABOVE_U128.u256.digits(), &[0, 0, 1, 0]);
+    assert_eq!(ABOVE_U128.i256, I256::from_bits(ABOVE_U128.u256));
+    assert_eq!(BELOW_I128.i256, I256::from(i128::MIN) - I256::ONE);
+}
+
+#[test]
+fn test_bnum_limits() {
+    assert_eq!(U256_MAX.u256, U256::MAX);
+    assert_eq!(I256_MAX.i256, I256::MAX);
+    assert_eq!(I256_MIN.i256, I256::MIN);
+}
@@ -0,0 +1,47 @@
+#![no_std]
+#![cfg(test)]
+
+use bounded_integer::{BoundedI16, BoundedU8};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    #[bounds(0, 100)]
+    const PERCENT: u8 | bounded_u8 = 42;
+    #[bounds(0, 100)]
+    const FULL: bounded_u8 | u16 = 100;
+    #[bounds(-50, 50)]
+    const LOWEST: bounded_i16 | i32 = -50;
+    #[bounds(0, 100)]
+    #[saturate]
+    const CLAMPED: bounded_u8 | u16 = 1000;
+    #[bounds(-50, 50)]
+    #[saturate]
+    const FLOORED: bounded_i16 | i32 = -1000;
+    #[bounds(0, 100)]
+    #[also(halved)]
+    const SHARE: u8 | bounded_u8 = 80;
+}
+
+#[test]
+fn test_bounded_range() {
+    let percent: BoundedU8<0, 100> = PERCENT.into();
+    assert_eq!(percent.get(), 42);
+    assert_eq!(FULL.bounded_u8.get(), 100);
+    assert_eq!(LOWEST.bounded_i16, BoundedI16::<-50, 50>::new(-50).unwrap());
+}
+
+#[test]
+fn test_bounded_saturate() {
+    // Saturated to the bounds rather than to the range of the type
+    assert_eq!(CLAMPED.bounded_u8.get(), 100);
+    assert_eq!(CLAMPED.u16, 1000);
+    assert_eq!(FLOORED.bounded_i16.get(), -50);
+    assert_eq!(FLOORED.i32, -1000);
+}
+
+#[test]
+fn test_bounded_companion() {
+    // The companion shares the range of the constant
+    let half: BoundedU8<0, 100> = SHARE_HALF.bounded_u8;
+    assert_eq!(half.get(), 40);
+}
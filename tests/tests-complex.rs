@@ -0,0 +1,35 @@
+#![cfg(all(test, feature = "complex"))]
+
+use num_complex::Complex;
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const GAIN: f32 | f64 | c_f32 | c_f64 = 0.1;
+    const NEGATIVE: c_f64 = -2.5e-3;
+    #[round(down)]
+    const FLOOR: c_f32 = 0.1;
+    const ONE: u8 = 1;
+    const THREE: u8 = 3;
+    const THIRD: f32 | c_f32 | c_f64 = ratio_of(ONE, THREE);
+}
+
+#[test]
+fn test_complex_values() {
+    // The real part is the matching float variant, and the imaginary part is zero
+    assert_eq!(GAIN.c_f32, Complex::new(GAIN.f32, 0.0));
+    assert_eq!(GAIN.c_f64, Complex::new(GAIN.f64, 0.0));
+    assert_eq!(NEGATIVE.c_f64, Complex::new(-2.5e-3, 0.0));
+    // Rounded like the float variant
+    assert!(f64::from(FLOOR.c_f32.re) < 0.1);
+    assert_eq!(FLOOR.c_f32.re, f32::from_bits(0.1f32.to_bits() - 1));
+
+    let gain: Complex<f64> = GAIN.into();
+    assert_eq!(gain * gain, Complex::new(0.1 * 0.1, 0.0));
+}
+
+#[test]
+fn test_complex_computed() {
+    assert_eq!(THIRD.c_f32, Complex::new(THIRD.f32, 0.0));
+    assert_eq!(THIRD.c_f32.re, 1.0 / 3.0);
+    assert_eq!(THIRD.c_f64.re, 1.0 / 3.0);
+}
@@ -0,0 +1,124 @@
+#![no_std]
+#![cfg(test)]
+
+use polymorphic_constant::polymorphic_config;
+
+polymorphic_config! {
+    strict = true;
+    derive = [Debug, Clone, Copy, PartialEq];
+}
+
+polymorphic_config! {
+    derive = [Debug, Clone, Copy, PartialEq];
+    name = my_constant;
+}
+
+polymorphic_config! {
+    name = from_constant;
+    impls = [from, try_from];
+}
+
+type Real = f64;
+
+polymorphic_config! {
+    name = real_constant;
+    aliases = [Real = f64];
+    version = 2;
+}
+
+mod units {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Meters(pub f64);
+
+    impl Meters {
+        pub const fn new(value: f64) -> Self {
+            Meters(value)
+        }
+    }
+}
+
+polymorphic_config! {
+    name = unit_constant;
+    customs = [meters = crate::units::Meters::new];
+}
+
+my_constant! {
+    const PI: f32 | f64 = 3.141592653589793;
+    pub const UINT: u16 | u32 = 2047;
+}
+
+from_constant! {
+    const LIMIT: u8 | i64 = 100;
+    #[impls(no_into)]
+    const MASK: u8 | u16 = 255;
+}
+
+real_constant! {
+    const E: f32 | Real = 2.718281828459045;
+    #[renamed_from(EULER, since = 2)]
+    const EULER_NUMBER: f64 = 2.718281828459045;
+}
+
+const fn pixels() -> u64 {
+    3_000_000_001
+}
+
+polymorphic_constant! {
+    const PIXELS: u64 | f64 = pixels();
+    const AREA = PIXELS * PIXELS;
+    #[strict]
+    const EXPLICIT = PIXELS * 2;
+}
+
+unit_constant! {
+    const RADIUS: f64 | meters = 6371.0;
+}
+
+mod inner {
+    my_constant! {
+        pub const LOCAL: u8 = 3;
+    }
+}
+
+#[test]
+fn test_config() {
+    assert_eq!(PI.f64, 3.141592653589793);
+    assert_eq!(UINT, UINT);
+    assert_eq!(inner::LOCAL, inner::LOCAL);
+}
+
+#[test]
+fn test_config_default() {
+    assert_eq!(AREA.u64, 9_000_000_006_000_000_001);
+    assert_eq!(EXPLICIT.u64, 6_000_000_002);
+    assert_eq!(PIXELS, PIXELS);
+}
+
+#[test]
+fn test_config_impls() {
+    use core::convert::TryFrom;
+    use polymorphic_constant::ConstValue;
+
+    assert_eq!(i64::from(LIMIT), 100);
+    assert_eq!(u16::from(MASK), 255);
+    assert!(LIMIT::try_from(ConstValue::I64(100)).is_ok());
+    assert_eq!(MASK::try_from(ConstValue::U16(0)).err(), Some(ConstValue::U16(0)));
+}
+
+#[test]
+fn test_config_aliases() {
+    let e: Real = E.into();
+    assert_eq!(e, 2.718281828459045);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_config_version() {
+    assert_eq!(EULER.f64, EULER_NUMBER.f64);
+}
+
+#[test]
+fn test_config_customs() {
+    assert_eq!(RADIUS.meters, units::Meters(6371.0));
+    assert_eq!(RADIUS.f64, 6371.0);
+}
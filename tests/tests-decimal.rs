@@ -0,0 +1,43 @@
+#![cfg(all(test, feature = "decimal"))]
+
+use polymorphic_constant::polymorphic_constant;
+use rust_decimal::Decimal;
+
+polymorphic_constant! {
+    const FEE_RATE: f64 | decimal = 0.0125;
+    const REFUND: decimal = -0.5;
+    const THOUSANDS: decimal = 12.5e3;
+    // The smallest scale, and the largest one once trailing zeros are dropped
+    const WHOLE: u32 | decimal = 42;
+    const FINEST: decimal = 0.000_000_000_000_000_000_000_000_000_1;
+    const PADDED: decimal = 0.000_000_000_000_000_000_000_000_000_10;
+    const SHIFTED: decimal = 1.5e-27;
+    // The largest mantissa held in 96 bits, one more or a 29th fractional digit failing to compile
+    const LARGEST: decimal = 79_228_162_514_264_337_593_543_950_335;
+    const SMALLEST: decimal = -79_228_162_514_264_337_593_543_950_335;
+}
+
+#[test]
+fn test_decimal_values() {
+    assert_eq!(FEE_RATE.decimal, Decimal::new(125, 4));
+    assert_eq!(FEE_RATE.decimal.to_string(), "0.0125");
+    assert_eq!(REFUND.decimal, Decimal::new(-5, 1));
+    assert_eq!(THOUSANDS.decimal, Decimal::new(12_500, 0));
+    let whole: Decimal = WHOLE.into();
+    assert_eq!(whole, Decimal::from(WHOLE.u32));
+}
+
+#[test]
+fn test_decimal_scale() {
+    assert_eq!(WHOLE.decimal.scale(), 0);
+    assert_eq!(FINEST.decimal, Decimal::from_i128_with_scale(1, 28));
+    assert_eq!(FINEST.decimal.scale(), 28);
+    assert_eq!(PADDED.decimal.scale(), 28);
+    assert_eq!(SHIFTED.decimal, Decimal::from_i128_with_scale(15, 28));
+}
+
+#[test]
+fn test_decimal_precision() {
+    assert_eq!(LARGEST.decimal, Decimal::MAX);
+    assert_eq!(SMALLEST.decimal, Decimal::MIN);
+}
@@ -0,0 +1,47 @@
+#![cfg(all(test, feature = "fixed"))]
+
+use fixed::types::{I0F8, I16F16, I32F0, I8F8, U0F32, U8F8};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const GAIN: f32 | i16f16 | u8f8 = 1.5;
+    // Half of the last bit, rounded away from zero
+    const HALF_BIT: u8f8 = 0.001_953_125;
+    const NEGATIVE_HALF_BIT: i8f8 = -0.001_953_125;
+    const BELOW_HALF_BIT: u8f8 = 0.001_9;
+    const ROUNDED: i32f0 = 2.5;
+    // The limits of the range of each type, `255.999` rounding beyond the one of `u8f8` and failing to compile
+    const TOP: u8f8 = 255.996_093_75;
+    const BOTTOM: i8f8 = -128.0;
+    const LOWEST: i32f0 = -2_147_483_648;
+    // Types without integer bits, or without fractional bits
+    const FRACTION: i0f8 = -0.5;
+    const UNIT: u0f32 = 0.5;
+    const COUNT: i32f0 | u16 = 1_000;
+}
+
+#[test]
+fn test_fixed_values() {
+    assert_eq!(GAIN.i16f16, I16F16::from_num(1.5));
+    assert_eq!(GAIN.u8f8, U8F8::from_num(1.5));
+    let gain: I16F16 = GAIN.into();
+    assert_eq!(gain.to_num::<f32>(), GAIN.f32);
+}
+
+#[test]
+fn test_fixed_rounding() {
+    assert_eq!(HALF_BIT.u8f8.to_bits(), 1);
+    assert_eq!(NEGATIVE_HALF_BIT.i8f8.to_bits(), -1);
+    assert_eq!(BELOW_HALF_BIT.u8f8.to_bits(), 0);
+    assert_eq!(ROUNDED.i32f0, I32F0::from_num(3));
+}
+
+#[test]
+fn test_fixed_range() {
+    assert_eq!(TOP.u8f8, U8F8::MAX);
+    assert_eq!(BOTTOM.i8f8, I8F8::MIN);
+    assert_eq!(LOWEST.i32f0, I32F0::MIN);
+    assert_eq!(FRACTION.i0f8, I0F8::MIN);
+    assert_eq!(UNIT.u0f32, U0F32::from_bits(1 << 31));
+    assert_eq!(COUNT.i32f0.to_num::<u16>(), COUNT.u16);
+}
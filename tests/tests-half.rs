@@ -0,0 +1,47 @@
+#![cfg(all(test, feature = "half"))]
+
+use half::{bf16, f16};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const GAIN: f32 | f16 | bf16 = 1.5;
+    // Rounded to the nearest subnormal, the smallest one being 2^-24 in `f16` and 2^-133 in `bf16`
+    const TINY: f16 = 3e-8;
+    const TINIEST: f16 = 2.9e-8;
+    const TINY_BF16: bf16 = 1e-40;
+    // The largest value rounding down to the maximum of `f16` rather than up to infinity
+    const EDGE: f16 = 65_519.0;
+    #[saturate]
+    const HUGE: f16 | bf16 = 1e300;
+    #[saturate]
+    const LOWEST: f16 = -1e6;
+    const THREE: u32 = 3;
+    const SPAN: u32 = 67_108_864;
+    const QUARTERS: f16 = ratio_of(THREE, SPAN);
+}
+
+#[test]
+fn test_half_values() {
+    assert_eq!(GAIN.f16, f16::from_f32(1.5));
+    assert_eq!(GAIN.bf16, bf16::from_f32(1.5));
+    let gain: f16 = GAIN.into();
+    assert_eq!(gain.to_f32(), GAIN.f32);
+}
+
+#[test]
+fn test_half_subnormal() {
+    assert_eq!(TINY.f16.to_bits(), 1);
+    assert_eq!(TINIEST.f16.to_bits(), 0);
+    assert_eq!(TINY_BF16.bf16.to_bits(), 1);
+    // Three quarters of the smallest subnormal round up to it
+    assert_eq!(QUARTERS.f16.to_bits(), 1);
+}
+
+#[test]
+fn test_half_overflow() {
+    assert_eq!(EDGE.f16, f16::MAX);
+    // Values overflowing to infinity fail to compile, unless saturated
+    assert_eq!(HUGE.f16, f16::MAX);
+    assert_eq!(HUGE.bf16, bf16::MAX);
+    assert_eq!(LOWEST.f16, f16::MIN);
+}
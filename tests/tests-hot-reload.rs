@@ -0,0 +1,42 @@
+#![cfg(all(test, feature = "hot-reload", debug_assertions))]
+
+use polymorphic_constant::hot_reload::{parse, Watcher};
+use polymorphic_constant::{polymorphic_constant, reload_overrides};
+
+polymorphic_constant! {
+    #[tunable]
+    const SPEED: f32 | f64 = 4.0;
+    #[tunable]
+    const LIVES: u8 | nz_u32 = 3;
+}
+
+#[test]
+fn test_parse() {
+    let entries: Vec<_> = parse("# comment\n[table]\nSPEED = 2.5 # fast\n\nbroken\nNAME = \"text\"\nCOLOR = \"#ff0000\" # red").collect();
+
+    assert_eq!(entries, vec![("SPEED", "2.5"), ("NAME", "text"), ("COLOR", "#ff0000")]);
+}
+
+#[test]
+fn test_reload() {
+    reload_overrides!("SPEED = 2.5\nLIVES = 0\nOTHER = 1", [SPEED, LIVES]);
+
+    assert_eq!(SPEED.get().f32, 2.5);
+    assert_eq!(SPEED.get().f64, 2.5);
+    // 0 does not fit in nz_u32, so the entry is skipped
+    assert_eq!(LIVES.get().u8, 3);
+}
+
+#[test]
+fn test_watcher() {
+    // Unique to this process, as concurrent test runs share the temporary directory
+    let path = std::env::temp_dir().join(format!("polymorphic-constant-test-watcher-{}.toml", std::process::id()));
+    std::fs::write(&path, "LIVES = 7").unwrap();
+
+    let mut watcher = Watcher::new(&path);
+
+    assert_eq!(watcher.poll().unwrap().as_deref(), Some("LIVES = 7"));
+    assert_eq!(watcher.poll().unwrap(), None);
+
+    std::fs::remove_file(&path).unwrap();
+}
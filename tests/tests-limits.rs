@@ -0,0 +1,44 @@
+#![no_std]
+#![cfg(test)]
+
+use polymorphic_constant::polymorphic_limits;
+
+polymorphic_limits! {
+    /// The number of worker threads
+    pub const WORKERS: u8 | u32 | nz_u16 = 8 in 1..=64;
+    pub (crate) const GAIN: f32 | f64 = 1.0 in 0.0..=4.0;
+    const OFFSET: i8 | i64 = -3 in -10..=10;
+}
+
+mod inner {
+    use polymorphic_constant::polymorphic_limits;
+
+    polymorphic_limits! {
+        pub const RETRIES: u32 = 3 in 0..=5;
+    }
+}
+
+#[test]
+fn test_limits() {
+    assert_eq!(WORKERS.u32, 8);
+    assert_eq!((WORKERS::MIN.u8, WORKERS::MAX.u32), (1, 64));
+    assert_eq!(WORKERS::MIN.nz_u16.get(), 1);
+    assert_eq!((GAIN::MIN.f32, GAIN.f32, GAIN::MAX.f64), (0.0, 1.0, 4.0));
+    assert_eq!((OFFSET::MIN.i8, OFFSET::DEFAULT.i64), (-10, -3));
+    let max: u32 = inner::RETRIES::MAX.into();
+    assert_eq!(max, 5);
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq!(WORKERS::u8(0), 1);
+    assert_eq!(WORKERS::u32(100), 64);
+    assert_eq!(WORKERS::u32(12), 12);
+    assert_eq!(WORKERS::nz_u16(core::num::NonZeroU16::new(300).unwrap()).get(), 64);
+    assert_eq!(GAIN::f64(-0.5), 0.0);
+    assert_eq!(OFFSET::i64(i64::MIN), -10);
+    assert_eq!(inner::RETRIES::u32(9), 5);
+
+    const CLAMPED: u8 = WORKERS::u8(255);
+    assert_eq!(CLAMPED, 64);
+}
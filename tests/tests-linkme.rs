@@ -0,0 +1,34 @@
+#![cfg(all(test, feature = "linkme"))]
+
+use polymorphic_constant::{polymorphic_constant, ConstValue, Descriptor};
+
+#[linkme::distributed_slice]
+pub static CONSTANTS: [Descriptor];
+
+polymorphic_constant! {
+    #![register(crate::CONSTANTS)]
+
+    const WIDTH: u16 | u32 = 32;
+    const RATIO: f32 = 0.5;
+}
+
+mod other {
+    use polymorphic_constant::polymorphic_constant;
+
+    polymorphic_constant! {
+        #![register(crate::CONSTANTS)]
+
+        pub const DEPTH: u8 = 8;
+    }
+}
+
+#[test]
+fn test_register() {
+    let mut names: Vec<_> = CONSTANTS.iter().map(|descriptor| (descriptor.module, descriptor.name)).collect();
+    names.sort();
+
+    assert_eq!(names, [("tests_linkme", "RATIO"), ("tests_linkme", "WIDTH"), ("tests_linkme::other", "DEPTH")]);
+
+    let width = CONSTANTS.iter().find(|descriptor| descriptor.name == "WIDTH").unwrap();
+    assert_eq!(width.variants, [("u16", ConstValue::U16(32)), ("u32", ConstValue::U32(32))]);
+}
@@ -0,0 +1,46 @@
+#![no_std]
+#![cfg(test)]
+
+use polymorphic_constant::polymorphic_matrix;
+
+polymorphic_matrix! {
+    /// A Sobel kernel
+    #[flatten(SOBEL_FLAT)]
+    pub const SOBEL: i8 | i16 | i32 = [
+        [-1, 0, 1],
+        [-2, 0, 2],
+        [-1, 0, 1],
+    ];
+    pub (crate) const TAPS: f32 | f64 = [[0.25, 0.5, 0.25], [0.5, 0.0, -0.5]];
+}
+
+mod inner {
+    use polymorphic_constant::polymorphic_matrix;
+
+    polymorphic_matrix! {
+        pub const ROW: u8 | u64 = [[255, 0, 7, 1]];
+    }
+}
+
+#[test]
+fn test_matrix() {
+    let sobel: [[i8; 3]; 3] = SOBEL.into();
+    assert_eq!(sobel[1], [-2, 0, 2]);
+    assert_eq!((SOBEL::ROWS, SOBEL::COLUMNS), (3, 3));
+
+    let taps: [[f64; 3]; 2] = TAPS.into();
+    assert_eq!(taps[1][2], -0.5);
+    assert_eq!((TAPS::ROWS, TAPS::COLUMNS), (2, 3));
+
+    let row: [[u64; 4]; 1] = inner::ROW.into();
+    assert_eq!(row, [[255, 0, 7, 1]]);
+}
+
+#[test]
+fn test_flatten() {
+    let flat: [i32; 9] = SOBEL_FLAT.into();
+    assert_eq!(flat, [-1, 0, 1, -2, 0, 2, -1, 0, 1]);
+
+    let flat: [i16; 9] = SOBEL_FLAT.into();
+    assert_eq!(flat[3], -2);
+}
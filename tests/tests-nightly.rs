@@ -0,0 +1,42 @@
+#![cfg(all(test, feature = "nightly"))]
+#![feature(f16, f128)]
+#![no_std]
+
+use polymorphic_constant::{polymorphic_constant, CastMode, ConstValue};
+
+polymorphic_constant! {
+    #![lookup(fn lookup)]
+
+    const HALF: f16 | f32 | f64 | f128 = 0.5;
+    #[saturate]
+    const LARGE: f16 | f32 = 1e6;
+    const THIRD: f16 | f128 = 1.0 / 3.0;
+    const DOUBLE = HALF * 4.0;
+}
+
+#[test]
+fn test_half_and_quad() {
+    assert_eq!(HALF.f16, 0.5f16);
+    assert_eq!(HALF.f128, 0.5f128);
+    assert_eq!((LARGE.f16, LARGE.f32), (f16::MAX, 1e6));
+    assert_eq!(THIRD.f16, (1.0f64 / 3.0) as f16);
+    assert_eq!(DOUBLE.f16, 2.0);
+
+    assert_eq!(HALF.widest(), 0.5f128);
+    assert_eq!(HALF.narrowest(), 0.5f16);
+    assert_eq!(HALF.cast::<u8>(CastMode::Saturating), Some(0));
+    assert_eq!(lookup("THIRD").unwrap()[0], ("f16", ConstValue::F32(THIRD.f16 as f32)));
+}
+
+#[test]
+fn test_also_quad() {
+    polymorphic_constant! {
+        #[also(recip, squared)]
+        const TENTH: f64 | f128 = 0.1;
+    }
+
+    assert_eq!(TENTH_RECIP.f128, 1.0f128 / 0.1f128);
+    assert_eq!(TENTH_SQUARED.f128, 0.1f128 * 0.1f128);
+    assert_ne!(TENTH_SQUARED.f128, (0.1f64 * 0.1) as f128);
+    assert_eq!(TENTH_SQUARED.f64, 0.1f64 * 0.1);
+}
@@ -1,5 +1,6 @@
 #![no_std]
 #![cfg(test)]
+#![forbid(unsafe_code)]
 
 use polymorphic_constant::polymorphic_constant;
 
@@ -64,3 +65,1169 @@ fn test_template() {
 
     assert_eq!(times_pi(2.0), 6.283185307179586f64);
 }
+
+#[test]
+fn test_from_polymorphic() {
+    use polymorphic_constant::FromPolymorphic;
+
+    fn times_pi<T: core::ops::Mul<T> + FromPolymorphic<PI>>(value: T) -> <T as core::ops::Mul>::Output {
+        value * T::from_polymorphic(PI)
+    }
+
+    assert_eq!(times_pi(2.0f32), 6.2831855f32);
+    assert_eq!(u32::from_polymorphic(UINT), 2047);
+}
+
+#[test]
+fn test_non_zero() {
+    polymorphic_constant! {
+        const NZ: nz_u32 | nz_i64 = 2047;
+    };
+
+    assert_eq!(NZ.nz_u32, core::num::NonZeroU32::new(2047).unwrap());
+    assert_eq!(NZ.nz_i64.get(), 2047);
+}
+
+#[test]
+fn test_optional_non_zero() {
+    polymorphic_constant! {
+        const SOME: u8 | opt_nz_u8 | opt_nz_i64 = 12;
+        #[zero_is_none]
+        const NONE: u16 | opt_nz_u16 = 0;
+        #[zero_is_none]
+        #[saturate]
+        const CLAMPED: opt_nz_u8 | opt_nz_i8 = 300;
+        #[zero_is_none]
+        const COMPUTED: opt_nz_u32 = 4 - 4;
+        const DOUBLE = SOME * 2;
+    };
+
+    assert_eq!(SOME.opt_nz_u8, core::num::NonZeroU8::new(12));
+    assert_eq!(SOME.opt_nz_i64.unwrap().get(), 12);
+    let none: Option<core::num::NonZeroU16> = NONE.into();
+    assert_eq!(none, None);
+    assert_eq!((CLAMPED.opt_nz_u8.unwrap().get(), CLAMPED.opt_nz_i8.unwrap().get()), (255, 127));
+    assert_eq!(COMPUTED.opt_nz_u32, None);
+    assert_eq!(DOUBLE.opt_nz_i64.unwrap().get(), 24);
+    assert!(NONE.into_iter().all(|(_, value)| value == polymorphic_constant::ConstValue::U16(0)));
+}
+
+#[test]
+fn test_narrow_to() {
+    polymorphic_constant! {
+        const WIDE: i8 | i16 | i32 | i64 = 100;
+        #[narrow_from(WIDE)]
+        const NARROW: i16 | i64 = -3;
+    };
+
+    const NARROWED: NARROW = WIDE.narrow_to();
+    assert_eq!(NARROWED.i16, 100);
+    assert_eq!(NARROWED.i64, 100);
+    assert_eq!(NARROW.i16, -3);
+}
+
+#[test]
+fn test_sign() {
+    use polymorphic_constant::Sign;
+
+    polymorphic_constant! {
+        const NEGATIVE: i8 | i64 | nz_i32 = -4;
+        const NOTHING: u8 | i16 = 0;
+    };
+
+    const BACKWARDS: bool = NEGATIVE::IS_NEGATIVE;
+    assert!(BACKWARDS && NEGATIVE.is_negative());
+    assert!(!NEGATIVE.is_positive());
+    assert!(NOTHING.is_zero());
+    assert!(PI.is_positive());
+}
+
+mod units {
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    pub struct Meters(pub f64);
+
+    impl Meters {
+        pub const fn new(value: f64) -> Self {
+            Meters(value)
+        }
+    }
+}
+
+#[test]
+fn test_custom() {
+    const fn halved(value: f64) -> f64 {
+        value / 2.0
+    }
+
+    polymorphic_constant! {
+        #[custom(meters = crate::units::Meters::new)]
+        const RADIUS: f32 | f64 | meters = 6371.0;
+        #[custom(meters = units::Meters::new)]
+        const HALF: f64 | meters = halved(3.0);
+    };
+
+    assert_eq!(RADIUS.meters, units::Meters(6371.0));
+    assert_eq!(RADIUS.f32, 6371.0);
+    assert_eq!(HALF.meters, units::Meters(1.5));
+}
+
+#[test]
+fn test_custom_registered() {
+    polymorphic_constant! {
+        #![custom(meters = crate::units::Meters::new)]
+
+        const RADIUS: meters | f64 = 6371.0;
+        const ORBIT: f32 | meters | f64 = 42164.0;
+        const PLAIN: f32 | f64 = 0.5;
+    };
+
+    assert_eq!(RADIUS.meters, units::Meters(6371.0));
+    assert_eq!(RADIUS.f64, 6371.0);
+    assert_eq!(ORBIT.meters, units::Meters(42164.0));
+    assert_eq!(ORBIT.f32, 42164.0);
+    assert_eq!(PLAIN.f64, 0.5);
+}
+
+#[test]
+fn test_proof() {
+    const fn minutes() -> i64 {
+        60
+    }
+
+    polymorphic_constant! {
+        #[proof]
+        const DIVISOR: u32 | nz_u32 | nz_u8 = 60;
+        #[proof]
+        const COMPUTED: i64 | nz_i64 = minutes() * 60;
+    };
+
+    let () = _PROOF_DIVISOR_nz_u32;
+    let () = _PROOF_DIVISOR_nz_u8;
+    let () = _PROOF_COMPUTED_nz_i64;
+    assert_eq!(COMPUTED.nz_i64.get(), 3600);
+}
+
+#[test]
+fn test_raw_ident() {
+    polymorphic_constant! {
+        const r#type: u8 | u16 = 2;
+        pub const r#final: f32 | f64 = 0.5;
+    };
+
+    assert_eq!(r#type.u8, 2);
+    assert_eq!(r#type.u16, 2);
+    assert_eq!(r#final.f64, 0.5);
+}
+
+#[test]
+fn test_widest() {
+    polymorphic_constant! {
+        const MIXED: u8 | i64 | u16 = 12;
+    };
+
+    assert_eq!(PI.widest(), 3.141592653589793f64);
+    assert_eq!(PI.narrowest(), 3.141592653589793f32);
+    assert_eq!(MIXED.widest(), 12i64);
+    assert_eq!(MIXED.narrowest(), 12u8);
+}
+
+#[test]
+fn test_widen() {
+    polymorphic_constant! {
+        const SMALL: i8 | i32 = -100;
+        const LARGE: u64 = 18_446_744_073_709_551_615;
+        const RATIO: f32 = 0.25;
+    };
+
+    const SUM: f64 = SMALL.to_f64() + RATIO.to_f64();
+    const TOTAL: i128 = SMALL.to_i128() + LARGE.to_i128();
+
+    assert_eq!(SUM, -99.75);
+    assert_eq!(TOTAL, u64::MAX as i128 - 100);
+}
+
+#[test]
+fn test_iter() {
+    use polymorphic_constant::ConstValue;
+
+    let mut iter = INT.into_iter();
+
+    assert_eq!(iter.next(), Some(("i16", ConstValue::I16(-2047))));
+    assert_eq!(iter.next(), Some(("i32", ConstValue::I32(-2047))));
+    assert_eq!(iter.next(), Some(("i64", ConstValue::I64(-2047))));
+    assert_eq!(iter.next(), Some(("isize", ConstValue::Isize(-2047))));
+    assert_eq!(iter.next(), None);
+    assert_eq!(PI.into_iter().count(), 2);
+}
+
+#[test]
+fn test_also() {
+    polymorphic_constant! {
+        #[also(recip = QUARTER_RECIP, squared = QUARTER_SQUARED, halved = QUARTER_HALF)]
+        const QUARTER: f32 | f64 = 0.25;
+        #[also(squared = TWELVE_SQUARED, halved = TWELVE_HALF)]
+        const TWELVE: u8 | i64 = 12;
+    };
+
+    assert_eq!(QUARTER_RECIP.f32, 4.0);
+    assert_eq!(QUARTER_RECIP.f64, 4.0);
+    assert_eq!(QUARTER_SQUARED.f64, 0.0625);
+    assert_eq!(QUARTER_HALF.f32, 0.125);
+    assert_eq!(TWELVE_SQUARED.u8, 144);
+    assert_eq!(TWELVE_SQUARED.i64, 144);
+    assert_eq!(TWELVE_HALF.u8, 6);
+}
+
+#[test]
+#[deny(missing_docs, clippy::excessive_precision)]
+fn test_lints() {
+    polymorphic_constant! {
+        /// Precise enough for f64, narrowed to f32 on purpose
+        #[also(halved = TAU_HALF)]
+        pub const TAU: f32 | f64 = 6.283185307179586;
+    };
+
+    assert_eq!(TAU.f32, core::f32::consts::TAU);
+    assert_eq!(TAU_HALF.f64, core::f64::consts::PI);
+}
+
+#[test]
+fn test_alias() {
+    type Scalar = f32;
+    type Index = core::num::NonZeroU16;
+
+    polymorphic_constant! {
+        #![alias(Scalar = f32, Index = nz_u16)]
+        #![lookup(fn find)]
+
+        const GRAVITY: Scalar | f64 = 9.81;
+        const FIRST: u8 | Index = 1;
+        const DOUBLE = GRAVITY * GRAVITY;
+    };
+
+    let gravity: Scalar = GRAVITY.into();
+    let first: Index = FIRST.into();
+    assert_eq!(gravity, 9.81);
+    assert_eq!(first.get(), 1);
+    assert_eq!(DOUBLE.f64, 9.81 * 9.81);
+    assert_eq!(find("FIRST").unwrap().len(), 2);
+}
+
+polymorphic_constant! {
+    #![version(5)]
+
+    #[renamed_from(OLD_WIDTH, since = 5)]
+    pub const WIDTH_PX: u16 | u32 = 1280;
+    #[removed(since = 4)]
+    const OLD_HEIGHT: u16 = 720;
+    #[renamed_from(OLD_DOUBLE_WIDTH, since = 4)]
+    const DOUBLE_WIDTH_PX = WIDTH_PX + WIDTH_PX;
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_version() {
+    let width: u32 = OLD_WIDTH.into();
+    let alias: OLD_WIDTH = WIDTH_PX;
+    assert_eq!(width, 1280);
+    assert_eq!(alias.u16, 1280);
+    assert_eq!(OLD_HEIGHT.u16, 720);
+    assert_eq!(OLD_DOUBLE_WIDTH.u32, DOUBLE_WIDTH_PX.u32);
+}
+
+#[test]
+fn test_sort_fields() {
+    polymorphic_constant! {
+        #[sort_fields]
+        #[repr(C)]
+        const SORTED: u8 | i64 | u16 = 255;
+        #[repr(C)]
+        const UNSORTED: u8 | i64 | u16 = 255;
+        #[sort_fields]
+        const ALL_SIZES: u8 | i64 | u16 | i128 | usize | u32 = 255;
+    };
+
+    assert_eq!(core::mem::size_of_val(&SORTED), 16);
+    assert_eq!(core::mem::size_of_val(&UNSORTED), 24);
+
+    let mut iter = ALL_SIZES.into_iter().map(|(name, _)| name);
+    assert_eq!(iter.next(), Some("i128"));
+    assert_eq!(iter.next(), Some("i64"));
+    assert_eq!(iter.next(), Some("usize"));
+    assert_eq!(iter.next(), Some("u32"));
+    assert_eq!(iter.next(), Some("u16"));
+    assert_eq!(iter.next(), Some("u8"));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_impls() {
+    use core::convert::TryFrom;
+    use polymorphic_constant::ConstValue;
+
+    polymorphic_constant! {
+        #[impls(from, try_from)]
+        const LIMIT: u8 | u64 = 200;
+    };
+
+    let limit: u8 = LIMIT.into();
+    assert_eq!(limit, 200);
+    assert_eq!(u64::from(LIMIT), 200);
+    assert!(LIMIT::try_from(ConstValue::U64(200)).is_ok());
+    assert!(LIMIT::try_from(ConstValue::U16(200)).is_err());
+}
+
+const fn kibibytes(count: u64) -> u64 {
+    count * 1024
+}
+
+const fn ratio(numerator: f64, denominator: f64) -> f64 {
+    numerator / denominator
+}
+
+#[test]
+fn test_computed() {
+    polymorphic_constant! {
+        #![lookup(fn find)]
+        const BUFFER: u16 | i32 | usize = kibibytes(16);
+        const HALF: f32 | f64 = ratio(1.0, 2.0);
+        const OFFSET: i8 | i64 = 5 - kibibytes(8) as i64 / 1024;
+        #[also(halved = SIX_HALF)]
+        const SIX: u8 | u32 = 6;
+    };
+
+    assert_eq!(BUFFER.u16, 16384);
+    assert_eq!(BUFFER.usize, 16384);
+    assert_eq!(HALF.f32, 0.5);
+    assert_eq!(OFFSET.i8, -3);
+    assert_eq!(SIX_HALF.u32, 3);
+    assert!(find("BUFFER").is_some());
+}
+
+#[test]
+fn test_ffi() {
+    use core::ffi::{c_int, c_long, c_uchar};
+    use polymorphic_constant::ConstValue;
+
+    polymorphic_constant! {
+        #![lookup(fn find)]
+        const BUFFER_SIZE: c_int | c_long | c_size_t | c_uchar = 255;
+        const PAGE_SIZE: c_int | c_ulonglong = kibibytes(4);
+    };
+
+    let int: c_int = BUFFER_SIZE.c_int;
+    let long: c_long = BUFFER_SIZE.c_long;
+    let size: usize = BUFFER_SIZE.c_size_t;
+    let uchar: c_uchar = BUFFER_SIZE.c_uchar;
+    assert_eq!((int, long, size, uchar), (255, 255, 255, 255));
+    assert_eq!(PAGE_SIZE.c_ulonglong, 4096);
+    assert_eq!(BUFFER_SIZE.narrowest(), 255);
+    assert_eq!(find("BUFFER_SIZE").unwrap()[3], ("c_uchar", ConstValue::U8(255)));
+    assert_eq!(find("BUFFER_SIZE").unwrap()[0], ("c_int", ConstValue::from(int)));
+}
+
+#[test]
+fn test_ffi_range() {
+    use core::ffi::{c_char, c_long, c_ulong};
+
+    // The limits shared by every target
+    polymorphic_constant! {
+        const LONG_MAX: c_long = 2_147_483_647;
+        const LONG_MIN: c_long = -2_147_483_648;
+        const ULONG_MAX: c_ulong = 4_294_967_295;
+        const CHAR_MAX: c_char = 127;
+    };
+    assert_eq!((LONG_MAX.c_long, LONG_MIN.c_long), (2_147_483_647, -2_147_483_648));
+    assert_eq!(ULONG_MAX.c_ulong, 4_294_967_295);
+    assert_eq!(CHAR_MAX.c_char, 127);
+
+    // `long` is 64 bits wide on 64-bit Unix, and 32 bits wide on Windows and 32-bit targets,
+    // where these literals fail to compile
+    #[cfg(all(unix, target_pointer_width = "64"))]
+    {
+        polymorphic_constant! {
+            const WIDE_MAX: c_long = 9_223_372_036_854_775_807;
+            const WIDE_MIN: c_long = -9_223_372_036_854_775_808;
+            const WIDE_UMAX: c_ulong = 18_446_744_073_709_551_615;
+        };
+        assert_eq!((WIDE_MAX.c_long, WIDE_MIN.c_long), (c_long::MAX, c_long::MIN));
+        assert_eq!(WIDE_UMAX.c_ulong, c_ulong::MAX);
+    }
+    #[cfg(any(windows, target_pointer_width = "32"))]
+    {
+        assert_eq!((LONG_MAX.c_long, LONG_MIN.c_long), (c_long::MAX, c_long::MIN));
+        assert_eq!(ULONG_MAX.c_ulong, c_ulong::MAX);
+    }
+
+    // `char` is signed on x86, and unsigned on ARM and most other Linux targets, where `-128` fails to compile
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        polymorphic_constant! {
+            const CHAR_MIN: c_char = -128;
+        };
+        assert_eq!(CHAR_MIN.c_char, c_char::MIN);
+    }
+    #[cfg(all(target_os = "linux", any(target_arch = "aarch64", target_arch = "arm")))]
+    {
+        polymorphic_constant! {
+            const CHAR_UMAX: c_char = 255;
+        };
+        assert_eq!(CHAR_UMAX.c_char, c_char::MAX);
+    }
+}
+
+mod variants {
+    polymorphic_constant::polymorphic_constant! {
+        pub const MASK: u8 | i16 | c_int = 255;
+    }
+}
+
+#[test]
+fn test_has_variant() {
+    use polymorphic_constant::has_variant;
+
+    const _: () = assert!(has_variant!(variants::MASK, u8));
+
+    assert!(has_variant!(variants::MASK, i16));
+    assert!(has_variant!(variants::MASK, c_int));
+    assert!(!has_variant!(variants::MASK, u16));
+    assert!(!has_variant!(UINT, u8));
+    assert!(has_variant!(PI, f64));
+}
+
+#[test]
+fn test_variant_of() {
+    use polymorphic_constant::{ConstValue, NumType};
+
+    polymorphic_constant! {
+        const WIDTH: i16 | usize | char = 64;
+    };
+
+    assert_eq!(variants::MASK::variant_of::<u8>(), Some(NumType::U8));
+    assert_eq!(variants::MASK::variant_of::<core::ffi::c_int>(), Some(NumType::I32));
+    assert_eq!(WIDTH::variant_of::<usize>(), Some(NumType::Usize));
+    assert_eq!(WIDTH::variant_of::<char>(), None);
+    assert_eq!(WIDTH::variant_of::<u16>(), None);
+    assert_eq!(ConstValue::I16(WIDTH.i16).num_type(), NumType::I16);
+}
+
+#[test]
+fn test_units() {
+    polymorphic_constant! {
+        #![lookup(fn find)]
+        #[units(mm = WIDTH_MM, cm = WIDTH_CM, inch = WIDTH_IN)]
+        const WIDTH: f32 | f64 = 2.54 m;
+        #[units(ms = TIMEOUT_MS, min = TIMEOUT_MIN)]
+        const TIMEOUT: u16 | u64 = 60 s;
+    };
+
+    assert_eq!(WIDTH.f64, 2.54);
+    assert_eq!(WIDTH_MM.f64, 2540.0);
+    assert_eq!(WIDTH_CM.f32, 254.0);
+    assert_eq!(WIDTH_IN.f64, 100.0);
+    assert_eq!(TIMEOUT_MIN.u16, 1);
+    assert_eq!(TIMEOUT_MS.u16, 60_000);
+    assert!(find("TIMEOUT").is_some());
+    assert_eq!(WIDTH::unit(), "m");
+    assert_eq!(WIDTH_IN::unit(), "inch");
+}
+
+#[test]
+fn test_unit_label() {
+    polymorphic_constant! {
+        #[unit("m/s^2")]
+        const GRAVITY: f32 | f64 = 9.80665;
+        #[unit("m")]
+        #[units(mm = DEPTH_MM)]
+        const DEPTH: u16 | u32 = 2 m;
+    };
+
+    assert_eq!(GRAVITY::unit(), "m/s^2");
+    assert_eq!(DEPTH::unit(), "m");
+    assert_eq!(DEPTH_MM::unit(), "mm");
+}
+
+#[test]
+fn test_pack() {
+    polymorphic_constant! {
+        #![lookup(fn find)]
+        const KIND: u8 | i32 = 5;
+        const FLAGS: u8 = 0b101;
+        const LENGTH: u16 | u64 = 1500;
+        const HEADER: u32 | u64 | i64 = pack(kind: KIND, 4; flags: FLAGS, 3; length: LENGTH, 25);
+    };
+
+    assert_eq!(HEADER.u32, (5 << 28) | (0b101 << 25) | 1500);
+    assert_eq!(HEADER.i64, HEADER.u64 as i64);
+    assert!(find("HEADER").is_some());
+}
+
+const KERNEL: [f64; 5] = [0.1, 0.2, 0.4, 0.2, 0.1];
+const WEIGHTS: [i64; 4] = [3, -8, 12, 1];
+
+#[test]
+fn test_aggregate() {
+    polymorphic_constant! {
+        const KERNEL_SUM: f32 | f64 = sum(KERNEL);
+        const KERNEL_MEAN: f32 | f64 = mean(KERNEL);
+        const WEIGHTS_SUM: u8 | i32 = sum(WEIGHTS);
+        const WEIGHTS_MAX: u8 | i64 = max_elem(WEIGHTS);
+        const WEIGHTS_MIN: i8 | i64 = min_elem(WEIGHTS);
+        const SIXTEEN_K: u16 | u32 = kibibytes(16);
+    };
+
+    assert_eq!(KERNEL_SUM.f64, 1.0);
+    assert_eq!(KERNEL_SUM.f32, 1.0);
+    assert_eq!(KERNEL_MEAN.f64, 0.2);
+    assert_eq!(WEIGHTS_SUM.u8, 8);
+    assert_eq!(WEIGHTS_MAX.u8, 12);
+    assert_eq!(WEIGHTS_MIN.i8, -8);
+    assert_eq!(SIXTEEN_K.u16, 16384);
+}
+
+#[test]
+fn test_cfg() {
+    use polymorphic_constant::ConstValue;
+
+    polymorphic_constant! {
+        #![lookup(fn find)]
+        #[cfg(any())]
+        #[also(halved = LIMIT_HALF)]
+        #[impls(from)]
+        const LIMIT: u8 | u16 = 100;
+        #[cfg(not(any()))]
+        #[also(halved = LIMIT_HALF)]
+        #[impls(from)]
+        const LIMIT: u8 | u16 = 200;
+        #[cfg_attr(not(any()), derive(Hash))]
+        #[cfg_attr(not(any()), doc = "Hashable")]
+        const HASHED: u8 = 1;
+    };
+
+    fn is_hash<T: core::hash::Hash>(_: T) -> bool {
+        true
+    }
+
+    assert_eq!(u16::from(LIMIT), 200);
+    assert_eq!(LIMIT_HALF.u8, 100);
+    assert_eq!(find("LIMIT").unwrap()[1], ("u16", ConstValue::U16(200)));
+    assert!(is_hash(HASHED));
+}
+
+#[test]
+fn test_group() {
+    polymorphic_constant! {
+        #![group(const ALL_SIZES: u16 | u32)]
+        const HEIGHT: u16 | u32 | u64 = 16;
+        const WIDTH: u16 | u32 = 32;
+        #[cfg(any())]
+        const HIDDEN: u16 | u32 = 48;
+        const DEPTH: u16 | u32 = 64;
+    };
+
+    assert_eq!(ALL_SIZES.u16, &[16, 32, 64]);
+    assert_eq!(ALL_SIZES.u32.iter().sum::<u32>(), 112);
+}
+
+mod consts {
+    pub mod sizes {
+        polymorphic_constant::polymorphic_constant! {
+            #![prelude(pub mod prelude)]
+            pub const WIDTH: u16 | u32 = 32;
+            pub(crate) const HEIGHT: u16 | u32 = 16;
+            pub(super) const DEPTH: u8 = 8;
+            const MARGIN: u8 = 2;
+            #[cfg(any())]
+            pub const HIDDEN: u8 = 1;
+        }
+
+        pub(crate) const TOTAL_MARGIN: u8 = prelude::MARGIN.u8 * 2;
+    }
+
+    pub(super) const DEPTH: u8 = sizes::prelude::DEPTH.widest();
+}
+
+#[test]
+fn test_prelude() {
+    use consts::sizes::prelude::*;
+
+    let width: u32 = WIDTH.into();
+    let height: u16 = HEIGHT.into();
+    assert_eq!((width, height), (32, 16));
+    assert_eq!(consts::DEPTH, 8);
+    assert_eq!(consts::sizes::TOTAL_MARGIN, 4);
+}
+
+#[test]
+fn test_round() {
+    polymorphic_constant! {
+        #[round(up)]
+        const TENTH_UP: f32 | f64 = 0.1;
+        #[round(down)]
+        const TENTH_DOWN: f32 | f64 = 0.1;
+        #[round(toward_zero)]
+        const MINUS_TENTH: f32 | f64 = -0.1;
+        #[round(nearest)]
+        const TENTH: f32 | f64 = 0.1;
+        #[round(down)]
+        const HALF: f32 | f64 = 0.5;
+        #[round(down)]
+        const THIRD: f32 | f64 = ratio(1.0, 3.0);
+        #[round(up)]
+        const THIRD_UP: f32 | f64 = ratio(1.0, 3.0);
+        #[round(up)]
+        const COUNT: u8 | u16 = 3;
+        #[round(up)]
+        const TINY: f32 | f64 = 1e-50;
+        #[round(toward_zero)]
+        const SCALED: f32 | f64 = 1_000.000_1e-3;
+    };
+
+    assert_eq!(TENTH_UP.f32, 0.1);
+    assert_eq!(TENTH_UP.f64, 0.1);
+    assert_eq!(TENTH_DOWN.f32, f32::from_bits(0.1f32.to_bits() - 1));
+    assert_eq!(TENTH_DOWN.f64, f64::from_bits(0.1f64.to_bits() - 1));
+    assert_eq!(MINUS_TENTH.f32, -TENTH_DOWN.f32);
+    assert_eq!(MINUS_TENTH.f64, -TENTH_DOWN.f64);
+    assert_eq!(TENTH.f32, 0.1);
+    assert_eq!(HALF.f32, 0.5);
+    assert!((THIRD.f32 as f64) < 1.0 / 3.0);
+    assert!((THIRD_UP.f32 as f64) > 1.0 / 3.0);
+    assert_eq!(THIRD.f64, 1.0 / 3.0);
+    assert_eq!(COUNT.u16, 3);
+    assert_eq!(TINY.f32, f32::from_bits(1));
+    assert_eq!(TINY.f64, 1e-50);
+    assert_eq!(SCALED.f32, 1.0);
+    assert_eq!(SCALED.f64, f64::from_bits(1.0000001f64.to_bits() - 1));
+}
+
+#[test]
+fn test_smallest() {
+    const BASE: i64 = 70_000;
+
+    polymorphic_constant! {
+        const PORT: smallest_uint = 300;
+        const DELTA: smallest_int | i64 = -129;
+        const LIMIT: smallest_uint | smallest_int = 200;
+        #[flat(smallest_uint = LARGE_VALUE)]
+        const LARGE: smallest_uint = BASE * 2;
+    };
+
+    let port: u16 = PORT.smallest_uint;
+    let delta: i16 = DELTA.smallest_int;
+    let limit: (u8, i16) = (LIMIT.smallest_uint, LIMIT.smallest_int);
+    let large: u32 = LARGE_VALUE;
+    assert_eq!(port, 300);
+    assert_eq!(delta, -129);
+    assert_eq!(limit, (200, 200));
+    assert_eq!(large, 140_000);
+}
+
+// Each constant generates a test comparing its variants with the initializer computed at runtime
+polymorphic_constant! {
+    const SIDE: u8 | u32 = 12;
+    const HALF: f32 | f64 = 0.5;
+    const THIRD: f32 | f64 = 0.3333333333333333;
+    #[reference_test(test_reference_literal, tolerance = 1e-7)]
+    const FIFTH: f32 | f64 = 0.2;
+    #[reference_test(test_reference_derived)]
+    const AREA = SIDE * SIDE;
+    #[reference_test(test_reference_derived_float, tolerance = 1e-7)]
+    const SIXTH = HALF * THIRD;
+    #[strict]
+    #[reference_test(test_reference_strict)]
+    const PERIMETER = SIDE * 4;
+}
+
+#[test]
+fn test_warn_precision_loss() {
+    // Exact values compile without warnings
+    polymorphic_constant! {
+        #[warn_precision_loss]
+        const HALF: f32 | f64 = 0.5;
+        #[warn_precision_loss]
+        const QUARTER: f32 | f64 = 1.0 / 4.0;
+    };
+
+    assert_eq!(HALF.f32, 0.5);
+    assert_eq!(QUARTER.f32, 0.25);
+}
+
+#[test]
+fn test_cfg_value() {
+    polymorphic_constant! {
+        #[cfg_value(test, 16)]
+        const BUFFER_SIZE: u16 | usize = 4096;
+        #[cfg_value(not(test), 1)]
+        #[cfg_value(test, 1 + 1)]
+        const RETRIES: u8 | u32 = 5;
+        #[cfg_value(any(), 0.0)]
+        const TIMEOUT: f32 | f64 = 2.5;
+    };
+
+    assert_eq!(BUFFER_SIZE.u16, 16);
+    assert_eq!(BUFFER_SIZE.usize, 16);
+    assert_eq!(RETRIES.u32, 2);
+    assert_eq!(TIMEOUT.f64, 2.5);
+}
+
+#[test]
+fn test_cfg_initializer() {
+    const fn seed() -> u64 {
+        0xFFFF_FFFF
+    }
+
+    polymorphic_constant! {
+        const SEED: u32 | u64 = if cfg(target_endian = "big") { 0x1234_5678 } else { 0x7856_3412 };
+        pub (crate) const LEVEL: i8 | i16 = if cfg(not(test)) { 3 } else if cfg(test) { -1 } else { 0 };
+        const MAGIC: u32 = native_of(0xCAFE_BABE, 0xBEBA_FECA);
+        const MASK: u32 | u64 = native_of(seed(), seed() >> 8);
+    };
+
+    assert_eq!(SEED.u32.to_ne_bytes(), [0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(LEVEL.i16, -1);
+    assert_eq!(MAGIC.u32.to_ne_bytes(), [0xBE, 0xBA, 0xFE, 0xCA]);
+    assert_eq!(MASK.u64, if cfg!(target_endian = "big") { 0xFF_FFFF } else { 0xFFFF_FFFF });
+}
+
+#[test]
+fn test_derived() {
+    polymorphic_constant! {
+        const WIDTH: u8 | u16 | u32 = 20;
+        pub const HEIGHT: u32 | u16 = 10;
+        /// Only has the variants common to `WIDTH` and `HEIGHT`
+        const AREA = WIDTH * HEIGHT + (WIDTH.u8 as i128);
+        const HALF_PI: f32 | f64 = 1.5707963267948966;
+        const PI = HALF_PI * 2.0;
+    };
+
+    assert_eq!(AREA.u16, 220);
+    assert_eq!(AREA.u32, 220);
+    let narrowest: u16 = AREA.narrowest();
+    assert_eq!(narrowest, 220);
+    assert_eq!(PI.f32, core::f32::consts::PI);
+    assert_eq!(PI.f64, core::f64::consts::PI);
+}
+
+#[test]
+fn test_prune_unfit() {
+    use polymorphic_constant::{has_variant, Pruned};
+
+    polymorphic_constant! {
+        #[prune_unfit]
+        const OFFSET: u8 | i8 | i16 | u64 = -200;
+        #[prune_unfit]
+        const SCALE: f32 | f64 = 1e100;
+    };
+
+    assert_eq!(OFFSET.i16, -200);
+    assert_eq!(OFFSET.u8, Pruned);
+    assert_eq!(OFFSET::PRUNED, ["u8", "i8", "u64"]);
+    assert!(has_variant!(OFFSET, i16));
+    assert!(!has_variant!(OFFSET, u64));
+    assert_eq!(SCALE.f64, 1e100);
+    assert_eq!(SCALE::PRUNED, ["f32"]);
+}
+
+#[test]
+fn test_strict() {
+    const fn pixels() -> u64 {
+        3_000_000_001
+    }
+
+    polymorphic_constant! {
+        const PIXELS: u64 | f64 = pixels();
+        #[saturate]
+        const LIMIT: u8 | u16 = 300;
+        /// Computed in `f64`, which rounds the product
+        const LOOSE = PIXELS * PIXELS;
+        #[strict]
+        const EXACT = PIXELS * PIXELS;
+        #[strict]
+        const MARGIN = LIMIT - 200;
+        const HALF: f32 | f64 = 0.5;
+        #[strict]
+        const DOUBLE = HALF * 2.0;
+    };
+
+    assert_eq!(LOOSE.u64, 9_000_000_006_000_000_000);
+    assert_eq!(EXACT.u64, 9_000_000_006_000_000_001);
+    assert_eq!(MARGIN.u8, 100);
+    assert_eq!(DOUBLE.f32, 1.0);
+}
+
+#[test]
+fn test_from_file() {
+    polymorphic_constant! {
+        const MAGIC: u32 | u64 = from_file("calib.bin", 0, u32_le);
+        const GAIN: u16 | i32 = from_file("calib.bin", 4, u16_be);
+        const OFFSET: i8 | u8 = from_file("calib.bin", 6, u8);
+        const SCALE: f32 | f64 = from_file("calib.bin", 7, f64_le);
+    };
+
+    assert_eq!(MAGIC.u64, 0xdead_beef);
+    assert_eq!(GAIN.i32, 300);
+    assert_eq!(OFFSET.i8, 7);
+    assert_eq!(SCALE.f32, 2.5);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated() {
+    polymorphic_constant! {
+        #[deprecated(note = "use NEW_LIMIT")]
+        #[also(halved = HALF_LIMIT)]
+        const LIMIT: f32 | f64 = 100.0;
+    };
+
+    let limit: f64 = LIMIT.into();
+    assert_eq!(limit, 100.0);
+    assert_eq!(HALF_LIMIT.f32, 50.0);
+}
+
+#[test]
+fn test_lazy() {
+    use polymorphic_constant::Lazy;
+
+    const ANSWER: Lazy<u64> = Lazy::new(|| 6 * 7);
+    let copy = ANSWER;
+
+    assert_eq!(copy.get(), 42);
+    assert_eq!(copy, ANSWER);
+}
+
+#[test]
+fn test_to_str() {
+    polymorphic_constant! {
+        #[to_str(PI_STR)]
+        #[to_str(PI_ROUNDED, precision = 4)]
+        const PI: f32 | f64 = 3.141_592_653_589_793;
+        #[to_str(SMALL_STR)]
+        #[to_str(SMALL_ROUNDED, precision = 2)]
+        const SMALL: f64 = 1.5e-3;
+        #[to_str(NINES_ROUNDED, precision = 0)]
+        const NINES: f64 = 99.5;
+        #[to_str(OFFSET_STR, precision = 1)]
+        const OFFSET: i32 | i64 = -42;
+        #[to_str(MASK_STR)]
+        const MASK: u32 = 0xFF_FF;
+    };
+
+    assert_eq!(PI_STR, "3.141592653589793");
+    assert_eq!(PI_ROUNDED, "3.1416");
+    assert_eq!(SMALL_STR, "0.0015");
+    assert_eq!(SMALL_ROUNDED, "0.00");
+    assert_eq!(NINES_ROUNDED, "100");
+    assert_eq!(OFFSET_STR, "-42.0");
+    assert_eq!(MASK_STR, "0xFFFF");
+}
+
+#[test]
+fn test_cast() {
+    use polymorphic_constant::{CastMode, ConstValue};
+
+    polymorphic_constant! {
+        const BIG: u16 | u32 = 300;
+        const NEGATIVE: i8 | i64 = -3;
+        const HALF: f32 | f64 = 2.5;
+    };
+
+    assert_eq!(BIG.cast::<u8>(CastMode::Checked), None);
+    assert_eq!(BIG.cast::<u8>(CastMode::Saturating), Some(255));
+    assert_eq!(BIG.cast::<u8>(CastMode::Wrapping), Some(44));
+    assert_eq!(BIG.cast::<i128>(CastMode::Exact), Some(300));
+    assert_eq!(NEGATIVE.cast::<u64>(CastMode::Saturating), Some(0));
+    assert_eq!(NEGATIVE.cast::<u8>(CastMode::Wrapping), Some(253));
+    assert_eq!(NEGATIVE.cast::<f32>(CastMode::Exact), Some(-3.0));
+
+    assert_eq!(HALF.cast::<i8>(CastMode::Checked), Some(2));
+    assert_eq!(HALF.cast::<i8>(CastMode::Exact), None);
+    assert_eq!(HALF.cast::<f32>(CastMode::Exact), Some(2.5));
+
+    assert_eq!(ConstValue::F64(-1e10).cast::<i32>(CastMode::Checked), None);
+    assert_eq!(ConstValue::F64(-1e10).cast::<i32>(CastMode::Saturating), Some(i32::MIN));
+    assert_eq!(ConstValue::F64(-257.9).cast::<u8>(CastMode::Wrapping), Some(255));
+    assert_eq!(ConstValue::F64(f64::NAN).cast::<u8>(CastMode::Checked), None);
+    assert_eq!(ConstValue::F64(1e300).cast::<f32>(CastMode::Checked), None);
+    assert_eq!(ConstValue::F64(1e300).cast::<f32>(CastMode::Saturating), Some(f32::MAX));
+    assert_eq!(ConstValue::F64(0.1).cast::<f32>(CastMode::Exact), None);
+    assert_eq!(ConstValue::U128(u128::MAX).cast::<f64>(CastMode::Exact), None);
+    assert_eq!(ConstValue::U128(u128::MAX).cast::<f32>(CastMode::Saturating), Some(f32::MAX));
+    assert_eq!(ConstValue::F64(1.7e38).cast::<u128>(CastMode::Checked), Some(1.7e38 as u128));
+}
+
+#[test]
+fn test_real() {
+    use polymorphic_constant::Real;
+
+    polymorphic_constant! {
+        #[also(halved = HALF_GRAVITY)]
+        const GRAVITY: f64 | real = 9.80665;
+        const DOUBLE_GRAVITY = GRAVITY * 2.0;
+    };
+
+    let gravity: Real = GRAVITY.real;
+    assert_eq!(gravity, 9.80665 as Real);
+    assert_eq!(HALF_GRAVITY.real, 4.903325 as Real);
+    assert_eq!(DOUBLE_GRAVITY.real, 19.6133 as Real);
+    assert_eq!(GRAVITY.widest(), 9.80665);
+}
+
+#[test]
+fn test_ip() {
+    use core::net::{Ipv4Addr, SocketAddr};
+
+    polymorphic_constant! {
+        #[socket_addr(LOCAL = PORT)]
+        const LOCALHOST: u32 | ipv4 = 0x7f00_0001;
+        const PORT: u16 | u32 = 8080;
+        const NEXT = LOCALHOST + 1;
+        #[socket_addr(ANY = PORT)]
+        const UNSPECIFIED: ipv4 | ipv6 = 0;
+    };
+
+    let localhost: Ipv4Addr = LOCALHOST.into();
+    assert_eq!(localhost, Ipv4Addr::LOCALHOST);
+    assert_eq!(NEXT.ipv4, Ipv4Addr::new(127, 0, 0, 2));
+    assert_eq!(LOCAL, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 8080));
+    assert!(ANY.is_ipv6());
+    assert_eq!(UNSPECIFIED.ipv6.octets(), [0; 16]);
+}
+
+#[test]
+fn test_duration() {
+    use core::time::Duration;
+
+    polymorphic_constant! {
+        #[unit("ms")]
+        const TIMEOUT: u64 | duration = 250;
+        #[units(ms = RETRY_DELAY_MS)]
+        const RETRY_DELAY: u32 | duration = 3 min;
+        #[unit("ns")]
+        const LONGEST: duration = 18_446_744_073_709_551_615;
+    };
+
+    let timeout: Duration = TIMEOUT.into();
+    assert_eq!(timeout, Duration::from_millis(250));
+    assert_eq!(TIMEOUT.u64, 250);
+    assert_eq!(RETRY_DELAY.duration, Duration::from_secs(180));
+    assert_eq!((RETRY_DELAY_MS.u32, RETRY_DELAY_MS.duration), (180_000, Duration::from_secs(180)));
+    assert_eq!(LONGEST.duration, Duration::from_nanos(u64::MAX));
+}
+
+#[test]
+fn test_pointer() {
+    polymorphic_constant! {
+        #[sort_fields]
+        const GPIO_BASE: usize | ptr_u32 | u32 | ptr_u8 = 0x4002_1000;
+        #[const_ptr]
+        const DEVICE_ID: ptr_u16 | usize = 0x1FFF_7A10;
+        const GPIO_ODR = GPIO_BASE + 0x14;
+    };
+
+    let gpio: *mut u32 = GPIO_BASE.ptr_u32;
+    assert_eq!(gpio as usize, 0x4002_1000);
+    assert_eq!(GPIO_BASE.ptr_u8 as usize, GPIO_BASE.usize);
+    let device_id: *const u16 = DEVICE_ID.ptr_u16;
+    assert_eq!(device_id as usize, DEVICE_ID.usize);
+    assert_eq!(GPIO_ODR.ptr_u32 as usize, 0x4002_1014);
+}
+
+#[test]
+fn test_limbs() {
+    const fn wide() -> u128 {
+        1 << 70
+    }
+
+    polymorphic_constant! {
+        const P256: limbs = 0xffff_ffff_0000_0001_0000_0000_0000_0000_0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
+        const SMALL: u8 | limbs = 7;
+        const WIDE: u128 | limbs = wide();
+    };
+
+    assert_eq!(P256.limbs, [u64::MAX, 0xffff_ffff, 0, 0xffff_ffff_0000_0001]);
+    assert_eq!(P256::__LIMBS, 4);
+    let small: [u64; 1] = SMALL.into();
+    assert_eq!(small, [7]);
+    assert_eq!(WIDE.limbs, [0, 64]);
+}
+
+#[test]
+fn test_bytes() {
+    const fn port() -> u64 {
+        8080
+    }
+
+    polymorphic_constant! {
+        const MAGIC: u32 | u64 | le_bytes | be_bytes | ne_bytes = 0x1234_5678;
+        #[bytes(i16)]
+        const OFFSET: i64 | le_bytes = -2;
+        const PORT: u16 | be_bytes = port();
+    };
+
+    assert_eq!(MAGIC.le_bytes, [0x78, 0x56, 0x34, 0x12, 0, 0, 0, 0]);
+    assert_eq!(MAGIC.be_bytes, [0, 0, 0, 0, 0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(MAGIC.ne_bytes, 0x1234_5678u64.to_ne_bytes());
+    assert_eq!(OFFSET.le_bytes, [0xFE, 0xFF]);
+    assert_eq!(PORT.be_bytes, [0x1F, 0x90]);
+}
+
+#[test]
+fn test_bool() {
+    polymorphic_constant! {
+        const ENABLED: bool | u8 | u32 = 1;
+        const DISABLED: u8 | bool = 0;
+        #[saturate]
+        const CLAMPED: bool = -5;
+        const BOTH = ENABLED * DISABLED;
+    };
+
+    let enabled: bool = ENABLED.into();
+    assert!(enabled);
+    assert_eq!(ENABLED.u32, 1);
+    assert_eq!((DISABLED.bool, CLAMPED.bool, BOTH.bool), (false, false, false));
+}
+
+#[test]
+fn test_metrics() {
+    polymorphic_constant! {
+        const ZERO: u8 = 0;
+        const NEGATIVE: i16 | i64 = -1000;
+        const HUGE: u128 = 340_282_366_920_938_463_463_374_607_431_768_211_455;
+        const WHOLE: f32 | f64 = 255.0;
+        const ZERO_DIGITS: u8 = digits(ZERO);
+        const ZERO_BITS: u8 = bits(ZERO);
+        const NEGATIVE_DIGITS: u8 | usize = digits(NEGATIVE);
+        const NEGATIVE_BITS: u8 = bits(NEGATIVE);
+        const HUGE_DIGITS: u8 = digits(HUGE);
+        const HUGE_BITS: u8 = bits(HUGE);
+        const WHOLE_BITS: u32 = bits(WHOLE);
+    };
+
+    assert_eq!((ZERO_DIGITS.u8, ZERO_BITS.u8), (1, 0));
+    assert_eq!((NEGATIVE_DIGITS.usize, NEGATIVE_BITS.u8), (4, 10));
+    assert_eq!((HUGE_DIGITS.u8, HUGE_BITS.u8), (39, 128));
+    assert_eq!(WHOLE_BITS.u32, 8);
+}
+
+#[test]
+fn test_char() {
+    polymorphic_constant! {
+        const LINE_RETURN: u8 | u32 | char = 10;
+        const SNOWMAN: char | u32 = 0x2603;
+        #[saturate]
+        const CLAMPED: char = 0x11_0000;
+        const NEXT = LINE_RETURN + 1;
+    };
+
+    let line_return: char = LINE_RETURN.into();
+    assert_eq!(line_return, '\n');
+    assert_eq!(LINE_RETURN.u8, b'\n');
+    assert_eq!((SNOWMAN.char, CLAMPED.char, NEXT.char), ('☃', char::MAX, '\u{b}'));
+}
+
+#[test]
+fn test_str() {
+    polymorphic_constant! {
+        const SQRT_2: f32 | f64 | str = 1.414_213_562_373_095_048_801_688;
+        const MASK: u16 | str = 0xFF_FF;
+        #[saturate]
+        const OFFSET: str | i8 = -200;
+    };
+
+    let sqrt_2: &str = SQRT_2.into();
+    assert_eq!(sqrt_2, "1.414_213_562_373_095_048_801_688");
+    assert_eq!((MASK.str, MASK.u16), ("0xFF_FF", 0xFFFF));
+    assert_eq!((OFFSET.str, OFFSET.i8), ("-200", i8::MIN));
+}
+
+#[test]
+fn test_static() {
+    use core::sync::atomic::Ordering;
+
+    polymorphic_constant! {
+        /// Seeded from a checked value, then counted at runtime
+        static COUNTER: u16 | atomic_u16 | atomic_usize = 0x100;
+        const STEP: i32 = -2;
+        #[cfg(all())]
+        static OFFSET: i32 | atomic_i32 = (STEP.i32 * 8);
+        static ENABLED: bool | atomic_bool = 1;
+    };
+
+    COUNTER.atomic_u16.fetch_add(1, Ordering::Relaxed);
+    OFFSET.atomic_i32.fetch_sub(STEP.i32, Ordering::Relaxed);
+    ENABLED.atomic_bool.store(false, Ordering::Relaxed);
+    assert_eq!(COUNTER.atomic_u16.load(Ordering::Relaxed), COUNTER.u16 + 1);
+    assert_eq!(COUNTER.atomic_usize.load(Ordering::Relaxed), 256);
+    assert_eq!(OFFSET.atomic_i32.load(Ordering::Relaxed), OFFSET.i32 + 2);
+    assert!(ENABLED.bool && !ENABLED.atomic_bool.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_namespace() {
+    polymorphic_constant! {
+        #![lookup(fn lookup)]
+
+        const OUTSIDE: u8 = 1;
+
+        /// Sizes of the screen
+        group SCREEN {
+            const WIDTH: u16 | u32 = 320;
+            #[cfg(any())]
+            const DEPTH: u16 = 8;
+            const HEIGHT: u16 | u32 = 200;
+            const AREA = WIDTH * HEIGHT;
+        }
+
+        const ASPECT = WIDTH / HEIGHT;
+    };
+
+    assert_eq!(SCREEN.WIDTH.u16, 320);
+    assert_eq!(SCREEN.AREA.u32, 64_000);
+    assert_eq!((ASPECT.u16, OUTSIDE.u8), (1, 1));
+    assert!(lookup("HEIGHT").is_some());
+}
+
+#[test]
+fn test_ratio() {
+    polymorphic_constant! {
+        const DISTANCE: i32 | i64 = -900;
+        const DURATION: u8 | u16 = 60;
+        const THIRD_OF: f32 | f64 = 1.0;
+        const THREE: u8 = 3;
+        const SPEED: i8 | i16 = ratio_of(DISTANCE, DURATION);
+        pub const RATE: f32 | f64 = ratio_of(DURATION, DISTANCE,);
+        const THIRD: f32 | f64 = ratio_of(THIRD_OF, THREE);
+    };
+
+    assert_eq!((SPEED.i8, SPEED.i16), (-15, -15));
+    assert_eq!((RATE.f32, RATE.f64), (60.0 / -900.0, 60.0 / -900.0));
+    assert_eq!((THIRD.f32, THIRD.f64), (1.0 / 3.0, 1.0 / 3.0));
+}
+
+#[test]
+fn test_ratio_exact() {
+    polymorphic_constant! {
+        const A: u64 = 1_099_511_693_311;
+        const B: u64 = 1_099_511_627_775;
+        // Just above the midpoint between two `f32`, which rounding to `f64` first would lose
+        const R: f32 | f64 = ratio_of(A, B);
+        const WIDE: u64 = 27_021_597_764_222_979;
+        const THREE: u8 = 3;
+        // Beyond 2^53, the operands are not rounded before the division
+        const SPLIT: f64 = ratio_of(WIDE, THREE);
+        const MAX: u128 = 340_282_366_920_938_463_463_374_607_431_768_211_455;
+        const MAX_THIRD: u128 = ratio_of(MAX, THREE);
+        const MIN: i128 = -170_141_183_460_469_231_731_687_303_715_884_105_728;
+        const MINUS_ONE: i8 = -1;
+        const FLIPPED: u128 = ratio_of(MIN, MINUS_ONE);
+        const NEGATED: i128 = ratio_of(MAX_THIRD, MINUS_ONE);
+    };
+
+    assert_eq!(R.f32, 1.000_000_1);
+    assert_eq!(R.f64, 1_099_511_693_311.0 / 1_099_511_627_775.0);
+    assert_eq!(SPLIT.f64, 9_007_199_254_740_992.0);
+    assert_eq!(MAX_THIRD.u128, u128::MAX / 3);
+    assert_eq!(FLIPPED.u128, 1 << 127);
+    assert_eq!(NEGATED.i128, -((u128::MAX / 3) as i128));
+}
@@ -0,0 +1,54 @@
+#![no_std]
+#![cfg(test)]
+
+use nonmax::{NonMaxI16, NonMaxU32, NonMaxU8};
+use polymorphic_constant::{polymorphic_constant, ConstValue};
+
+polymorphic_constant! {
+    #![lookup(fn find)]
+
+    const ID: u32 | nm_u8 | nm_u32 = 254;
+    const OFFSET: nm_i16 | i64 = -300;
+    const LAST: u8 | nonmax_u8 = 254;
+    const LOWEST: nonmax_i8 | i8 = -128;
+    #[saturate]
+    const CLAMPED: nonmax_u8 | u16 = 1000;
+}
+
+#[test]
+fn test_nm_alias() {
+    assert_eq!(ID.nonmax_u8, NonMaxU8::new(254).unwrap());
+    assert_eq!(ID.nonmax_u32, NonMaxU32::new(254).unwrap());
+    assert_eq!(OFFSET.nonmax_i16, NonMaxI16::new(-300).unwrap());
+
+    let id: NonMaxU32 = ID.into();
+    assert_eq!(id.get(), 254);
+
+    assert_eq!(find("ID").unwrap()[1], ("nonmax_u8", ConstValue::U8(254)));
+}
+
+#[test]
+fn test_nonmax_range() {
+    assert_eq!(LAST.nonmax_u8.get(), u8::MAX - 1);
+    assert_eq!(LOWEST.nonmax_i8.get(), i8::MIN);
+    // Saturated to the largest value a non-max type holds
+    assert_eq!(CLAMPED.nonmax_u8.get(), 254);
+    assert_eq!(CLAMPED.u16, 1000);
+}
+
+#[test]
+fn test_nonmax_proof() {
+    polymorphic_constant! {
+        #[proof]
+        const INDEX: u16 | nonmax_u16 = 500;
+        #[proof]
+        #[saturate]
+        const LIMITED: nonmax_u8 | nz_u8 | u16 = 1000;
+    };
+
+    let () = _PROOF_INDEX_nonmax_u16;
+    let () = _PROOF_LIMITED_nonmax_u8;
+    let () = _PROOF_LIMITED_nz_u8;
+    assert_eq!(LIMITED.nonmax_u8.get(), 254);
+    assert_eq!(LIMITED.nz_u8.get(), 255);
+}
@@ -0,0 +1,59 @@
+#![cfg(all(test, feature = "ordered-float"))]
+
+use std::collections::BTreeSet;
+
+use ordered_float::{NotNan, OrderedFloat};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const THRESHOLD: f64 | of_f64 | nn_f32 = 0.25;
+    const LIMIT: f32 | of_f64 | nn_f64 = -1.5;
+    #[proof]
+    const PROVEN: nn_f64 | f64 = 2.5;
+    #[saturate]
+    const HUGE: f32 | of_f32 | nn_f32 = 1e300;
+    #[round(down)]
+    const TENTH: f64 | nn_f32 = 0.1;
+}
+
+// The `NotNan` variants are built during constant evaluation
+const KEY: NotNan<f32> = THRESHOLD.nn_f32;
+
+#[test]
+fn test_ordered_float() {
+    assert_eq!(THRESHOLD.of_f64, OrderedFloat(0.25));
+    assert_eq!(THRESHOLD.nn_f32, NotNan::new(0.25).unwrap());
+    assert_eq!(LIMIT.nn_f64.into_inner(), -1.5);
+
+    let ordered: OrderedFloat<f64> = THRESHOLD.into();
+    let not_nan: NotNan<f32> = THRESHOLD.into();
+    assert_eq!(ordered.0, 0.25);
+    assert_eq!(not_nan.into_inner(), 0.25);
+    assert_eq!(KEY.into_inner(), 0.25);
+}
+
+#[test]
+fn test_ordered_float_keys() {
+    let mut levels = BTreeSet::new();
+    levels.insert(THRESHOLD.of_f64);
+    levels.insert(LIMIT.of_f64);
+    assert_eq!(levels.first(), Some(&OrderedFloat(-1.5)));
+
+    let mut values = vec![PROVEN.nn_f64, LIMIT.nn_f64];
+    values.sort();
+    assert_eq!(values, [NotNan::new(-1.5).unwrap(), NotNan::new(2.5).unwrap()]);
+}
+
+#[test]
+fn test_ordered_float_rounding() {
+    // Saturated to the largest finite value, which is never NaN
+    assert_eq!(HUGE.of_f32, OrderedFloat(f32::MAX));
+    assert_eq!(HUGE.nn_f32.into_inner(), f32::MAX);
+    assert!((TENTH.nn_f32.into_inner() as f64) < 0.1);
+}
+
+#[test]
+fn test_ordered_float_proof() {
+    let () = _PROOF_PROVEN_nn_f64;
+    assert_eq!(PROVEN.f64, 2.5);
+}
@@ -0,0 +1,45 @@
+#![cfg(test)]
+#![allow(dead_code, non_camel_case_types)]
+
+// The derives of bevy_reflect and rkyv rely on the prelude
+#[cfg(not(any(feature = "bevy", feature = "rkyv")))]
+#[no_implicit_prelude]
+mod no_prelude {
+    ::polymorphic_constant::polymorphic_constant! {
+        const HALF: f32 | f64 = 0.5;
+        const UINT: u8 | nz_u8 | u64 = 200;
+    }
+
+    #[test]
+    fn test_no_prelude() {
+        ::core::assert_eq!(HALF.f32, 0.5);
+        ::core::assert_eq!(UINT.u8, 200);
+        ::core::assert_eq!(UINT.nz_u8.get(), 200);
+    }
+}
+
+mod shadowed {
+    use polymorphic_constant::polymorphic_constant;
+
+    trait Into {}
+    trait From {}
+    struct Debug;
+    struct Clone;
+    struct Copy;
+    type u8 = ();
+    type f32 = ();
+
+    polymorphic_constant! {
+        const HALF: f32 | f64 = 0.5;
+        const UINT: u8 | nz_u8 | u64 = 200;
+    }
+
+    #[test]
+    fn test_shadowed() {
+        let half: f64 = core::convert::Into::into(HALF);
+
+        assert_eq!(half, 0.5);
+        assert_eq!(HALF.f32, 0.5);
+        assert_eq!(UINT.u8, 200);
+    }
+}
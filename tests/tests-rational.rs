@@ -0,0 +1,41 @@
+#![cfg(all(test, feature = "rational"))]
+
+use num_rational::Ratio;
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const STEP: f64 | ratio_i64 = 0.1;
+    const QUARTER: ratio_u32 | ratio_i128 = 0.25;
+    const SCALED: ratio_i32 = 2.5e-3;
+    const HUNDREDS: ratio_i32 = 1.5e2;
+    const DEBT: ratio_i32 = -0.75;
+    const MASK: ratio_u64 = 0x10;
+    // Reduced before being narrowed, even though `10^10` does not fit in `i32`
+    const PADDED: ratio_i32 = 0.500_000_000_0;
+    // The limits of the numerator and denominator, `2_147_483_648` or `1e-10` failing to compile
+    const TOP: ratio_i32 = 2_147_483_647;
+    const BOTTOM: ratio_i32 = -2_147_483_648;
+    const FINEST: ratio_i32 = 1e-9;
+    const WIDE: ratio_u128 = 340_282_366_920_938_463_463_374_607_431_768_211_455;
+}
+
+#[test]
+fn test_ratio_reduced() {
+    assert_eq!(STEP.ratio_i64, Ratio::new(1, 10));
+    assert_eq!((*STEP.ratio_i64.numer(), *STEP.ratio_i64.denom()), (1, 10));
+    assert_eq!(QUARTER.ratio_u32, Ratio::new(1, 4));
+    assert_eq!(QUARTER.ratio_i128, Ratio::new(1, 4));
+    assert_eq!(SCALED.ratio_i32, Ratio::new(1, 400));
+    assert_eq!(HUNDREDS.ratio_i32, Ratio::from_integer(150));
+    assert_eq!((*DEBT.ratio_i32.numer(), *DEBT.ratio_i32.denom()), (-3, 4));
+    assert_eq!(MASK.ratio_u64, Ratio::from_integer(16));
+    assert_eq!((*PADDED.ratio_i32.numer(), *PADDED.ratio_i32.denom()), (1, 2));
+}
+
+#[test]
+fn test_ratio_limits() {
+    assert_eq!(TOP.ratio_i32, Ratio::from_integer(i32::MAX));
+    assert_eq!(BOTTOM.ratio_i32, Ratio::from_integer(i32::MIN));
+    assert_eq!(FINEST.ratio_i32, Ratio::new(1, 1_000_000_000));
+    assert_eq!(WIDE.ratio_u128, Ratio::from_integer(u128::MAX));
+}
@@ -0,0 +1,80 @@
+#![cfg(all(test, feature = "rkyv"))]
+
+use core::num::NonZeroU32;
+use core::time::Duration;
+use polymorphic_constant::polymorphic_constant;
+use rkyv::rancor::Error;
+
+polymorphic_constant! {
+    const PACKET: u8 | i64 | nz_u32 = 120;
+    const SPEED: f32 | f64 = 1.5;
+    #[unit("s")]
+    const TIMEOUT: u32 | duration = 30;
+    // The text of the literal is not archivable, the constant is left without the derives
+    const MASK: u16 | str = 0xFF;
+    // Neither are custom variants and deprecated constants
+    #[custom(meters = Meters::new)]
+    const DEPTH: f64 | meters = 11.0;
+    #[deprecated]
+    const OLD_PACKET: u8 = 64;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Meters(pub f64);
+
+impl Meters {
+    pub const fn new(value: f64) -> Self {
+        Meters(value)
+    }
+}
+
+#[test]
+fn test_rkyv_round_trip() {
+    let bytes = rkyv::to_bytes::<Error>(&PACKET).unwrap();
+
+    let archived = rkyv::access::<rkyv::Archived<PACKET>, Error>(&bytes).unwrap();
+    assert_eq!(archived.u8, 120);
+    assert_eq!(archived.i64, 120);
+    assert_eq!(archived.nz_u32.get(), 120);
+
+    let packet = rkyv::from_bytes::<PACKET, Error>(&bytes).unwrap();
+    assert_eq!(packet.u8, 120);
+    assert_eq!(packet.i64, 120);
+    assert_eq!(packet.nz_u32, NonZeroU32::new(120).unwrap());
+}
+
+#[test]
+fn test_rkyv_float() {
+    let bytes = rkyv::to_bytes::<Error>(&SPEED).unwrap();
+    let speed = rkyv::from_bytes::<SPEED, Error>(&bytes).unwrap();
+    assert_eq!(speed.f32, 1.5);
+    assert_eq!(speed.f64, 1.5);
+}
+
+#[test]
+fn test_rkyv_duration() {
+    let bytes = rkyv::to_bytes::<Error>(&TIMEOUT).unwrap();
+    let timeout = rkyv::from_bytes::<TIMEOUT, Error>(&bytes).unwrap();
+    assert_eq!(timeout.u32, 30);
+    assert_eq!(timeout.duration, Duration::from_secs(30));
+}
+
+#[test]
+fn test_rkyv_skipped() {
+    assert_eq!(MASK.u16, 255);
+    assert_eq!(MASK.str, "0xFF");
+}
+
+#[test]
+fn test_rkyv_layout() {
+    assert_eq!(core::mem::offset_of!(PACKET, u8), 0);
+    assert_eq!(core::mem::offset_of!(PACKET, i64), 8);
+    assert_eq!(core::mem::size_of::<PACKET>(), 24);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_rkyv_skipped_custom() {
+    assert_eq!(DEPTH.meters.0, 11.0);
+    assert_eq!(OLD_PACKET.u8, 64);
+}
@@ -0,0 +1,21 @@
+#![cfg(all(test, feature = "schemars"))]
+
+use polymorphic_constant::polymorphic_constant;
+use schemars::schema_for;
+
+polymorphic_constant! {
+    const MAX_SPEED: u8 | i64 = 120;
+    const GRAVITY: f32 | f64 = 9.81;
+}
+
+#[test]
+fn test_schema() {
+    assert_eq!(
+        schema_for!(MAX_SPEED).as_value().to_string(),
+        r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","const":120,"title":"MAX_SPEED"}"#
+    );
+    assert_eq!(
+        schema_for!(GRAVITY).as_value().to_string(),
+        r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","const":9.81,"title":"GRAVITY"}"#
+    );
+}
@@ -0,0 +1,30 @@
+#![cfg(all(test, feature = "portable_simd"))]
+#![feature(portable_simd)]
+#![no_std]
+
+use core::simd::{f32x4, f64x2, i16x8, u8x16};
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    const SCALE: f32 | f32x4 | f32x8 | f64x2 = 0.5;
+    const BIAS: i16 | i16x8 | u8x16 = 7;
+    #[saturate]
+    const CLAMPED: u8x16 | i16x8 = 300;
+    const OFFSET: i32 | i32x4 = (BIAS.i16 as i32 * 2);
+    const DOUBLE = SCALE * 2.0;
+}
+
+#[test]
+fn test_splat() {
+    assert_eq!(SCALE.f32x4, f32x4::splat(0.5));
+    assert_eq!(SCALE.f32x8.to_array(), [0.5; 8]);
+    assert_eq!(SCALE.f64x2, f64x2::splat(0.5));
+    assert_eq!(BIAS.i16x8, i16x8::splat(7));
+    assert_eq!(BIAS.u8x16, u8x16::splat(7));
+    assert_eq!((CLAMPED.u8x16, CLAMPED.i16x8), (u8x16::splat(255), i16x8::splat(300)));
+    assert_eq!(OFFSET.i32x4.to_array(), [14; 4]);
+    assert_eq!(DOUBLE.f32x4, f32x4::splat(1.0));
+
+    let scale: f32x4 = SCALE.into();
+    assert_eq!(scale * f32x4::splat(4.0), f32x4::splat(2.0));
+}
@@ -17,3 +17,265 @@ fn test_nz() {
     assert_eq!(NZ.nz_i64, ::std::num::NonZeroI64::new(2047).unwrap());
     assert_eq!(NZ.nz_isize, ::std::num::NonZeroIsize::new(2047).unwrap());
 }
+
+#[test]
+fn test_extremes() {
+    polymorphic_constant! {
+        const SIGNED_MAX: i128 | u128 | nz_i128 = 170141183460469231731687303715884105727;
+        const SIGNED_MIN: i128 | nz_i128 = -170141183460469231731687303715884105728;
+        const UNSIGNED_MAX: u128 | nz_u128 = 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+        const COMPUTED_MAX: u128 | nz_u128 = (u128::MAX);
+        const COMPUTED_MIN: i128 | i64 = (i64::MIN as i128);
+    }
+
+    assert_eq!((SIGNED_MAX.i128, SIGNED_MAX.u128, SIGNED_MAX.nz_i128.get()), (i128::MAX, i128::MAX as u128, i128::MAX));
+    assert_eq!((SIGNED_MIN.i128, SIGNED_MIN.nz_i128.get()), (i128::MIN, i128::MIN));
+    assert_eq!((UNSIGNED_MAX.u128, UNSIGNED_MAX.nz_u128.get()), (u128::MAX, u128::MAX));
+    assert_eq!(COMPUTED_MAX.nz_u128.get(), u128::MAX);
+    assert_eq!((COMPUTED_MIN.i128, COMPUTED_MIN.i64), (i64::MIN as i128, i64::MIN));
+}
+
+#[test]
+fn test_lookup() {
+    use polymorphic_constant::ConstValue;
+
+    polymorphic_constant! {
+        #![lookup(fn find)]
+
+        const WIDTH: u16 | nz_u8 = 32;
+        pub const RATIO: f32 = 0.5;
+    };
+
+    assert_eq!(
+        find("WIDTH"),
+        Some(&[("u16", ConstValue::U16(32)), ("nz_u8", ConstValue::NonZeroU8(WIDTH.nz_u8))][..])
+    );
+    assert_eq!(find("RATIO"), Some(&[("f32", ConstValue::F32(0.5))][..]));
+    assert_eq!(find("HEIGHT"), None);
+}
+
+#[test]
+fn test_also_nz() {
+    polymorphic_constant! {
+        #[also(squared = NINE_SQUARED)]
+        const NINE: u8 | nz_u32 = 9;
+    }
+
+    assert_eq!(NINE_SQUARED.u8, 81);
+    assert_eq!(NINE_SQUARED.nz_u32.get(), 81);
+}
+
+#[test]
+fn test_also_names() {
+    polymorphic_constant! {
+        #[also(recip, squared, halved)]
+        const PI: f32 | f64 = 3.141592653589793;
+        #[also(recip, halved = NEGATIVE_HALVED)]
+        const NEGATIVE: f64 = -0.5;
+    }
+
+    assert_eq!(PI_RECIP.f64, 0.3183098861837907);
+    assert_eq!(PI_SQUARED.f32, 9.869604);
+    assert_eq!(PI_HALF.f64, 1.5707963267948966);
+    assert_eq!(NEGATIVE_RECIP.f64, -2.0);
+    assert_eq!(NEGATIVE_HALVED.f64, -0.25);
+}
+
+#[test]
+fn test_sort_fields_aliases() {
+    polymorphic_constant! {
+        #![alias(Index = u8, Wide = u64)]
+
+        #[sort_fields]
+        const SIZE: Index | nm_u16 | Wide | u32 = 200;
+    }
+
+    let names: Vec<_> = SIZE.into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, ["u64", "u32", "nonmax_u16", "u8"]);
+}
+
+#[test]
+fn test_units_exact() {
+    polymorphic_constant! {
+        #[units(m = BIG_M)]
+        const BIG: u32 | u64 = 3_000_000_000 mm;
+        #[units(um = HUGE_UM)]
+        const HUGE: u64 = 3_000_000_000 mm;
+        #[units(mm = PRECISE_MM)]
+        const PRECISE: u64 | i128 = 9007199254740993 m;
+        #[units(ft = THIRD_FT)]
+        const THIRD: f32 | f64 = 0.1016 m;
+        #[units(km = SHORT_KM)]
+        const SHORT: f64 = -1.5 mm;
+    }
+
+    assert_eq!(BIG_M.u32, 3_000_000);
+    assert_eq!(HUGE_UM.u64, 3_000_000_000_000);
+    assert_eq!(PRECISE_MM.u64, 9_007_199_254_740_993_000);
+    assert_eq!(PRECISE_MM.i128, 9_007_199_254_740_993_000);
+    assert_eq!(THIRD_FT.f64, 1.0 / 3.0);
+    assert_eq!(THIRD_FT.f32, 1.0 / 3.0);
+    assert_eq!(SHORT_KM.f64, -0.0000015);
+}
+
+#[test]
+fn test_sum_exact() {
+    const TABLE: [u64; 2] = [9007199254740993, 0];
+    const SIGNED: [i64; 3] = [i64::MAX, i64::MAX, -1];
+
+    polymorphic_constant! {
+        const S: u64 | i128 = sum(TABLE);
+        const SIGNED_SUM: i128 = sum(SIGNED);
+        const SIGNED_MEAN: f64 = mean(SIGNED);
+    }
+
+    assert_eq!(S.u64, 9007199254740993);
+    assert_eq!(S.i128, 9007199254740993);
+    assert_eq!(SIGNED_SUM.i128, i64::MAX as i128 * 2 - 1);
+    assert_eq!(SIGNED_MEAN.f64, (i64::MAX as f64 * 2.0) / 3.0);
+}
+
+mod flat {
+    use polymorphic_constant::polymorphic_constant;
+
+    polymorphic_constant! {
+        /// Flattened in the public API
+        #[flat(u8 = ANSWER_U8, nz_u32 = ANSWER_NZ_U32, i64 = ANSWER_I64)]
+        pub const ANSWER: u8 | nz_u32 | i64 = 42;
+    }
+}
+
+#[test]
+fn test_flat() {
+    let small: u8 = flat::ANSWER_U8;
+    assert_eq!(small, 42);
+    assert_eq!(flat::ANSWER_NZ_U32.get(), 42);
+    assert_eq!(flat::ANSWER_I64, 42i64);
+}
+
+pub mod strict {
+    //! Constants generated under strict lint levels
+    #![deny(missing_docs, unsafe_code)]
+
+    use polymorphic_constant::polymorphic_constant;
+
+    polymorphic_constant! {
+        #![lints(allow(missing_docs), deny(unsafe_code))]
+
+        pub const LIMIT: u32 | nz_u32 = 64;
+        pub const DOUBLE = LIMIT + LIMIT;
+    }
+}
+
+#[test]
+fn test_lints() {
+    assert_eq!(strict::LIMIT.widest(), 64);
+    assert_eq!(strict::DOUBLE.widest(), 128);
+}
+
+#[test]
+fn test_ord() {
+    polymorphic_constant! {
+        #[ord(MAX_SIZE, DEFAULT_SIZE)]
+        const MIN_SIZE: nz_u16 | i32 = 16;
+        #[ord(DEFAULT_SIZE)]
+        const MAX_SIZE: nz_u16 | i32 = 1024;
+        const DEFAULT_SIZE: nz_u16 | i32 = 16;
+    }
+
+    assert!(MIN_SIZE < MAX_SIZE);
+    assert!(MAX_SIZE > MIN_SIZE);
+    assert!(MIN_SIZE == DEFAULT_SIZE);
+    assert!(DEFAULT_SIZE <= MIN_SIZE);
+    assert!(DEFAULT_SIZE < MAX_SIZE);
+}
+
+#[test]
+fn test_dump() {
+    polymorphic_constant! {
+        #![dump(fn dump)]
+        const HALF: f32 | f64 = 0.5;
+        #[cfg(any())]
+        const HIDDEN: u8 = 1;
+        const BUFFER_SIZE: u16 | nz_u32 = 4096;
+    };
+
+    let mut table = String::new();
+    dump(&mut table).unwrap();
+
+    assert_eq!(
+        table,
+        "constant     variant  value\n\
+         HALF         f32      0.5\n\
+         HALF         f64      0.5\n\
+         BUFFER_SIZE  u16      4096\n\
+         BUFFER_SIZE  nz_u32   4096\n"
+    );
+}
+
+#[test]
+fn test_report() {
+    polymorphic_constant! {
+        #![report(fn report)]
+        const RATE: f32 | f64 = 0.25;
+        #[saturate]
+        const LEVEL: u8 | i16 = -0x1_f4;
+        const STEP: u16 = 2 * 3;
+    };
+
+    let mut table = String::new();
+    report(&mut table).unwrap();
+
+    assert_eq!(
+        table,
+        "constant  variant  value  status\n\
+         RATE      f32      0.25   exact\n\
+         RATE      f64      0.25   exact\n\
+         LEVEL     u8       0      saturated from -0x1_f4\n\
+         LEVEL     i16      -500   exact\n\
+         STEP      u16      6      computed\n"
+    );
+}
+
+#[test]
+fn test_saturate() {
+    polymorphic_constant! {
+        #[saturate]
+        const GAIN: u8 | i8 | u16 | i64 = 300;
+        #[saturate]
+        const OFFSET: u8 | i8 | nz_u8 | nz_i8 = -200;
+        #[saturate]
+        const HUGE: f32 | f64 = 1e39;
+        #[saturate]
+        #[round(down)]
+        const TENTH: f32 | f64 = 0.1;
+    };
+
+    assert_eq!(GAIN.u8, 255);
+    assert_eq!(GAIN.i8, 127);
+    assert_eq!(GAIN.u16, 300);
+    assert_eq!(GAIN.i64, 300);
+    assert_eq!(OFFSET.u8, 0);
+    assert_eq!(OFFSET.i8, -128);
+    assert_eq!(OFFSET.nz_u8.get(), 1);
+    assert_eq!(OFFSET.nz_i8.get(), -128);
+    assert_eq!(HUGE.f32, f32::MAX);
+    assert_eq!(HUGE.f64, 1e39);
+    assert!((TENTH.f32 as f64) < 0.1);
+}
+
+#[test]
+fn test_total_ord() {
+    polymorphic_constant! {
+        #[total_ord]
+        const GAIN: f32 | f64 = 0.5;
+    };
+
+    let quiet = GAIN { f32: 0.25, f64: 0.25 };
+    let missing = GAIN { f32: f32::NAN, f64: f64::NAN };
+    let gains: std::collections::BTreeSet<GAIN> = [missing, GAIN, quiet, GAIN].iter().copied().collect();
+
+    assert_eq!(gains.len(), 3);
+    assert_eq!(gains.first(), Some(&quiet));
+    assert_eq!(missing, missing);
+}
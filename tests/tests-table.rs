@@ -0,0 +1,56 @@
+#![no_std]
+#![cfg(test)]
+
+use polymorphic_constant::polymorphic_table;
+
+polymorphic_table! {
+    /// The thresholds of the battery levels
+    pub const THRESHOLDS: u8 | i32 | nz_u16 = [10, 25, 50, 75];
+    pub (crate) const CURVE: f32 | f64 = [-1.0, 0.0, 2.0] => [4.0, 0.0, 1.0];
+    const STEPS: u8 | i64 = [0, 100, 200] => [0, 10, 40,];
+}
+
+mod inner {
+    use polymorphic_constant::polymorphic_table;
+
+    polymorphic_table! {
+        pub const SINGLE: u32 = [7];
+    }
+}
+
+#[test]
+fn test_table() {
+    let thresholds: [i32; 4] = THRESHOLDS.into();
+    assert_eq!(thresholds, [10, 25, 50, 75]);
+    assert_eq!(THRESHOLDS.nz_u16[3].get(), 75);
+    assert_eq!((THRESHOLDS::LEN, CURVE::LEN), (4, 3));
+    assert_eq!(CURVE::VALUES.f32, [4.0, 0.0, 1.0]);
+    let single: [u32; 1] = inner::SINGLE.into();
+    assert_eq!(single, [7]);
+}
+
+#[test]
+fn test_search() {
+    assert_eq!(THRESHOLDS::u8(0), 0);
+    assert_eq!(THRESHOLDS::u8(10), 1);
+    assert_eq!(THRESHOLDS::i32(49), 2);
+    assert_eq!(THRESHOLDS::nz_u16(core::num::NonZeroU16::new(80).unwrap()), 4);
+    assert_eq!((inner::SINGLE::u32(6), inner::SINGLE::u32(7)), (0, 1));
+
+    const INDEX: usize = THRESHOLDS::u8(30);
+    assert_eq!(INDEX, 2);
+}
+
+#[test]
+fn test_interpolate() {
+    assert_eq!(CURVE::f32(-2.0), 4.0);
+    assert_eq!(CURVE::f32(-0.5), 2.0);
+    assert_eq!(CURVE::f64(1.0), 0.5);
+    assert_eq!(CURVE::f64(3.0), 1.0);
+    assert_eq!(STEPS::u8(50), 5);
+    assert_eq!(STEPS::i64(150), 25);
+    assert_eq!(STEPS::i64(-5), 0);
+
+    const HALF: f64 = CURVE::f64(-0.5);
+    assert_eq!(HALF, 2.0);
+}
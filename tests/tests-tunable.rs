@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use polymorphic_constant::polymorphic_constant;
+
+polymorphic_constant! {
+    #[tunable]
+    const SPEED: f32 | f64 = 4.0;
+}
+
+polymorphic_constant! {
+    #[tunable]
+    const LIVES: u8 | nz_u16 | i64 = 3;
+}
+
+#[test]
+fn test_tunable() {
+    assert_eq!(LIVES.get().u8, 3);
+
+    #[cfg(debug_assertions)]
+    {
+        LIVES.set_override(LIVES {
+            u8: 5,
+            nz_u16: std::num::NonZeroU16::new(5).unwrap(),
+            i64: 5,
+        });
+
+        assert_eq!(LIVES.get().u8, 5);
+        assert_eq!(LIVES.get().nz_u16.get(), 5);
+        assert_eq!(LIVES.get().i64, 5);
+        assert_eq!(LIVES.u8, 3);
+
+        LIVES.reset_override();
+    }
+
+    assert_eq!(LIVES.get().i64, 3);
+}
+
+#[test]
+fn test_tunable_float() {
+    assert_eq!(SPEED.get().f32, 4.0);
+
+    #[cfg(debug_assertions)]
+    {
+        SPEED.set_override(SPEED { f32: 2.5, f64: 2.5 });
+
+        assert_eq!(SPEED.get().f32, 2.5);
+        assert_eq!(SPEED.get().f64, 2.5);
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_tunable_consistent() {
+    polymorphic_constant! {
+        #[tunable]
+        const RATE: u8 | u32 | i64 = 1;
+    }
+
+    let writer = std::thread::spawn(|| {
+        for i in 0..10_000u32 {
+            let value = (i % 200) as u8;
+            RATE.set_override(RATE { u8: value, u32: value.into(), i64: value.into() });
+        }
+    });
+    while !writer.is_finished() {
+        let rate = RATE.get();
+        assert_eq!(u32::from(rate.u8), rate.u32);
+        assert_eq!(i64::from(rate.u8), rate.i64);
+    }
+    writer.join().unwrap();
+}
@@ -0,0 +1,18 @@
+#![cfg(all(test, feature = "ufmt"))]
+
+use polymorphic_constant::polymorphic_constant;
+use ufmt::uwrite;
+
+polymorphic_constant! {
+    const LIVES: u8 | i64 = 3;
+    const OFFSET: i8 | i32 = -120;
+}
+
+#[test]
+fn test_ufmt() {
+    let mut text = String::new();
+    uwrite!(text, "{} {:?}", LIVES, OFFSET).unwrap();
+
+    assert_eq!(text, "3 -120");
+    assert_eq!(text, format!("{} {}", LIVES.i64, OFFSET.i32));
+}
@@ -0,0 +1,60 @@
+#![cfg(all(test, feature = "uom"))]
+
+use polymorphic_constant::polymorphic_constant;
+use uom::si::acceleration::standard_gravity;
+use uom::si::f64::{Acceleration, Frequency, Length, Mass, Time, Velocity};
+use uom::si::frequency::megahertz;
+use uom::si::length::{meter, mile};
+use uom::si::mass::{ounce, pound};
+use uom::si::time::hour;
+use uom::si::velocity::{kilometer_per_hour, knot, meter_per_second};
+
+polymorphic_constant! {
+    const SPEED_OF_LIGHT: f64 | uom_velocity = 299_792_458.0;
+    #[uom_unit(kilometer_per_hour)]
+    const SPEED_LIMIT: f32 | f64 | uom_velocity = 90.0;
+    #[uom_unit(knot)]
+    const CRUISE: f64 | uom_velocity = 450.0;
+    #[uom_unit(mile)]
+    const MARATHON: f64 | uom_length = 26.2;
+    #[uom_unit(pound)]
+    const PAYLOAD: f64 | uom_mass = 150.0;
+    #[uom_unit(ounce)]
+    const SAMPLE: f64 | uom_mass = 12.0;
+    #[uom_unit(hour)]
+    const SHIFT: f64 | uom_time = 8.0;
+    #[uom_unit(standard_gravity)]
+    const LOAD: f64 | uom_acceleration = 3.5;
+    #[uom_unit(megahertz)]
+    const CLOCK: f64 | uom_frequency = 16.0;
+}
+
+// The factors of the macro and of `uom` may round differently
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= expected.abs() * 1e-12, "{} is not {}", value, expected);
+}
+
+#[test]
+fn test_uom_base_unit() {
+    assert_eq!(SPEED_OF_LIGHT.uom_velocity.get::<meter_per_second>(), SPEED_OF_LIGHT.f64);
+    assert_eq!(SPEED_OF_LIGHT.uom_velocity, Velocity::new::<meter_per_second>(299_792_458.0));
+}
+
+#[test]
+fn test_uom_conversion() {
+    // Held in the base unit of the quantity, and read back in the unit of the constant
+    assert_eq!(SPEED_LIMIT.uom_velocity.value, 25.0);
+    assert_close(SPEED_LIMIT.uom_velocity.get::<kilometer_per_hour>(), SPEED_LIMIT.f64);
+    assert_eq!(SPEED_LIMIT.f32, 90.0);
+
+    assert_close(CRUISE.uom_velocity.value, Velocity::new::<knot>(450.0).value);
+    assert_close(MARATHON.uom_length.get::<meter>(), 42_164.812_8);
+    assert_close(MARATHON.uom_length.value, Length::new::<mile>(26.2).value);
+    assert_close(PAYLOAD.uom_mass.value, Mass::new::<pound>(150.0).value);
+    assert_close(PAYLOAD.uom_mass.get::<pound>(), PAYLOAD.f64);
+    assert_close(SAMPLE.uom_mass.get::<ounce>(), SAMPLE.f64);
+    assert_eq!(SHIFT.uom_time, Time::new::<hour>(8.0));
+    assert_eq!(SHIFT.uom_time.value, 28_800.0);
+    assert_close(LOAD.uom_acceleration.value, Acceleration::new::<standard_gravity>(3.5).value);
+    assert_eq!(CLOCK.uom_frequency, Frequency::new::<megahertz>(16.0));
+}